@@ -10,6 +10,15 @@ pub struct AATreeIterator<'a, K: 'a + Ord, V: 'a> {
 }
 
 impl<'a, K: Ord, V> AATreeIterator<'a, K, V> {
+    /// `root`を根とする木のイテレータを作成する
+    pub fn new(root: &'a AATreeNode<K, V>) -> Self {
+        let mut iter = AATreeIterator {
+            unvisited: VecDeque::new(),
+        };
+        iter.push_left_edge(root);
+        iter
+    }
+
     fn push_left_edge(&mut self, mut tree: &'a AATreeNode<K, V>) {
         while let Some(node) = tree.as_deref() {
             self.unvisited.push_front(node);
@@ -34,11 +43,7 @@ impl<'a, K: Ord, V> Iterator for AATreeIterator<'a, K, V> {
 
 impl<K: Ord, V> AATreeMap<K, V> {
     pub fn iter<'a>(&'a self) -> AATreeIterator<'a, K, V> {
-        let mut iter = AATreeIterator {
-            unvisited: VecDeque::new(),
-        };
-        iter.push_left_edge(&self.root);
-        iter
+        AATreeIterator::new(&self.root)
     }
 }
 
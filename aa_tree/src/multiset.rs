@@ -1,43 +1,147 @@
-//! 辞書型の実装
+//! 多重集合の実装
 
 use std::fmt::Debug;
 
-use crate::{node::*, print_util::pretty_print};
+use crate::{
+    iterator::AATreeIterator,
+    multiset_node::*,
+    node::{get, lower_bound, upper_bound},
+    print_util::pretty_print,
+};
 
-pub struct AATreeMap<K: Ord, V> {
-    pub root: AATreeNode<K, V>,
+/// AA木による多重集合
+/// - 各ノードにキーの個数を持たせて管理する
+pub struct AATreeMultiset<K: Ord> {
+    pub root: AATreeMultisetNode<K>,
+    size: usize,
 }
 
-impl<K: Ord, V> AATreeMap<K, V> {
-    /// mapの初期化
+impl<K: Ord> AATreeMultiset<K> {
+    /// 多重集合の初期化
     pub fn new() -> Self {
-        Self { root: None }
+        Self {
+            root: None,
+            size: 0,
+        }
     }
 
-    /// キーに対応する値の参照を取得する
-    pub fn get(&self, key: &K) -> Option<&V> {
-        get(&self.root, key)
+    /// 要素数（重複を含む）
+    pub fn len(&self) -> usize {
+        self.size
     }
 
-    /// キーに対応する値の**可変**参照を取得する
-    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
-        get_mut(&mut self.root, key)
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
     }
 
-    /// キーに対応する値を挿入する
-    pub fn insert(&mut self, key: K, value: V) {
-        self.root = insert(self.root.take(), key, value);
+    /// 要素を追加する（重複を許す）
+    pub fn insert(&mut self, key: K) {
+        self.root = insert_multi(self.root.take(), key, 1);
+        self.size += 1;
     }
 
-    /// キーに対応する値を削除する
-    pub fn remove(&mut self, key: &K) -> Option<V> {
-        let (new_root, old) = delete(self.root.take(), key);
-        self.root = new_root;
-        old.map(|old| old.1)
+    /// `key`に一致する要素の個数を返す
+    pub fn count(&self, key: &K) -> usize {
+        get(&self.root, key).copied().unwrap_or(0)
+    }
+
+    /// `key`に一致する要素を1つ削除する
+    pub fn remove_one(&mut self, key: &K) -> bool {
+        let removed;
+        (self.root, removed) = remove_one(self.root.take(), key);
+        if removed {
+            self.size -= 1;
+        }
+        removed
+    }
+
+    /// `key`に一致する要素をすべて削除し，削除した個数を返す
+    pub fn remove_all(&mut self, key: &K) -> usize {
+        let cnt;
+        (self.root, cnt) = remove_all(self.root.take(), key);
+        self.size -= cnt;
+        cnt
+    }
+
+    /// `key`以上の最小の要素を返す
+    pub fn lower_bound(&self, key: &K) -> Option<&K> {
+        lower_bound(&self.root, key).map(|(k, _)| k)
+    }
+
+    /// `key`より大きい最小の要素を返す
+    pub fn upper_bound(&self, key: &K) -> Option<&K> {
+        upper_bound(&self.root, key).map(|(k, _)| k)
+    }
+
+    /// 要素を昇順に（重複を含めて）返すイテレータ
+    pub fn iter(&self) -> AATreeMultisetIterator<'_, K> {
+        AATreeMultisetIterator {
+            inner: AATreeIterator::new(&self.root),
+            front: None,
+        }
+    }
+
+    /// 多重集合のすべての要素を削除する
+    pub fn clear(&mut self) -> AATreeMultisetNode<K> {
+        self.size = 0;
+        self.root.take()
+    }
+}
+
+impl<K: Ord> Default for AATreeMultiset<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----- iterator -----
+pub struct AATreeMultisetIterator<'a, K: 'a + Ord> {
+    inner: AATreeIterator<'a, K, usize>,
+    /// 現在のキーと，残りの個数
+    front: Option<(&'a K, usize)>,
+}
+
+impl<'a, K: Ord> Iterator for AATreeMultisetIterator<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front.is_none_or(|(_, rem)| rem == 0) {
+            let (key, &cnt) = self.inner.next()?;
+            self.front = Some((key, cnt));
+        }
+        let (key, rem) = self.front.as_mut()?;
+        *rem -= 1;
+        Some(*key)
+    }
+}
+
+impl<'a, K: Ord> IntoIterator for &'a AATreeMultiset<K> {
+    type IntoIter = AATreeMultisetIterator<'a, K>;
+    type Item = &'a K;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Ord> FromIterator<K> for AATreeMultiset<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut mset = Self::new();
+        for key in iter {
+            mset.insert(key);
+        }
+        mset
+    }
+}
+
+impl<K: Ord + Debug> Debug for AATreeMultiset<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
     }
 }
 
-impl<K: Ord + Debug, V: Debug> AATreeMap<K, V> {
+impl<K: Ord + Debug> AATreeMultiset<K> {
     /// 整形して表示する
     pub fn pretty_print(&self) {
         pretty_print(&self.root);
@@ -1,120 +1,36 @@
-#![allow(non_snake_case)]
-
-use std::{cmp::Ordering, fmt::Debug, mem};
-
-/// AA木のノード
-#[derive(Debug)]
-pub struct AATreeNodeInner<K: Ord, V> {
-    pub key: K,
-    pub value: V,
-    pub level: usize,
-    pub left: Option<Box<AATreeNodeInner<K, V>>>,
-    pub right: Option<Box<AATreeNodeInner<K, V>>>,
-}
+//! 多重集合用のノード操作
+//!
+//! 各ノードの`value`にキーの出現回数を保持する．
 
-impl<K: Ord, V> AATreeNodeInner<K, V> {
-    pub fn new(key: K, value: V) -> AATreeNode<K, V> {
-        Some(Box::new(AATreeNodeInner {
-            key,
-            value,
-            level: 1,
-            left: None,
-            right: None,
-        }))
-    }
-}
+#![allow(non_snake_case)]
 
-pub type AATreeNode<K, V> = Option<Box<AATreeNodeInner<K, V>>>;
+use std::cmp::Ordering;
 
-/// ノードの逆転
-/// ```text
-///   |        ⇓           ⇓        
-/// 2 |    L ← T           L → T    
-///   |   ↙ ↘   ↘   ==>   ↙   ↙ ↘   
-/// 1 |  A   B   R       A   B   R  
-/// ```
-fn skew<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
-    let Some(mut T) = node else {
-        return None;
-    };
-    if T.left.is_none() {
-        Some(T)
-    } else if T.level == T.left.as_ref().unwrap().level {
-        // ポインタの入れ替え
-        let mut L = T.left.unwrap();
-        T.left = L.right;
-        L.right = Some(T);
-        Some(L)
-    } else {
-        Some(T)
-    }
-}
+use crate::node::{delete, get_mut, skew, split, AATreeNode, AATreeNodeInner};
 
-/// ノードの分割操作
-/// ```text
-///   |                         ⇓    
-/// 3 |                         R    
-///   |    ⇓                   ↙ ↘   
-/// 2 |    T → R → X   ==>    T   X  
-///   |   ↙   ↙              ↙ ↘     
-/// 1 |  A   B              A   B    
-/// ```
-fn split<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
-    let Some(mut T) = node else {
-        return None;
-    };
-    if T.right.is_none() || T.right.as_ref().unwrap().right.is_none() {
-        Some(T)
-    } else if T.level == T.right.as_ref().unwrap().right.as_ref().unwrap().level {
-        let mut R = T.right.unwrap();
-        T.right = R.left;
-        R.left = Some(T);
-        R.level += 1; // Rのレベルを1上げる
-        Some(R)
-    } else {
-        Some(T)
-    }
-}
+/// 多重集合のノード（`value`はキーの個数）
+pub type AATreeMultisetNode<K> = AATreeNode<K, usize>;
 
-/// 値`key`の値の参照を取得する
-pub fn get<'a, K: Ord, V>(root: &'a AATreeNode<K, V>, key: &K) -> Option<&'a V> {
-    let Some(T) = root else {
-        return None;
-    };
-    match key.cmp(&T.key) {
-        Ordering::Less => get(&T.left, key),
-        Ordering::Greater => get(&T.right, key),
-        Ordering::Equal => Some(&T.value),
-    }
-}
-
-/// 値`key`の値の可変参照を取得する
-pub fn get_mut<'a, K: Ord, V>(root: &'a mut AATreeNode<K, V>, key: &K) -> Option<&'a mut V> {
-    let Some(T) = root else {
-        return None;
-    };
-    match key.cmp(&T.key) {
-        Ordering::Less => get_mut(&mut T.left, key),
-        Ordering::Greater => get_mut(&mut T.right, key),
-        Ordering::Equal => Some(&mut T.value),
-    }
-}
-
-/// 値`key`に`value`を挿入する
+/// 値`key`を`n`個追加する
 /// - `root`: 挿入する木の根
-pub fn insert<K: Ord, V>(root: AATreeNode<K, V>, key: K, value: V) -> AATreeNode<K, V> {
+pub fn insert_multi<K: Ord>(
+    root: AATreeMultisetNode<K>,
+    key: K,
+    n: usize,
+) -> AATreeMultisetNode<K> {
     let Some(mut T) = root else {
-        return AATreeNodeInner::new(key, value);
+        return AATreeNodeInner::new(key, n);
     };
     match key.cmp(&T.key) {
         Ordering::Less => {
-            T.left = insert(T.left, key, value);
+            T.left = insert_multi(T.left, key, n);
         }
         Ordering::Greater => {
-            T.right = insert(T.right, key, value);
+            T.right = insert_multi(T.right, key, n);
         }
         Ordering::Equal => {
-            T.value = value;
+            T.value += n;
+            return Some(T);
         }
     }
     let mut root = Some(T);
@@ -123,177 +39,25 @@ pub fn insert<K: Ord, V>(root: AATreeNode<K, V>, key: K, value: V) -> AATreeNode
     root
 }
 
-/// 値`key`を削除し，削除されたノードの`value`を返す
-/// - `root`: 削除する木の根
-pub fn delete<K: Ord, V>(root: AATreeNode<K, V>, key: &K) -> (AATreeNode<K, V>, Option<(K, V)>) {
-    let Some(mut T) = root else {
-        return (None, None);
-    };
-    let (new_root, old_key_value) = match key.cmp(&T.key) {
-        Ordering::Less => {
-            let (new_left, old_key_value) = delete(T.left, key);
-            T.left = new_left;
-            (Some(T), old_key_value)
+/// 値`key`を1つ削除する
+/// - 戻り値：(新しい根, 削除できたか)
+pub fn remove_one<K: Ord>(
+    mut root: AATreeMultisetNode<K>,
+    key: &K,
+) -> (AATreeMultisetNode<K>, bool) {
+    match get_mut(&mut root, key) {
+        None => (root, false),
+        Some(cnt) if *cnt > 1 => {
+            *cnt -= 1;
+            (root, true)
         }
-        Ordering::Greater => {
-            let (new_right, old_key_value) = delete(T.right, key);
-            T.right = new_right;
-            (Some(T), old_key_value)
-        }
-        Ordering::Equal => {
-            if T.left.is_none() {
-                (T.right, Some((T.key, T.value)))
-            } else if T.right.is_none() {
-                (T.left, Some((T.key, T.value)))
-            } else {
-                // 左右の子を持つ場合，左の子の最大値を現在のノードに代入
-                let (new_left, right_most) = delete_and_get_max(T.left.take());
-                if let Some(L) = new_left {
-                    T.left.replace(L);
-                }
-                let Some(right_most) = right_most else {
-                    unreachable!("T.left is not None");
-                };
-                let old_key_value = (
-                    mem::replace(&mut T.key, right_most.key),
-                    mem::replace(&mut T.value, right_most.value),
-                );
-                (Some(T), Some(old_key_value))
-            }
-        }
-    };
-    // バランスの修正
-    let rebalanced = rebarance(new_root);
-    (rebalanced, old_key_value)
-}
-
-/// 削除後の頂点を再平衡化
-fn rebarance<K: Ord, V>(root: AATreeNode<K, V>) -> AATreeNode<K, V> {
-    let Some(mut T) = root else {
-        return None;
-    };
-    let left_level = T.left.as_ref().map_or(0, |node| node.level);
-    let right_level = T.right.as_ref().map_or(0, |node| node.level);
-    if left_level < T.level - 1 || right_level < T.level - 1 {
-        T.level -= 1;
-        // 右が大きい場合，下げる
-        if right_level > T.level {
-            T.right.as_mut().unwrap().level = T.level;
-        }
-        // 同じレベルのノードをskew
-        T = skew(Some(T)).unwrap();
-        T.right = skew(T.right);
-        if let Some(mut right) = T.right.take() {
-            right.right = skew(right.right);
-            T.right.replace(right);
-        }
-        // 同じレベルのノードをsplit
-        T = split(Some(T)).unwrap();
-        T.right = split(T.right);
-    }
-    Some(T)
-}
-
-/// nodeを根とする木のうち，値が最大のものを削除する
-/// - 戻り値：(新しい根, 削除されたノード)
-fn delete_and_get_max<K: Ord, V>(
-    root: AATreeNode<K, V>,
-) -> (AATreeNode<K, V>, Option<AATreeNodeInner<K, V>>) {
-    let Some(mut T) = root else {
-        return (None, None);
-    };
-    // 右の子の取り出し
-    let (new_right, right_most) = delete_and_get_max(T.right.take());
-    let Some(right_most) = right_most else {
-        return (None, Some(*T));
-    };
-    if let Some(R) = new_right {
-        T.right.replace(R);
+        Some(_) => (delete(root, key).0, true),
     }
-    let mut new_root = Some(T);
-    // 削除したので，再平衡化
-    new_root = rebarance(new_root);
-    (new_root, Some(right_most))
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::tree;
-
-    #[test]
-    fn test_skew() {
-        let mut tree = tree! {
-            key: "D",
-            value: 4,
-            level: 2,
-            left: tree! {
-                key: "B",
-                value: 2,
-                level: 2,
-                left: tree! {
-                    key: "A",
-                    value: 1,
-                    level: 1,
-                },
-                right: tree! {
-                    key: "C",
-                    value: 3,
-                    level: 1,
-                },
-            },
-            right: tree! {
-                key: "E",
-                value: 5,
-                level: 1,
-            }
-        };
-
-        println!(" before skew ");
-        println!("{:#?}", &tree);
-
-        // skew
-        tree = skew(tree);
-
-        println!(" after skew ");
-        println!("{:#?}", &tree);
-    }
-
-    #[test]
-    fn test_split() {
-        let mut tree = tree! {
-            key: "B",
-            value: 2,
-            level: 2,
-            left: tree! {
-                key: "A",
-                value: 1,
-                level: 1,
-            },
-            right: tree! {
-                key: "D",
-                value: 4,
-                level: 2,
-                left: tree! {
-                    key: "C",
-                    value: 3,
-                    level: 1,
-                },
-                right: tree! {
-                    key: "E",
-                    value: 5,
-                    level: 2,
-                }
-            }
-        };
-
-        println!(" before split ");
-        println!("{:#?}", &tree);
-
-        // split
-        tree = split(tree);
-
-        println!(" after split ");
-        println!("{:#?}", &tree);
-    }
+/// 値`key`をすべて削除する
+/// - 戻り値：(新しい根, 削除された個数)
+pub fn remove_all<K: Ord>(root: AATreeMultisetNode<K>, key: &K) -> (AATreeMultisetNode<K>, usize) {
+    let (new_root, old) = delete(root, key);
+    (new_root, old.map_or(0, |(_, cnt)| cnt))
 }
@@ -33,7 +33,7 @@ pub type AATreeNode<K, V> = Option<Box<AATreeNodeInner<K, V>>>;
 ///   |   ↙ ↘   ↘   ==>   ↙   ↙ ↘   
 /// 1 |  A   B   R       A   B   R  
 /// ```
-fn skew<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
+pub(crate) fn skew<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
     let Some(mut T) = node else {
         return None;
     };
@@ -59,7 +59,7 @@ fn skew<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
 ///   |   ↙   ↙              ↙ ↘     
 /// 1 |  A   B              A   B    
 /// ```
-fn split<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
+pub(crate) fn split<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
     let Some(mut T) = node else {
         return None;
     };
@@ -100,6 +100,30 @@ pub fn get_mut<'a, K: Ord, V>(root: &'a mut AATreeNode<K, V>, key: &K) -> Option
     }
 }
 
+/// `key`以上である最小のキーを持つノードのキーと値を取得する
+pub fn lower_bound<'a, K: Ord, V>(root: &'a AATreeNode<K, V>, key: &K) -> Option<(&'a K, &'a V)> {
+    let Some(T) = root else {
+        return None;
+    };
+    if key <= &T.key {
+        lower_bound(&T.left, key).or(Some((&T.key, &T.value)))
+    } else {
+        lower_bound(&T.right, key)
+    }
+}
+
+/// `key`より大きい最小のキーを持つノードのキーと値を取得する
+pub fn upper_bound<'a, K: Ord, V>(root: &'a AATreeNode<K, V>, key: &K) -> Option<(&'a K, &'a V)> {
+    let Some(T) = root else {
+        return None;
+    };
+    if key < &T.key {
+        upper_bound(&T.left, key).or(Some((&T.key, &T.value)))
+    } else {
+        upper_bound(&T.right, key)
+    }
+}
+
 /// 値`key`に`value`を挿入する
 /// - `root`: 挿入する木の根
 pub fn insert<K: Ord, V>(root: AATreeNode<K, V>, key: K, value: V) -> AATreeNode<K, V> {
@@ -0,0 +1,105 @@
+use aa_tree::multiset::AATreeMultiset;
+use rand::Rng;
+
+#[test]
+fn test_insert() {
+    let mut mset = AATreeMultiset::new();
+    assert_eq!(format!("{mset:?}"), "{}");
+    assert_eq!(mset.len(), 0);
+    assert_eq!(mset.count(&1), 0);
+
+    for _ in 0..3 {
+        for i in 0..5 {
+            mset.insert(i);
+        }
+    }
+
+    mset.pretty_print();
+    assert_eq!(
+        format!("{mset:?}"),
+        "{0, 0, 0, 1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4}"
+    );
+    assert_eq!(mset.len(), 15);
+    assert_eq!(mset.count(&1), 3);
+    assert_eq!(mset.count(&5), 0);
+}
+
+#[test]
+fn test_remove() {
+    let mut mset: AATreeMultiset<_> = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5].into_iter().collect();
+    assert_eq!(mset.len(), 11);
+    assert_eq!(format!("{mset:?}"), "{1, 1, 2, 3, 3, 4, 5, 5, 5, 6, 9}");
+
+    assert!(mset.remove_one(&5));
+    assert_eq!(mset.count(&5), 2);
+    assert_eq!(mset.len(), 10);
+
+    assert!(mset.remove_one(&2));
+    assert_eq!(mset.count(&2), 0);
+    assert!(!mset.remove_one(&2));
+    assert_eq!(mset.len(), 9);
+
+    assert_eq!(mset.remove_all(&5), 2);
+    assert_eq!(mset.remove_all(&5), 0);
+    assert_eq!(mset.remove_all(&1), 2);
+    assert_eq!(mset.len(), 5);
+    assert_eq!(format!("{mset:?}"), "{3, 3, 4, 6, 9}");
+
+    mset.clear();
+    assert!(mset.is_empty());
+    assert_eq!(mset.iter().next(), None);
+}
+
+#[test]
+fn test_lower_upper_bound() {
+    let mset: AATreeMultiset<_> = [10, 20, 20, 30, 30, 30].into_iter().collect();
+
+    assert_eq!(mset.lower_bound(&0), Some(&10));
+    assert_eq!(mset.lower_bound(&10), Some(&10));
+    assert_eq!(mset.lower_bound(&15), Some(&20));
+    assert_eq!(mset.lower_bound(&30), Some(&30));
+    assert_eq!(mset.lower_bound(&31), None);
+
+    assert_eq!(mset.upper_bound(&0), Some(&10));
+    assert_eq!(mset.upper_bound(&10), Some(&20));
+    assert_eq!(mset.upper_bound(&20), Some(&30));
+    assert_eq!(mset.upper_bound(&30), None);
+}
+
+#[test]
+fn test_random() {
+    use std::collections::BTreeMap;
+
+    let mut mset = AATreeMultiset::new();
+    let mut expected = BTreeMap::new();
+    let mut len = 0;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..2000 {
+        let key: u64 = rng.gen_range(0..50);
+        if rng.gen_range(0..3) == 0 {
+            let removed = mset.remove_one(&key);
+            let cnt: &mut usize = expected.entry(key).or_default();
+            assert_eq!(removed, *cnt > 0);
+            if *cnt > 0 {
+                *cnt -= 1;
+                len -= 1;
+            }
+        } else {
+            mset.insert(key);
+            *expected.entry(key).or_default() += 1;
+            len += 1;
+        }
+        assert_eq!(mset.len(), len);
+    }
+
+    for key in 0..50 {
+        assert_eq!(mset.count(&key), expected.get(&key).copied().unwrap_or(0));
+    }
+
+    let flat: Vec<u64> = expected
+        .iter()
+        .flat_map(|(&k, &c)| std::iter::repeat_n(k, c))
+        .collect();
+    assert_eq!(mset.iter().copied().collect::<Vec<_>>(), flat);
+}
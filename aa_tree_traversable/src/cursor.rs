@@ -0,0 +1,174 @@
+//! 親へのポインタを用いて木を双方向に走査するカーソル
+
+#![allow(non_snake_case)]
+
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    cmp::Ordering,
+    rc::Rc,
+};
+
+use crate::node::{delete_by_path, insert, AATreeNode, AATreeNodeInner};
+
+type NodeRef<K, V> = Rc<RefCell<AATreeNodeInner<K, V>>>;
+
+/// `node`を根とする部分木のうち，最小のノードを返す
+pub fn min_node<K: Ord, V>(node: &AATreeNode<K, V>) -> AATreeNode<K, V> {
+    let mut T = node.clone()?;
+    loop {
+        let Some(L) = T.borrow().left.clone() else {
+            break;
+        };
+        T = L;
+    }
+    Some(T)
+}
+
+/// `node`を根とする部分木のうち，最大のノードを返す
+pub fn max_node<K: Ord, V>(node: &AATreeNode<K, V>) -> AATreeNode<K, V> {
+    let mut T = node.clone()?;
+    loop {
+        let Some(R) = T.borrow().right.clone() else {
+            break;
+        };
+        T = R;
+    }
+    Some(T)
+}
+
+/// 中間順で`node`の次にあるノードを返す
+pub fn next_node<K: Ord, V>(node: &NodeRef<K, V>) -> AATreeNode<K, V> {
+    // 右の子があるとき，右部分木の最小値
+    if let Some(R) = &node.borrow().right {
+        return min_node(&Some(R.clone()));
+    }
+    // 左の子として辿れる祖先まで登る
+    let mut T = node.clone();
+    loop {
+        let P = T.borrow().parent.as_ref()?.upgrade()?;
+        if P.borrow().left.as_ref().is_some_and(|L| Rc::ptr_eq(L, &T)) {
+            return Some(P);
+        }
+        T = P;
+    }
+}
+
+/// 中間順で`node`の前にあるノードを返す
+pub fn prev_node<K: Ord, V>(node: &NodeRef<K, V>) -> AATreeNode<K, V> {
+    // 左の子があるとき，左部分木の最大値
+    if let Some(L) = &node.borrow().left {
+        return max_node(&Some(L.clone()));
+    }
+    // 右の子として辿れる祖先まで登る
+    let mut T = node.clone();
+    loop {
+        let P = T.borrow().parent.as_ref()?.upgrade()?;
+        if P.borrow().right.as_ref().is_some_and(|R| Rc::ptr_eq(R, &T)) {
+            return Some(P);
+        }
+        T = P;
+    }
+}
+
+/// 根から`node`までの経路を返す
+fn path_from_root<K: Ord, V>(node: &NodeRef<K, V>) -> Vec<Ordering> {
+    let mut path = vec![Ordering::Equal];
+    let mut T = node.clone();
+    loop {
+        let Some(P) = T.borrow().parent.as_ref().and_then(|P| P.upgrade()) else {
+            break;
+        };
+        if P.borrow().left.as_ref().is_some_and(|L| Rc::ptr_eq(L, &T)) {
+            path.push(Ordering::Less);
+        } else {
+            path.push(Ordering::Greater);
+        }
+        T = P;
+    }
+    path.reverse();
+    path
+}
+
+/// 木の要素を指すカーソル
+/// - 要素を指していないとき（末尾の次かつ先頭の前），`current`は`None`
+pub struct Cursor<'a, K: Ord, V> {
+    root: &'a mut AATreeNode<K, V>,
+    current: AATreeNode<K, V>,
+}
+
+impl<'a, K: Ord, V> Cursor<'a, K, V> {
+    /// どの要素も指していないカーソルを作成する
+    pub fn new(root: &'a mut AATreeNode<K, V>) -> Self {
+        Self {
+            root,
+            current: None,
+        }
+    }
+
+    /// 最小の要素を指すカーソルを作成する
+    pub fn first(root: &'a mut AATreeNode<K, V>) -> Self {
+        let current = min_node(root);
+        Self { root, current }
+    }
+
+    /// 最大の要素を指すカーソルを作成する
+    pub fn last(root: &'a mut AATreeNode<K, V>) -> Self {
+        let current = max_node(root);
+        Self { root, current }
+    }
+
+    /// 次の要素に移動する
+    /// - 最大の要素からは要素を指さない位置へ，要素を指さない位置からは最小の要素へ移動する
+    pub fn move_next(&mut self) {
+        self.current = match self.current.take() {
+            Some(T) => next_node(&T),
+            None => min_node(self.root),
+        };
+    }
+
+    /// 前の要素に移動する
+    /// - 最小の要素からは要素を指さない位置へ，要素を指さない位置からは最大の要素へ移動する
+    pub fn move_prev(&mut self) {
+        self.current = match self.current.take() {
+            Some(T) => prev_node(&T),
+            None => max_node(self.root),
+        };
+    }
+
+    /// 指している要素のキーを返す
+    pub fn key(&self) -> Option<Ref<'_, K>> {
+        let T = self.current.as_ref()?;
+        Some(Ref::map(T.borrow(), |T| &T.key))
+    }
+
+    /// 指している要素の値を返す
+    pub fn value(&self) -> Option<Ref<'_, V>> {
+        let T = self.current.as_ref()?;
+        Some(Ref::map(T.borrow(), |T| &T.value))
+    }
+
+    /// 指している要素の値の可変参照を返す
+    pub fn value_mut(&mut self) -> Option<RefMut<'_, V>> {
+        let T = self.current.as_ref()?;
+        Some(RefMut::map(T.borrow_mut(), |T| &mut T.value))
+    }
+
+    /// 指している要素を削除し，その`(key, value)`を返す
+    /// - 削除後，カーソルは次の要素を指す
+    pub fn remove_current(&mut self) -> Option<(K, V)> {
+        let T = self.current.take()?;
+        let next = next_node(&T);
+        let path = path_from_root(&T);
+        drop(T);
+        let old_key_value;
+        (*self.root, old_key_value) = delete_by_path(self.root.take(), &path);
+        self.current = next;
+        old_key_value
+    }
+
+    /// 木に`key`と`value`を挿入する
+    /// - カーソルの位置は変わらない
+    pub fn insert(&mut self, key: K, value: V) {
+        *self.root = insert(self.root.take(), key, value);
+    }
+}
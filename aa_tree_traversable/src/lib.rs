@@ -1,3 +1,4 @@
 pub mod build_macro;
+pub mod cursor;
 pub mod node;
 pub mod print_util;
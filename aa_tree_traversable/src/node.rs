@@ -3,6 +3,7 @@
 use std::{
     cell::RefCell,
     cmp::Ordering,
+    mem,
     rc::{Rc, Weak},
 };
 
@@ -56,6 +57,9 @@ fn skew<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
             // Tの左の子をBに
             T.borrow_mut().left.replace(B);
         }
+        // Lの親をTの親に
+        let P = T.borrow_mut().parent.take();
+        L.borrow_mut().parent = P;
         // Tの親をLに
         T.borrow_mut().parent.replace(Rc::downgrade(&L));
         // Lの右の子をTに
@@ -102,6 +106,9 @@ fn split<K: Ord, V>(node: AATreeNode<K, V>) -> AATreeNode<K, V> {
             // Tの右の子をBに
             T.borrow_mut().right.replace(B);
         }
+        // Rの親をTの親に
+        let P = T.borrow_mut().parent.take();
+        R.borrow_mut().parent = P;
         // Tの親をRに
         T.borrow_mut().parent.replace(Rc::downgrade(&R));
         // Rの左の子をTに
@@ -157,6 +164,180 @@ pub fn insert<K: Ord, V>(root: AATreeNode<K, V>, key: K, value: V) -> AATreeNode
     root
 }
 
+/// 値`key`を削除し，削除されたノードの`(key, value)`を返す
+/// - `root`: 削除する木の根
+pub fn delete<K: Ord, V>(root: AATreeNode<K, V>, key: &K) -> (AATreeNode<K, V>, Option<(K, V)>) {
+    let (new_root, old_key_value) = delete_inner(root, &mut |k| key.cmp(k));
+    if let Some(T) = &new_root {
+        T.borrow_mut().parent = None;
+    }
+    (new_root, old_key_value)
+}
+
+/// 根からの経路`path`で指定されたノードを削除し，削除されたノードの`(key, value)`を返す
+/// - `path`: 根から順に，左へ進むなら`Less`，右へ進むなら`Greater`，削除するノードで`Equal`
+pub(crate) fn delete_by_path<K: Ord, V>(
+    root: AATreeNode<K, V>,
+    path: &[Ordering],
+) -> (AATreeNode<K, V>, Option<(K, V)>) {
+    let mut path = path.iter();
+    let (new_root, old_key_value) =
+        delete_inner(root, &mut |_| *path.next().unwrap_or(&Ordering::Equal));
+    if let Some(T) = &new_root {
+        T.borrow_mut().parent = None;
+    }
+    (new_root, old_key_value)
+}
+
+/// 削除の本体
+/// - `cmp`: 探索するキーとノードのキーの比較結果を返す関数
+fn delete_inner<K: Ord, V, F: FnMut(&K) -> Ordering>(
+    root: AATreeNode<K, V>,
+    cmp: &mut F,
+) -> (AATreeNode<K, V>, Option<(K, V)>) {
+    let Some(T) = root else {
+        return (None, None);
+    };
+    let order = cmp(&T.borrow().key);
+    let (new_root, old_key_value) = match order {
+        Ordering::Less => {
+            let left = T.borrow_mut().left.take();
+            let (new_left, old_key_value) = delete_inner(left, cmp);
+            set_left(&T, new_left);
+            (Some(T), old_key_value)
+        }
+        Ordering::Greater => {
+            let right = T.borrow_mut().right.take();
+            let (new_right, old_key_value) = delete_inner(right, cmp);
+            set_right(&T, new_right);
+            (Some(T), old_key_value)
+        }
+        Ordering::Equal => {
+            let has_left = T.borrow().left.is_some();
+            let has_right = T.borrow().right.is_some();
+            if !has_left || !has_right {
+                // 子が高々1つの場合，子をTの位置に付け替える
+                let child = if has_left {
+                    T.borrow_mut().left.take()
+                } else {
+                    T.borrow_mut().right.take()
+                };
+                let P = T.borrow_mut().parent.take();
+                if let Some(C) = &child {
+                    C.borrow_mut().parent = P;
+                }
+                let T = into_inner(T);
+                (child, Some((T.key, T.value)))
+            } else {
+                // 左右の子を持つ場合，左の子の最大値を現在のノードに代入
+                let left = T.borrow_mut().left.take();
+                let (new_left, right_most) = delete_and_get_max(left);
+                set_left(&T, new_left);
+                let Some(right_most) = right_most else {
+                    unreachable!("T.left is not None");
+                };
+                let old_key_value = {
+                    let mut T = T.borrow_mut();
+                    (
+                        mem::replace(&mut T.key, right_most.key),
+                        mem::replace(&mut T.value, right_most.value),
+                    )
+                };
+                (Some(T), Some(old_key_value))
+            }
+        }
+    };
+    // バランスの修正
+    let rebalanced = rebarance(new_root);
+    (rebalanced, old_key_value)
+}
+
+/// 削除後の頂点を再平衡化
+fn rebarance<K: Ord, V>(root: AATreeNode<K, V>) -> AATreeNode<K, V> {
+    let T = root?;
+    let level = T.borrow().level;
+    let left_level = T
+        .borrow()
+        .left
+        .as_ref()
+        .map_or(0, |node| node.borrow().level);
+    let right_level = T
+        .borrow()
+        .right
+        .as_ref()
+        .map_or(0, |node| node.borrow().level);
+    if left_level + 1 < level || right_level + 1 < level {
+        T.borrow_mut().level -= 1;
+        // 右が大きい場合，下げる
+        if right_level > level - 1 {
+            T.borrow().right.as_ref().unwrap().borrow_mut().level = level - 1;
+        }
+        // 同じレベルのノードをskew
+        let T = skew(Some(T)).unwrap();
+        let right = T.borrow_mut().right.take();
+        set_right(&T, skew(right));
+        if let Some(R) = T.borrow().right.clone() {
+            let right = R.borrow_mut().right.take();
+            set_right(&R, skew(right));
+        }
+        // 同じレベルのノードをsplit
+        let T = split(Some(T)).unwrap();
+        let right = T.borrow_mut().right.take();
+        set_right(&T, split(right));
+        return Some(T);
+    }
+    Some(T)
+}
+
+/// nodeを根とする木のうち，値が最大のものを削除する
+/// - 戻り値：(新しい根, 削除されたノード)
+fn delete_and_get_max<K: Ord, V>(
+    root: AATreeNode<K, V>,
+) -> (AATreeNode<K, V>, Option<AATreeNodeInner<K, V>>) {
+    let Some(T) = root else {
+        return (None, None);
+    };
+    // 右の子の取り出し
+    let right = T.borrow_mut().right.take();
+    let (new_right, right_most) = delete_and_get_max(right);
+    let Some(right_most) = right_most else {
+        // Tが最大のとき，左の子をTの位置に付け替える
+        let left = T.borrow_mut().left.take();
+        let P = T.borrow_mut().parent.take();
+        if let Some(L) = &left {
+            L.borrow_mut().parent = P;
+        }
+        return (left, Some(into_inner(T)));
+    };
+    set_right(&T, new_right);
+    // 削除したので，再平衡化
+    (rebarance(Some(T)), Some(right_most))
+}
+
+/// Tの左の子を`child`にし，親のポインタを張る
+fn set_left<K: Ord, V>(T: &Rc<RefCell<AATreeNodeInner<K, V>>>, child: AATreeNode<K, V>) {
+    if let Some(C) = &child {
+        C.borrow_mut().parent.replace(Rc::downgrade(T));
+    }
+    T.borrow_mut().left = child;
+}
+
+/// Tの右の子を`child`にし，親のポインタを張る
+fn set_right<K: Ord, V>(T: &Rc<RefCell<AATreeNodeInner<K, V>>>, child: AATreeNode<K, V>) {
+    if let Some(C) = &child {
+        C.borrow_mut().parent.replace(Rc::downgrade(T));
+    }
+    T.borrow_mut().right = child;
+}
+
+/// 木から切り離されたノードの中身を取り出す
+fn into_inner<K: Ord, V>(T: Rc<RefCell<AATreeNodeInner<K, V>>>) -> AATreeNodeInner<K, V> {
+    let Ok(T) = Rc::try_unwrap(T) else {
+        panic!("the removed node is still referenced");
+    };
+    T.into_inner()
+}
+
 #[cfg(test)]
 mod test_aatree_traverse {
     use super::*;
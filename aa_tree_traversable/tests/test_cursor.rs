@@ -0,0 +1,109 @@
+use aa_tree_traversable::{cursor::Cursor, node::insert, print_util::print_as_binary_tree};
+
+#[test]
+fn test_move_next_prev() {
+    let mut tree = None;
+
+    for (i, c) in ('A'..='Z').rev().enumerate() {
+        tree = insert(tree, c, i);
+    }
+
+    print_as_binary_tree(&tree);
+
+    // 前から走査
+    let mut cursor = Cursor::first(&mut tree);
+    let mut keys = vec![];
+    while let Some(key) = cursor.key().map(|k| *k) {
+        keys.push(key);
+        cursor.move_next();
+    }
+    assert_eq!(keys, ('A'..='Z').collect::<Vec<_>>());
+
+    // 末尾の次から先頭へ戻る
+    cursor.move_next();
+    assert_eq!(cursor.key().map(|k| *k), Some('A'));
+    cursor.move_prev();
+    assert!(cursor.key().is_none());
+
+    // 後ろから走査
+    let mut keys = vec![];
+    cursor.move_prev();
+    while let Some(key) = cursor.key().map(|k| *k) {
+        keys.push(key);
+        cursor.move_prev();
+    }
+    assert_eq!(keys, ('A'..='Z').rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_value_mut() {
+    let mut tree = None;
+
+    for i in 0..20 {
+        tree = insert(tree, i, i);
+    }
+
+    let mut cursor = Cursor::first(&mut tree);
+    while cursor.key().is_some() {
+        *cursor.value_mut().unwrap() *= 10;
+        cursor.move_next();
+    }
+
+    let mut cursor = Cursor::last(&mut tree);
+    for i in (0..20).rev() {
+        assert_eq!(cursor.value().map(|v| *v), Some(i * 10));
+        cursor.move_prev();
+    }
+}
+
+#[test]
+fn test_remove_current() {
+    let mut tree = None;
+
+    for i in 0..50 {
+        tree = insert(tree, i, i);
+    }
+
+    // 3の倍数を削除
+    let mut cursor = Cursor::first(&mut tree);
+    while let Some(key) = cursor.key().map(|k| *k) {
+        if key % 3 == 0 {
+            assert_eq!(cursor.remove_current(), Some((key, key)));
+        } else {
+            cursor.move_next();
+        }
+    }
+    assert_eq!(cursor.remove_current(), None);
+
+    print_as_binary_tree(&tree);
+
+    let mut cursor = Cursor::first(&mut tree);
+    let mut keys = vec![];
+    while let Some(key) = cursor.key().map(|k| *k) {
+        keys.push(key);
+        cursor.move_next();
+    }
+    assert_eq!(keys, (0..50).filter(|x| x % 3 != 0).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_insert_while_traversing() {
+    let mut tree = None;
+
+    for i in (0..20).step_by(2) {
+        tree = insert(tree, i, ());
+    }
+
+    // 走査しながら奇数を挿入
+    let mut cursor = Cursor::first(&mut tree);
+    let mut visited = vec![];
+    while let Some(key) = cursor.key().map(|k| *k) {
+        visited.push(key);
+        if key % 2 == 0 {
+            cursor.insert(key + 1, ());
+        }
+        cursor.move_next();
+    }
+
+    assert_eq!(visited, (0..20).collect::<Vec<_>>());
+}
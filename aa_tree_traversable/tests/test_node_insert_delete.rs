@@ -1,7 +1,10 @@
+#![allow(non_snake_case)]
+
 use aa_tree_traversable::{
-    node::insert,
+    node::{delete, insert, AATreeNode, AATreeNodeInner},
     print_util::{print_as_binary_tree, print_as_btree},
 };
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn test_insert() {
@@ -66,25 +69,67 @@ fn test_int_increment() {
     }
 }
 
-// #[test]
-// fn test_delete() {
-//     let mut tree = None;
+#[test]
+fn test_delete() {
+    let mut tree = None;
+
+    for (i, c) in ('A'..='H').enumerate() {
+        tree = insert(tree, c, i);
+    }
+
+    println!("  default ");
+    print_as_btree(&tree);
+
+    for (i, c) in ('A'..='J').enumerate() {
+        println!("> delete {c}");
+
+        let old;
+        (tree, old) = delete(tree, &c);
+
+        assert_eq!(old, (c <= 'H').then_some((c, i)));
+        print_as_btree(&tree);
+        print_as_binary_tree(&tree);
+    }
 
-//     for (i, c) in ('A'..='H').enumerate() {
-//         tree = insert(tree, c, i);
-//     }
+    assert!(tree.is_none());
+}
+
+#[test]
+fn test_delete_parent() {
+    let mut tree = None;
 
-//     println!("  default ");
-//     print_as_btree(&tree);
+    for i in 0..100 {
+        tree = insert(tree, i * 37 % 100, i);
+    }
 
-//     for c in 'A'..='J' {
-//         println!("> delete {c}");
+    for i in 0..100 {
+        let key = i * 53 % 100;
+        let old;
+        (tree, old) = delete(tree, &key);
+        assert_eq!(old.map(|(k, _)| k), Some(key));
 
-//         tree = delete(tree, &c).0;
+        // 親へのポインタが正しく張られていることを確認
+        check_parent(&tree, None);
+    }
+}
 
-//         print_as_btree(&tree);
-//     }
-// }
+/// 各ノードの親へのポインタが実際の親を指していることを確認する
+fn check_parent(
+    node: &AATreeNode<i32, i32>,
+    parent: Option<&Rc<RefCell<AATreeNodeInner<i32, i32>>>>,
+) {
+    let Some(T) = node else {
+        return;
+    };
+    let actual = T.borrow().parent.as_ref().and_then(|P| P.upgrade());
+    match (actual, parent) {
+        (None, None) => (),
+        (Some(P), Some(Q)) => assert!(Rc::ptr_eq(&P, Q)),
+        _ => panic!("invalid parent pointer"),
+    }
+    check_parent(&T.borrow().left, Some(T));
+    check_parent(&T.borrow().right, Some(T));
+}
 
 /*
 
@@ -0,0 +1,5 @@
+pub mod iterator;
+pub mod map;
+pub mod node;
+pub mod print_util;
+pub mod set;
@@ -0,0 +1,202 @@
+//! 辞書型の実装
+
+use std::{fmt::Debug, ops::RangeBounds};
+
+use crate::{
+    iterator::{AvlTreeIterator, AvlTreeRange},
+    node::*,
+    print_util::pretty_print,
+};
+
+pub struct AvlTreeMap<K: Ord, V> {
+    pub root: AvlTreeNode<K, V>,
+}
+
+impl<K: Ord, V> AvlTreeMap<K, V> {
+    /// mapの初期化
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        len(&self.root)
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 木の高さ
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// キーに対応する値の参照を取得する
+    pub fn get(&self, key: &K) -> Option<&V> {
+        get(&self.root, key)
+    }
+
+    /// キーに対応する値の**可変**参照を取得する
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        get_mut(&mut self.root, key)
+    }
+
+    /// キーが存在するか判定する
+    pub fn contains_key(&self, key: &K) -> bool {
+        get(&self.root, key).is_some()
+    }
+
+    /// キーに対応する値を挿入する
+    /// - キーがすでに存在する場合は値を更新し，もとの値を返す
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let old_value;
+        (self.root, old_value) = insert(self.root.take(), key, value);
+        old_value
+    }
+
+    /// キーに対応する値を削除する
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let old;
+        (self.root, old) = delete(self.root.take(), key);
+        old.map(|old| old.1)
+    }
+
+    /// 最小のキーを持つ要素を削除して返す
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        let min;
+        (self.root, min) = delete_min(self.root.take());
+        min
+    }
+
+    /// 最大のキーを持つ要素を削除して返す
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        let max;
+        (self.root, max) = delete_max(self.root.take());
+        max
+    }
+
+    /// mapのすべての要素を削除する
+    pub fn clear(&mut self) -> AvlTreeNode<K, V> {
+        self.root.take()
+    }
+
+    /// 要素を昇順に返すイテレータ
+    pub fn iter(&self) -> AvlTreeIterator<'_, K, V> {
+        AvlTreeRange::new(&self.root, ..)
+    }
+
+    /// 区間`range`に含まれる要素を昇順に返すイテレータ
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> AvlTreeRange<'_, K, V, R> {
+        AvlTreeRange::new(&self.root, range)
+    }
+
+    /// `key`以上のキーを持つ要素を切り離して返す
+    /// - 計算量：$O(\log n)$
+    pub fn split_off(&mut self, key: &K) -> Self {
+        let (left, mid, right) = split(self.root.take(), key);
+        self.root = left;
+        let right = match mid {
+            Some((key, value)) => join(None, key, value, right),
+            None => right,
+        };
+        Self { root: right }
+    }
+
+    /// `other`のすべての要素を移動する
+    /// - `self`のすべてのキーが`other`のすべてのキーより小さいとき，$O(\log n)$ で連結する
+    /// - そうでないときは，`other`の要素を1つずつ挿入する（キーが重複する場合は`other`の値で上書きする）
+    pub fn append(&mut self, other: &mut Self) {
+        let other_root = other.root.take();
+        let is_ordered = match (max_key(&self.root), min_key(&other_root)) {
+            (Some(max), Some(min)) => max < min,
+            _ => true,
+        };
+        if is_ordered {
+            self.root = join2(self.root.take(), other_root);
+        } else {
+            let mut rest = other_root;
+            while let (new_rest, Some((key, value))) = delete_min(rest) {
+                rest = new_rest;
+                self.insert(key, value);
+            }
+        }
+    }
+
+    /// 2つのmapを連結する
+    /// - `left`のすべてのキーが`right`のすべてのキーより小さい必要がある
+    pub fn join(mut left: Self, mut right: Self) -> Self {
+        assert!(
+            match (max_key(&left.root), min_key(&right.root)) {
+                (Some(max), Some(min)) => max < min,
+                _ => true,
+            },
+            "all keys of `left` must be less than those of `right`"
+        );
+        Self {
+            root: join2(left.root.take(), right.root.take()),
+        }
+    }
+
+    /// AVL木の条件（高さの差が1以下）を満たしているか検証する
+    pub fn validate(&self) -> Result<(), String> {
+        validate(&self.root)
+    }
+}
+
+/// 最小のキー
+fn min_key<K: Ord, V>(root: &AvlTreeNode<K, V>) -> Option<&K> {
+    let mut node = root.as_ref()?;
+    while let Some(left) = &node.left {
+        node = left;
+    }
+    Some(&node.key)
+}
+
+/// 最大のキー
+fn max_key<K: Ord, V>(root: &AvlTreeNode<K, V>) -> Option<&K> {
+    let mut node = root.as_ref()?;
+    while let Some(right) = &node.right {
+        node = right;
+    }
+    Some(&node.key)
+}
+
+impl<K: Ord, V> Default for AvlTreeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a AvlTreeMap<K, V> {
+    type IntoIter = AvlTreeIterator<'a, K, V>;
+    type Item = (&'a K, &'a V);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for AvlTreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+impl<K: Ord + Debug, V: Debug> Debug for AvlTreeMap<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord + Debug, V: Debug> AvlTreeMap<K, V> {
+    /// 整形して表示する
+    pub fn pretty_print(&self) {
+        pretty_print(&self.root);
+    }
+}
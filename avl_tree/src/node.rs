@@ -0,0 +1,318 @@
+#![allow(non_snake_case)]
+
+use std::{cmp::Ordering, mem};
+
+/// AVL木のノード
+#[derive(Debug)]
+pub struct AvlTreeNodeInner<K: Ord, V> {
+    pub key: K,
+    pub value: V,
+    /// 部分木の高さ（葉は1）
+    pub height: usize,
+    /// 部分木のノード数
+    pub size: usize,
+    pub left: AvlTreeNode<K, V>,
+    pub right: AvlTreeNode<K, V>,
+}
+
+impl<K: Ord, V> AvlTreeNodeInner<K, V> {
+    pub fn new(key: K, value: V) -> AvlTreeNode<K, V> {
+        Some(Box::new(AvlTreeNodeInner {
+            key,
+            value,
+            height: 1,
+            size: 1,
+            left: None,
+            right: None,
+        }))
+    }
+
+    /// 子の情報から高さと大きさを再計算する
+    fn update(&mut self) {
+        self.height = height(&self.left).max(height(&self.right)) + 1;
+        self.size = len(&self.left) + len(&self.right) + 1;
+    }
+
+    /// 平衡係数（左の高さ - 右の高さ）
+    fn bias(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+pub type AvlTreeNode<K, V> = Option<Box<AvlTreeNodeInner<K, V>>>;
+
+/// 分割した結果：(左の木, 分割位置の要素, 右の木)
+pub type Splitted<K, V> = (AvlTreeNode<K, V>, Option<(K, V)>, AvlTreeNode<K, V>);
+
+/// 部分木の高さ
+pub fn height<K: Ord, V>(node: &AvlTreeNode<K, V>) -> usize {
+    node.as_ref().map_or(0, |node| node.height)
+}
+
+/// 部分木のノード数
+pub fn len<K: Ord, V>(node: &AvlTreeNode<K, V>) -> usize {
+    node.as_ref().map_or(0, |node| node.size)
+}
+
+/// 右回転
+/// ```text
+///      T          L
+///     ↙ ↘        ↙ ↘
+///    L   R  =>  A   T
+///   ↙ ↘            ↙ ↘
+///  A   B          B   R
+/// ```
+fn rotate_right<K: Ord, V>(mut T: Box<AvlTreeNodeInner<K, V>>) -> Box<AvlTreeNodeInner<K, V>> {
+    let Some(mut L) = T.left.take() else {
+        return T;
+    };
+    T.left = L.right.take();
+    T.update();
+    L.right = Some(T);
+    L.update();
+    L
+}
+
+/// 左回転
+/// ```text
+///    T              R
+///   ↙ ↘            ↙ ↘
+///  L   R    =>    T   B
+///     ↙ ↘        ↙ ↘
+///    A   B      L   A
+/// ```
+fn rotate_left<K: Ord, V>(mut T: Box<AvlTreeNodeInner<K, V>>) -> Box<AvlTreeNodeInner<K, V>> {
+    let Some(mut R) = T.right.take() else {
+        return T;
+    };
+    T.right = R.left.take();
+    T.update();
+    R.left = Some(T);
+    R.update();
+    R
+}
+
+/// 左右の高さの差が2以下のノードを再平衡化する
+fn balance<K: Ord, V>(mut T: Box<AvlTreeNodeInner<K, V>>) -> Box<AvlTreeNodeInner<K, V>> {
+    T.update();
+    match T.bias() {
+        // 左が高い
+        2.. => {
+            if T.left.as_ref().unwrap().bias() < 0 {
+                T.left = T.left.take().map(rotate_left);
+            }
+            rotate_right(T)
+        }
+        // 右が高い
+        ..=-2 => {
+            if T.right.as_ref().unwrap().bias() > 0 {
+                T.right = T.right.take().map(rotate_right);
+            }
+            rotate_left(T)
+        }
+        _ => T,
+    }
+}
+
+/// 値`key`の値の参照を取得する
+pub fn get<'a, K: Ord, V>(root: &'a AvlTreeNode<K, V>, key: &K) -> Option<&'a V> {
+    let T = root.as_ref()?;
+    match key.cmp(&T.key) {
+        Ordering::Less => get(&T.left, key),
+        Ordering::Greater => get(&T.right, key),
+        Ordering::Equal => Some(&T.value),
+    }
+}
+
+/// 値`key`の値の可変参照を取得する
+pub fn get_mut<'a, K: Ord, V>(root: &'a mut AvlTreeNode<K, V>, key: &K) -> Option<&'a mut V> {
+    let T = root.as_mut()?;
+    match key.cmp(&T.key) {
+        Ordering::Less => get_mut(&mut T.left, key),
+        Ordering::Greater => get_mut(&mut T.right, key),
+        Ordering::Equal => Some(&mut T.value),
+    }
+}
+
+/// 値`key`に`value`を挿入する
+/// - すでに値が存在する場合は更新し，もとの値を返す
+pub fn insert<K: Ord, V>(
+    root: AvlTreeNode<K, V>,
+    key: K,
+    value: V,
+) -> (AvlTreeNode<K, V>, Option<V>) {
+    let Some(mut T) = root else {
+        return (AvlTreeNodeInner::new(key, value), None);
+    };
+    let old_value = match key.cmp(&T.key) {
+        Ordering::Less => {
+            let old_value;
+            (T.left, old_value) = insert(T.left, key, value);
+            old_value
+        }
+        Ordering::Greater => {
+            let old_value;
+            (T.right, old_value) = insert(T.right, key, value);
+            old_value
+        }
+        Ordering::Equal => {
+            let old_value = mem::replace(&mut T.value, value);
+            return (Some(T), Some(old_value));
+        }
+    };
+    (Some(balance(T)), old_value)
+}
+
+/// 値`key`を削除し，削除されたノードの`(key, value)`を返す
+pub fn delete<K: Ord, V>(root: AvlTreeNode<K, V>, key: &K) -> (AvlTreeNode<K, V>, Option<(K, V)>) {
+    let Some(mut T) = root else {
+        return (None, None);
+    };
+    let old_key_value = match key.cmp(&T.key) {
+        Ordering::Less => {
+            let old_key_value;
+            (T.left, old_key_value) = delete(T.left, key);
+            old_key_value
+        }
+        Ordering::Greater => {
+            let old_key_value;
+            (T.right, old_key_value) = delete(T.right, key);
+            old_key_value
+        }
+        Ordering::Equal => {
+            // 右部分木の最小値を現在のノードに代入
+            let (new_right, min) = delete_min(T.right.take());
+            T.right = new_right;
+            let Some((min_key, min_value)) = min else {
+                return (T.left, Some((T.key, T.value)));
+            };
+            Some((
+                mem::replace(&mut T.key, min_key),
+                mem::replace(&mut T.value, min_value),
+            ))
+        }
+    };
+    (Some(balance(T)), old_key_value)
+}
+
+/// 最小の要素を削除する
+/// - 戻り値：(新しい根, 削除された`(key, value)`)
+pub fn delete_min<K: Ord, V>(root: AvlTreeNode<K, V>) -> (AvlTreeNode<K, V>, Option<(K, V)>) {
+    let Some(mut T) = root else {
+        return (None, None);
+    };
+    if T.left.is_none() {
+        return (T.right, Some((T.key, T.value)));
+    }
+    let min;
+    (T.left, min) = delete_min(T.left);
+    (Some(balance(T)), min)
+}
+
+/// 最大の要素を削除する
+/// - 戻り値：(新しい根, 削除された`(key, value)`)
+pub fn delete_max<K: Ord, V>(root: AvlTreeNode<K, V>) -> (AvlTreeNode<K, V>, Option<(K, V)>) {
+    let Some(mut T) = root else {
+        return (None, None);
+    };
+    if T.right.is_none() {
+        return (T.left, Some((T.key, T.value)));
+    }
+    let max;
+    (T.right, max) = delete_max(T.right);
+    (Some(balance(T)), max)
+}
+
+/// `left`のすべてのキー < `key` < `right`のすべてのキー であるとき，3つを連結する
+/// - 計算量：$O(|h_{left} - h_{right}| + 1)$
+pub fn join<K: Ord, V>(
+    left: AvlTreeNode<K, V>,
+    key: K,
+    value: V,
+    right: AvlTreeNode<K, V>,
+) -> AvlTreeNode<K, V> {
+    let (lh, rh) = (height(&left), height(&right));
+    if lh > rh + 1 {
+        // 左の木の右の辺を下る
+        let mut L = left.unwrap();
+        L.right = join(L.right, key, value, right);
+        Some(balance(L))
+    } else if rh > lh + 1 {
+        // 右の木の左の辺を下る
+        let mut R = right.unwrap();
+        R.left = join(left, key, value, R.left);
+        Some(balance(R))
+    } else {
+        let mut T = AvlTreeNodeInner::new(key, value).unwrap();
+        T.left = left;
+        T.right = right;
+        T.update();
+        Some(T)
+    }
+}
+
+/// `left`のすべてのキー < `right`のすべてのキー であるとき，2つを連結する
+pub fn join2<K: Ord, V>(left: AvlTreeNode<K, V>, right: AvlTreeNode<K, V>) -> AvlTreeNode<K, V> {
+    let (right, min) = delete_min(right);
+    match min {
+        Some((key, value)) => join(left, key, value, right),
+        None => left,
+    }
+}
+
+/// `key`で木を分割する
+/// - 戻り値：(`key`未満の木, `key`に一致する要素, `key`より大きい木)
+pub fn split<K: Ord, V>(root: AvlTreeNode<K, V>, key: &K) -> Splitted<K, V> {
+    let Some(T) = root else {
+        return (None, None, None);
+    };
+    let T = *T;
+    match key.cmp(&T.key) {
+        Ordering::Less => {
+            let (l, mid, r) = split(T.left, key);
+            (l, mid, join(r, T.key, T.value, T.right))
+        }
+        Ordering::Greater => {
+            let (l, mid, r) = split(T.right, key);
+            (join(T.left, T.key, T.value, l), mid, r)
+        }
+        Ordering::Equal => (T.left, Some((T.key, T.value)), T.right),
+    }
+}
+
+/// 木がAVL木の条件を満たしているか検証する
+/// - 二分探索木であること
+/// - 各ノードの左右の部分木の高さの差が1以下であること
+/// - 各ノードが保持する高さ・大きさが正しいこと
+pub fn validate<K: Ord, V>(root: &AvlTreeNode<K, V>) -> Result<(), String> {
+    validate_inner(root, None, None).map(|_| ())
+}
+
+/// 戻り値：(高さ, 大きさ)
+fn validate_inner<K: Ord, V>(
+    root: &AvlTreeNode<K, V>,
+    lower: Option<&K>,
+    upper: Option<&K>,
+) -> Result<(usize, usize), String> {
+    let Some(T) = root else {
+        return Ok((0, 0));
+    };
+    if lower.is_some_and(|lower| lower >= &T.key) || upper.is_some_and(|upper| &T.key >= upper) {
+        return Err("keys are not sorted".to_string());
+    }
+    let (lh, ls) = validate_inner(&T.left, lower, Some(&T.key))?;
+    let (rh, rs) = validate_inner(&T.right, Some(&T.key), upper)?;
+    if lh.abs_diff(rh) > 1 {
+        return Err(format!(
+            "unbalanced node: left height {lh}, right height {rh}"
+        ));
+    }
+    let (h, s) = (lh.max(rh) + 1, ls + rs + 1);
+    if T.height != h {
+        return Err(format!("wrong height: stored {}, actual {h}", T.height));
+    }
+    if T.size != s {
+        return Err(format!("wrong size: stored {}, actual {s}", T.size));
+    }
+    Ok((h, s))
+}
@@ -0,0 +1,32 @@
+//! 木を整形して表示するための関数
+
+use crate::node::AvlTreeNode;
+use std::fmt::Debug;
+
+pub fn pretty_print<K, V>(root: &AvlTreeNode<K, V>)
+where
+    K: Ord + Debug,
+    V: Debug,
+{
+    println!("┌─ Tree ───────────────");
+    fmt_inner(root, 0);
+    println!("└──────────────────────");
+}
+
+/// print recursive
+fn fmt_inner<K, V>(node: &AvlTreeNode<K, V>, depth: usize)
+where
+    K: Ord + Debug,
+    V: Debug,
+{
+    if let Some(node) = node.as_ref() {
+        fmt_inner(&node.left, depth + 1);
+        println!(
+            "│{}({:?}, {:?})",
+            "    ".repeat(depth),
+            node.key,
+            node.value
+        );
+        fmt_inner(&node.right, depth + 1);
+    }
+}
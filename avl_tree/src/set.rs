@@ -0,0 +1,134 @@
+//! 集合型の実装
+
+use std::{fmt::Debug, iter::Map, ops::RangeBounds};
+
+use crate::{iterator::AvlTreeRange, map::AvlTreeMap};
+
+pub struct AvlTreeSet<K: Ord> {
+    map: AvlTreeMap<K, ()>,
+}
+
+/// 区間に含まれる要素を昇順に返すイテレータ
+pub type AvlTreeSetRange<'a, K, R> = Map<AvlTreeRange<'a, K, (), R>, fn((&'a K, &'a ())) -> &'a K>;
+
+impl<K: Ord> AvlTreeSet<K> {
+    /// setの初期化
+    pub fn new() -> Self {
+        Self {
+            map: AvlTreeMap::new(),
+        }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// 木の高さ
+    pub fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    /// 要素が存在するか判定する
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// 要素を追加する
+    /// - 要素が新たに追加された場合は`true`を返す
+    pub fn insert(&mut self, key: K) -> bool {
+        self.map.insert(key, ()).is_none()
+    }
+
+    /// 要素を削除する
+    /// - 要素が存在した場合は`true`を返す
+    pub fn remove(&mut self, key: &K) -> bool {
+        self.map.remove(key).is_some()
+    }
+
+    /// 最小の要素を削除して返す
+    pub fn pop_first(&mut self) -> Option<K> {
+        self.map.pop_first().map(|(key, _)| key)
+    }
+
+    /// 最大の要素を削除して返す
+    pub fn pop_last(&mut self) -> Option<K> {
+        self.map.pop_last().map(|(key, _)| key)
+    }
+
+    /// setのすべての要素を削除する
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// 要素を昇順に返すイテレータ
+    pub fn iter(&self) -> AvlTreeSetRange<'_, K, std::ops::RangeFull> {
+        self.range(..)
+    }
+
+    /// 区間`range`に含まれる要素を昇順に返すイテレータ
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> AvlTreeSetRange<'_, K, R> {
+        self.map.range(range).map(|(key, _)| key)
+    }
+
+    /// `key`以上の要素を切り離して返す
+    pub fn split_off(&mut self, key: &K) -> Self {
+        Self {
+            map: self.map.split_off(key),
+        }
+    }
+
+    /// `other`のすべての要素を移動する
+    pub fn append(&mut self, other: &mut Self) {
+        self.map.append(&mut other.map);
+    }
+
+    /// 2つのsetを連結する
+    /// - `left`のすべての要素が`right`のすべての要素より小さい必要がある
+    pub fn join(left: Self, right: Self) -> Self {
+        Self {
+            map: AvlTreeMap::join(left.map, right.map),
+        }
+    }
+
+    /// AVL木の条件（高さの差が1以下）を満たしているか検証する
+    pub fn validate(&self) -> Result<(), String> {
+        self.map.validate()
+    }
+}
+
+impl<K: Ord> Default for AvlTreeSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, K: Ord> IntoIterator for &'a AvlTreeSet<K> {
+    type IntoIter = AvlTreeSetRange<'a, K, std::ops::RangeFull>;
+    type Item = &'a K;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Ord> FromIterator<K> for AvlTreeSet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for key in iter {
+            set.insert(key);
+        }
+        set
+    }
+}
+
+impl<K: Ord + Debug> Debug for AvlTreeSet<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
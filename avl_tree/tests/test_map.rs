@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use avl_tree::map::AvlTreeMap;
+use rand::prelude::*;
+
+#[test]
+fn test_insert() {
+    let mut map = AvlTreeMap::new();
+
+    let alphabet = [
+        "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa",
+        "lambda", "mu", "nu", "xi", "omicron", "pi", "rho", "sigma", "tau", "upsilon", "phi",
+        "chi", "psi", "omega",
+    ];
+
+    for (i, &a) in alphabet.iter().enumerate() {
+        assert_eq!(map.insert(a, i), None);
+        assert!(map.validate().is_ok());
+    }
+
+    map.pretty_print();
+
+    assert_eq!(map.len(), 24);
+    assert_eq!(map.get(&"iota"), Some(&8));
+    assert_eq!(map.insert("iota", 100), Some(8));
+    assert_eq!(map.get(&"iota"), Some(&100));
+    assert_eq!(map.get(&"foo"), None);
+
+    let mut sorted = alphabet.to_vec();
+    sorted.sort();
+    assert!(map.iter().map(|(&k, _)| k).eq(sorted));
+}
+
+#[test]
+fn test_sorted_height() {
+    let mut map = AvlTreeMap::new();
+
+    for i in 0..(1 << 12) - 1 {
+        map.insert(i, ());
+    }
+
+    // 昇順に挿入すると完全2分木になる
+    assert_eq!(map.height(), 12);
+    assert!(map.validate().is_ok());
+}
+
+#[test]
+fn test_range() {
+    let map: AvlTreeMap<i32, i32> = (0..100).map(|i| (i * 2, i)).collect();
+
+    assert!(map.range(10..20).map(|(&k, _)| k).eq([10, 12, 14, 16, 18]));
+    assert!(map
+        .range(9..=20)
+        .map(|(&k, _)| k)
+        .eq([10, 12, 14, 16, 18, 20]));
+    assert!(map.range(..5).map(|(&k, _)| k).eq([0, 2, 4]));
+    assert!(map.range(195..).map(|(&k, _)| k).eq([196, 198]));
+    assert_eq!(map.range(50..50).count(), 0);
+    assert_eq!(map.range(..).count(), 100);
+}
+
+#[test]
+fn test_random() {
+    const ITER: usize = 5000;
+    const SIZE: u32 = 500;
+
+    let mut rng = thread_rng();
+    let mut map = AvlTreeMap::new();
+    let mut expected = BTreeMap::new();
+
+    for i in 0..ITER {
+        let key = rng.gen_range(0..SIZE);
+        if rng.gen_bool(0.6) {
+            assert_eq!(map.insert(key, i), expected.insert(key, i));
+        } else {
+            assert_eq!(map.remove(&key), expected.remove(&key));
+        }
+        assert_eq!(map.len(), expected.len());
+        assert_eq!(map.get(&key), expected.get(&key));
+
+        if i % 100 == 0 {
+            assert_eq!(map.validate(), Ok(()));
+            let (l, r) = (rng.gen_range(0..SIZE), rng.gen_range(0..SIZE));
+            let (l, r) = (l.min(r), l.max(r));
+            assert!(map.range(l..r).eq(expected.range(l..r)));
+        }
+    }
+
+    assert!(map.iter().eq(expected.iter()));
+}
+
+#[test]
+fn test_split_join() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(0..300);
+        let mut map: AvlTreeMap<u32, u32> = (0..n).map(|i| (i * 3, i)).collect();
+
+        let key = rng.gen_range(0..n * 3 + 3);
+        let right = map.split_off(&key);
+
+        assert_eq!(map.validate(), Ok(()));
+        assert_eq!(right.validate(), Ok(()));
+        assert!(map.iter().all(|(&k, _)| k < key));
+        assert!(right.iter().all(|(&k, _)| k >= key));
+        assert_eq!(map.len() + right.len(), n as usize);
+
+        let joined = AvlTreeMap::join(map, right);
+        assert_eq!(joined.validate(), Ok(()));
+        assert!(joined
+            .iter()
+            .map(|(&k, &v)| (k, v))
+            .eq((0..n).map(|i| (i * 3, i))));
+    }
+}
+
+#[test]
+fn test_append() {
+    // 順序付きの連結
+    let mut left: AvlTreeMap<_, _> = (0..10).map(|i| (i, i)).collect();
+    let mut right: AvlTreeMap<_, _> = (10..1000).map(|i| (i, i)).collect();
+    left.append(&mut right);
+    assert_eq!(left.validate(), Ok(()));
+    assert!(right.is_empty());
+    assert!(left.iter().map(|(&k, _)| k).eq(0..1000));
+
+    // 重複のある連結
+    let mut a: AvlTreeMap<_, _> = (0..100).step_by(2).map(|i| (i, 'a')).collect();
+    let mut b: AvlTreeMap<_, _> = (0..100).step_by(3).map(|i| (i, 'b')).collect();
+    a.append(&mut b);
+    assert_eq!(a.validate(), Ok(()));
+    assert_eq!(a.get(&6), Some(&'b'));
+    assert_eq!(a.get(&4), Some(&'a'));
+    assert_eq!(
+        a.len(),
+        (0..100).filter(|i| i % 2 == 0 || i % 3 == 0).count()
+    );
+}
+
+#[test]
+fn test_pop() {
+    let mut map: AvlTreeMap<_, _> = (0..50).map(|i| (i, i * i)).collect();
+
+    assert_eq!(map.pop_first(), Some((0, 0)));
+    assert_eq!(map.pop_last(), Some((49, 2401)));
+    assert_eq!(map.len(), 48);
+    assert_eq!(map.validate(), Ok(()));
+
+    let mut empty = AvlTreeMap::<i32, i32>::new();
+    assert_eq!(empty.pop_first(), None);
+}
@@ -9,4 +9,27 @@ fn main() {
         tree.insert(x);
         tree.pretty_print();
     }
-}
\ No newline at end of file
+
+    println!("------------------");
+    println!("Height: {}", tree.height());
+
+    // 再平衡化
+    tree.rebalance();
+
+    println!("------------------");
+    println!("Rebalance");
+    tree.pretty_print();
+    println!("Height: {}", tree.height());
+
+    // 自動で再構築する木
+    let mut scapegoat = BinaryTree::<i32>::with_alpha(0.7);
+
+    for x in 0..20 {
+        scapegoat.insert(x);
+    }
+
+    println!("------------------");
+    println!("Scapegoat (alpha = 0.7)");
+    scapegoat.pretty_print();
+    println!("Height: {}", scapegoat.height());
+}
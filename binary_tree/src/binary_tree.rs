@@ -1,5 +1,7 @@
 use std::{cmp::Ordering, fmt};
 
+use crate::rebalance::{count_nodes, rebuild};
+
 #[derive(Debug)]
 pub struct BinaryTreeNode<T> {
     pub value: T,
@@ -11,6 +13,10 @@ pub struct BinaryTreeNode<T> {
 pub struct BinaryTree<T> {
     size: usize,
     pub root: Option<Box<BinaryTreeNode<T>>>,
+    /// 自動で再構築を行う際のパラメータ（`None`のときは再構築しない）
+    alpha: Option<f64>,
+    /// 最後に木全体を再構築してからの要素数の最大値
+    max_size: usize,
 }
 
 impl<T: Ord> BinaryTree<T> {
@@ -18,6 +24,23 @@ impl<T: Ord> BinaryTree<T> {
         BinaryTree {
             size: 0,
             root: None,
+            alpha: None,
+            max_size: 0,
+        }
+    }
+
+    /// 自動で再構築を行う木を作成する（スケープゴート木）
+    /// - 挿入したノードの深さが $\log_{1/\alpha} n$ を超えたとき，バランスの崩れた部分木を再構築する
+    /// - 要素数が最大時の $\alpha$ 倍を下回ったとき，木全体を再構築する
+    /// - `alpha`は $0.5 \le \alpha < 1$ を満たす必要がある
+    pub fn with_alpha(alpha: f64) -> Self {
+        assert!(
+            (0.5..1.0).contains(&alpha),
+            "alpha must satisfy 0.5 <= alpha < 1"
+        );
+        BinaryTree {
+            alpha: Some(alpha),
+            ..Self::new()
         }
     }
 
@@ -30,18 +53,31 @@ impl<T: Ord> BinaryTree<T> {
     }
 
     pub fn insert(&mut self, value: T) -> bool {
-        let res = search_mut(&value, &mut self.root);
-        if res.is_none() {
-            *res = Some(Box::new(BinaryTreeNode {
-                value,
-                left: None,
-                right: None,
-            }));
+        let inserted = match self.alpha {
+            None => {
+                let res = search_mut(&value, &mut self.root);
+                if res.is_none() {
+                    *res = Some(Box::new(BinaryTreeNode {
+                        value,
+                        left: None,
+                        right: None,
+                    }));
+                    true
+                } else {
+                    false
+                }
+            }
+            // 深くなりすぎた場合は再構築
+            Some(alpha) => {
+                let max_depth = ((self.size + 1) as f64).ln() / (1.0 / alpha).ln();
+                insert_scapegoat(&mut self.root, value, 0, max_depth, alpha).is_some()
+            }
+        };
+        if inserted {
             self.size += 1; // 要素数をインクリメント
-            true
-        } else {
-            false
+            self.max_size = self.max_size.max(self.size);
         }
+        inserted
     }
 
     pub fn discard(&mut self, value: &T) -> bool {
@@ -72,9 +108,82 @@ impl<T: Ord> BinaryTree<T> {
                 }
             };
             self.size -= 1; // 要素数をデクリメント
+
+            // 要素数が減りすぎた場合は木全体を再構築
+            if let Some(alpha) = self.alpha {
+                if (self.size as f64) < alpha * self.max_size as f64 {
+                    self.rebalance();
+                }
+            }
             true
         }
     }
+
+    /// 木全体をDay–Stout–Warrenアルゴリズムにより再構築し，高さを $\lfloor \log_2 n \rfloor + 1$ にする
+    /// - 追加のメモリを使わず，$O(n)$ で動作する
+    pub fn rebalance(&mut self) {
+        rebuild(&mut self.root);
+        self.max_size = self.size;
+    }
+
+    /// 木の高さ（空の木は0）
+    pub fn height(&self) -> usize {
+        height_inner(&self.root)
+    }
+
+    /// 最小の要素
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.value)
+    }
+
+    /// 最大の要素
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.value)
+    }
+
+    /// `value`より大きい最小の要素
+    pub fn successor(&self, value: &T) -> Option<&T> {
+        let mut node = &self.root;
+        let mut res = None;
+        while let Some(inner) = node {
+            if value < &inner.value {
+                res = Some(&inner.value);
+                node = &inner.left;
+            } else {
+                node = &inner.right;
+            }
+        }
+        res
+    }
+
+    /// `value`より小さい最大の要素
+    pub fn predecessor(&self, value: &T) -> Option<&T> {
+        let mut node = &self.root;
+        let mut res = None;
+        while let Some(inner) = node {
+            if value > &inner.value {
+                res = Some(&inner.value);
+                node = &inner.right;
+            } else {
+                node = &inner.left;
+            }
+        }
+        res
+    }
+}
+
+impl<T: Ord> Default for BinaryTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T: Ord + fmt::Debug> BinaryTree<T> {
@@ -123,6 +232,65 @@ fn search_mut<'a, T: Ord>(
     }
 }
 
+/// keyを挿入し，深さが`max_depth`を超えた場合はスケープゴートを探して部分木を再構築する
+/// - スケープゴート：挿入されたノードの祖先のうち，子の部分木の大きさが全体の`alpha`倍を超えるもの
+/// - 返り値
+///   - `None`：keyがすでに存在する
+///   - `Some(Some(size))`：スケープゴートを探索中であり，`size`はこの部分木の大きさ
+///   - `Some(None)`：挿入が完了した（再構築が不要，または再構築済み）
+fn insert_scapegoat<T: Ord>(
+    root: &mut Option<Box<BinaryTreeNode<T>>>,
+    value: T,
+    depth: usize,
+    max_depth: f64,
+    alpha: f64,
+) -> Option<Option<usize>> {
+    let Some(node) = root else {
+        *root = Some(Box::new(BinaryTreeNode {
+            value,
+            left: None,
+            right: None,
+        }));
+        return Some((depth as f64 > max_depth).then_some(1));
+    };
+    let (child, sibling) = match value.cmp(&node.value) {
+        Ordering::Equal => return None,
+        Ordering::Less => (&mut node.left, &node.right),
+        Ordering::Greater => (&mut node.right, &node.left),
+    };
+    let Some(child_size) = insert_scapegoat(child, value, depth + 1, max_depth, alpha)? else {
+        return Some(None);
+    };
+    // 子の部分木の大きさから，この部分木の大きさを求める
+    let size = child_size + 1 + count_nodes(sibling);
+    if child_size as f64 > alpha * size as f64 {
+        rebuild(root);
+        return Some(None);
+    }
+    Some(Some(size))
+}
+
+/// 高さを求める
+/// - 偏った木でもスタックが溢れないよう，深さごとにノードを並べて走査する
+fn height_inner<T>(root: &Option<Box<BinaryTreeNode<T>>>) -> usize {
+    let mut height = 0;
+    let mut level: Vec<&BinaryTreeNode<T>> = root.as_deref().into_iter().collect();
+    let mut next = vec![];
+    while !level.is_empty() {
+        height += 1;
+        for node in level.drain(..) {
+            if let Some(left) = &node.left {
+                next.push(&**left);
+            }
+            if let Some(right) = &node.right {
+                next.push(&**right);
+            }
+        }
+        std::mem::swap(&mut level, &mut next);
+    }
+    height
+}
+
 impl<T: Ord> BinaryTreeNode<T> {
     fn rightmost_child(&mut self) -> Option<Box<Self>> {
         match self.right {
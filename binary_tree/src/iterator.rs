@@ -0,0 +1,147 @@
+//! 木を走査するイテレータ
+
+use std::collections::VecDeque;
+
+use crate::binary_tree::{BinaryTree, BinaryTreeNode};
+
+type Link<T> = Option<Box<BinaryTreeNode<T>>>;
+
+/// 中間順（in-order）
+pub struct InOrder<'a, T> {
+    unvisited: Vec<&'a BinaryTreeNode<T>>,
+}
+
+impl<'a, T> InOrder<'a, T> {
+    fn push_left_edge(&mut self, mut node: &'a Link<T>) {
+        while let Some(inner) = node {
+            self.unvisited.push(inner);
+            node = &inner.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.unvisited.pop()?;
+        self.push_left_edge(&node.right);
+        Some(&node.value)
+    }
+}
+
+/// 行きがけ順（pre-order）
+pub struct PreOrder<'a, T> {
+    unvisited: Vec<&'a BinaryTreeNode<T>>,
+}
+
+impl<'a, T> Iterator for PreOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.unvisited.pop()?;
+        // 左の子から先に取り出すため，右の子から積む
+        if let Some(right) = &node.right {
+            self.unvisited.push(right);
+        }
+        if let Some(left) = &node.left {
+            self.unvisited.push(left);
+        }
+        Some(&node.value)
+    }
+}
+
+/// 帰りがけ順（post-order）
+pub struct PostOrder<'a, T> {
+    /// (ノード, 子をすでに積んだか)
+    unvisited: Vec<(&'a BinaryTreeNode<T>, bool)>,
+}
+
+impl<'a, T> Iterator for PostOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, expanded) = self.unvisited.pop()?;
+            if expanded {
+                return Some(&node.value);
+            }
+            self.unvisited.push((node, true));
+            if let Some(right) = &node.right {
+                self.unvisited.push((right, false));
+            }
+            if let Some(left) = &node.left {
+                self.unvisited.push((left, false));
+            }
+        }
+    }
+}
+
+/// 幅優先順（level-order）
+pub struct LevelOrder<'a, T> {
+    unvisited: VecDeque<&'a BinaryTreeNode<T>>,
+}
+
+impl<'a, T> Iterator for LevelOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.unvisited.pop_front()?;
+        if let Some(left) = &node.left {
+            self.unvisited.push_back(left);
+        }
+        if let Some(right) = &node.right {
+            self.unvisited.push_back(right);
+        }
+        Some(&node.value)
+    }
+}
+
+impl<T> BinaryTree<T> {
+    /// 中間順（昇順）に走査する
+    pub fn iter(&self) -> InOrder<'_, T> {
+        self.inorder()
+    }
+
+    /// 中間順（昇順）に走査する
+    pub fn inorder(&self) -> InOrder<'_, T> {
+        let mut iter = InOrder { unvisited: vec![] };
+        iter.push_left_edge(&self.root);
+        iter
+    }
+
+    /// 行きがけ順に走査する
+    pub fn preorder(&self) -> PreOrder<'_, T> {
+        PreOrder {
+            unvisited: self.root.as_deref().into_iter().collect(),
+        }
+    }
+
+    /// 帰りがけ順に走査する
+    pub fn postorder(&self) -> PostOrder<'_, T> {
+        PostOrder {
+            unvisited: self
+                .root
+                .as_deref()
+                .map(|node| (node, false))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// 幅優先順に走査する
+    pub fn levelorder(&self) -> LevelOrder<'_, T> {
+        LevelOrder {
+            unvisited: self.root.as_deref().into_iter().collect(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BinaryTree<T> {
+    type IntoIter = InOrder<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -1 +1,3 @@
 pub mod binary_tree;
+pub mod iterator;
+pub mod rebalance;
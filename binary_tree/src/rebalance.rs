@@ -0,0 +1,113 @@
+//! Day–Stout–Warrenアルゴリズムによる再平衡化
+//!
+//! 1. 右回転を繰り返し，木を右にのみ伸びる連結リスト（vine）にする
+//! 2. 左回転を繰り返し，vineを完全2分木に近い形に折りたたむ
+
+use crate::binary_tree::BinaryTreeNode;
+
+type Link<T> = Option<Box<BinaryTreeNode<T>>>;
+
+/// 右回転
+/// ```text
+///      T          L
+///     / \        / \
+///    L   R  =>  A   T
+///   / \            / \
+///  A   B          B   R
+/// ```
+fn rotate_right<T>(link: &mut Link<T>) {
+    let Some(mut node) = link.take() else {
+        return;
+    };
+    let Some(mut left) = node.left.take() else {
+        *link = Some(node);
+        return;
+    };
+    node.left = left.right.take();
+    left.right = Some(node);
+    *link = Some(left);
+}
+
+/// 左回転
+/// ```text
+///    T              R
+///   / \            / \
+///  L   R    =>    T   B
+///     / \        / \
+///    A   B      L   A
+/// ```
+fn rotate_left<T>(link: &mut Link<T>) {
+    let Some(mut node) = link.take() else {
+        return;
+    };
+    let Some(mut right) = node.right.take() else {
+        *link = Some(node);
+        return;
+    };
+    node.right = right.left.take();
+    right.left = Some(node);
+    *link = Some(right);
+}
+
+/// 木を右にのみ伸びる連結リスト（vine）に変形し，ノード数を返す
+fn tree_to_vine<T>(root: &mut Link<T>) -> usize {
+    let mut count = 0;
+    let mut cur = root;
+    while let Some(node) = cur {
+        if node.left.is_some() {
+            rotate_right(cur);
+        } else {
+            count += 1;
+            cur = &mut cur.as_mut().unwrap().right;
+        }
+    }
+    count
+}
+
+/// vineの先頭から1つおきに`count`回左回転を行う
+fn compress<T>(root: &mut Link<T>, count: usize) {
+    let mut cur = root;
+    for _ in 0..count {
+        rotate_left(cur);
+        cur = &mut cur.as_mut().unwrap().right;
+    }
+}
+
+/// `n`個のノードからなるvineを平衡な木に変形する
+fn vine_to_tree<T>(root: &mut Link<T>, n: usize) {
+    // 完全2分木になるノード数
+    let mut m = (n + 1).next_power_of_two();
+    if m > n + 1 {
+        m /= 2;
+    }
+    m -= 1;
+    // 余分なノードを最下段に移す
+    compress(root, n - m);
+    while m > 1 {
+        m /= 2;
+        compress(root, m);
+    }
+}
+
+/// `root`を根とする部分木を再構築する
+pub fn rebuild<T>(root: &mut Link<T>) {
+    let n = tree_to_vine(root);
+    vine_to_tree(root, n);
+}
+
+/// `root`を根とする部分木のノード数を数える
+/// - 偏った木でもスタックが溢れないよう，明示的なスタックで走査する
+pub fn count_nodes<T>(root: &Link<T>) -> usize {
+    let mut count = 0;
+    let mut unvisited: Vec<&BinaryTreeNode<T>> = root.as_deref().into_iter().collect();
+    while let Some(node) = unvisited.pop() {
+        count += 1;
+        if let Some(left) = &node.left {
+            unvisited.push(left);
+        }
+        if let Some(right) = &node.right {
+            unvisited.push(right);
+        }
+    }
+    count
+}
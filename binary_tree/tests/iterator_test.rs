@@ -0,0 +1,76 @@
+use binary_tree::binary_tree::*;
+
+/// ```text
+///         4
+///       /   \
+///      2     6
+///     / \   / \
+///    1   3 5   7
+/// ```
+fn build() -> BinaryTree<i32> {
+    let mut tree = BinaryTree::new();
+    for x in [4, 2, 6, 1, 3, 5, 7] {
+        tree.insert(x);
+    }
+    tree
+}
+
+#[test]
+fn test_traverse() {
+    let tree = build();
+
+    assert_eq!(
+        tree.inorder().copied().collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5, 6, 7]
+    );
+    assert_eq!(
+        tree.preorder().copied().collect::<Vec<_>>(),
+        [4, 2, 1, 3, 6, 5, 7]
+    );
+    assert_eq!(
+        tree.postorder().copied().collect::<Vec<_>>(),
+        [1, 3, 2, 5, 7, 6, 4]
+    );
+    assert_eq!(
+        tree.levelorder().copied().collect::<Vec<_>>(),
+        [4, 2, 6, 1, 3, 5, 7]
+    );
+    assert_eq!((&tree).into_iter().count(), 7);
+}
+
+#[test]
+fn test_traverse_empty() {
+    let tree = BinaryTree::<i32>::new();
+
+    assert_eq!(tree.inorder().next(), None);
+    assert_eq!(tree.preorder().next(), None);
+    assert_eq!(tree.postorder().next(), None);
+    assert_eq!(tree.levelorder().next(), None);
+}
+
+#[test]
+fn test_min_max_succ_pred() {
+    let mut tree = build();
+
+    assert_eq!(tree.min(), Some(&1));
+    assert_eq!(tree.max(), Some(&7));
+
+    assert_eq!(tree.successor(&0), Some(&1));
+    assert_eq!(tree.successor(&3), Some(&4));
+    assert_eq!(tree.successor(&4), Some(&5));
+    assert_eq!(tree.successor(&7), None);
+
+    assert_eq!(tree.predecessor(&8), Some(&7));
+    assert_eq!(tree.predecessor(&5), Some(&4));
+    assert_eq!(tree.predecessor(&4), Some(&3));
+    assert_eq!(tree.predecessor(&1), None);
+
+    tree.discard(&4);
+    assert_eq!(tree.successor(&3), Some(&5));
+    assert_eq!(tree.predecessor(&5), Some(&3));
+
+    let empty = BinaryTree::<i32>::new();
+    assert_eq!(empty.min(), None);
+    assert_eq!(empty.max(), None);
+    assert_eq!(empty.successor(&0), None);
+}
@@ -0,0 +1,109 @@
+use binary_tree::{binary_tree::*, rebalance::count_nodes};
+use rand::prelude::*;
+
+/// 要素数 `n` の平衡な木の高さ
+fn optimal_height(n: usize) -> usize {
+    (usize::BITS - n.leading_zeros()) as usize
+}
+
+#[test]
+fn test_rebalance() {
+    for n in 0..=100 {
+        let mut tree = BinaryTree::new();
+        for x in 0..n {
+            tree.insert(x);
+        }
+        assert_eq!(tree.height(), n);
+
+        tree.rebalance();
+
+        assert_eq!(tree.height(), optimal_height(n));
+        assert_eq!(tree.len(), n);
+        assert!(tree.iter().copied().eq(0..n));
+    }
+}
+
+#[test]
+fn test_rebalance_random() {
+    let mut rng = thread_rng();
+    let mut tree = BinaryTree::new();
+    let mut values = vec![];
+
+    for _ in 0..1000 {
+        let x: u16 = rng.gen();
+        if tree.insert(x) {
+            values.push(x);
+        }
+    }
+    values.sort();
+
+    tree.rebalance();
+
+    assert_eq!(tree.height(), optimal_height(tree.len()));
+    assert!(tree.iter().eq(values.iter()));
+}
+
+#[test]
+fn test_scapegoat_sorted() {
+    const N: usize = 10000;
+    const ALPHA: f64 = 0.7;
+
+    let mut tree = BinaryTree::with_alpha(ALPHA);
+
+    // 昇順に挿入しても高さが抑えられる
+    for x in 0..N {
+        tree.insert(x);
+        let bound = ((tree.len() as f64).ln() / (1.0 / ALPHA).ln()).floor() as usize + 1;
+        assert!(tree.height() <= bound + 1);
+    }
+    assert!(tree.iter().copied().eq(0..N));
+
+    // 削除しても高さが抑えられる
+    for x in 0..N - 10 {
+        tree.discard(&x);
+        let bound = ((tree.len() as f64).ln() / (1.0 / ALPHA).ln()).floor() as usize + 1;
+        assert!(tree.height() <= bound + 1);
+    }
+    assert!(tree.iter().copied().eq(N - 10..N));
+}
+
+#[test]
+fn test_scapegoat_random() {
+    let mut rng = thread_rng();
+    let mut tree = BinaryTree::with_alpha(0.6);
+    let mut set = std::collections::BTreeSet::new();
+
+    for _ in 0..5000 {
+        let x = rng.gen_range(0..1000);
+        if rng.gen_bool(0.6) {
+            assert_eq!(tree.insert(x), set.insert(x));
+        } else {
+            assert_eq!(tree.discard(&x), set.remove(&x));
+        }
+        assert_eq!(tree.len(), set.len());
+    }
+    assert!(tree.iter().eq(set.iter()));
+}
+
+#[test]
+fn test_rebalance_deep() {
+    const N: usize = 1_000_000;
+
+    // 右にのみ伸びる連結リスト状の木を直接作る
+    let mut tree = BinaryTree::new();
+    for x in (0..N).rev() {
+        tree.root = Some(Box::new(BinaryTreeNode {
+            value: x,
+            left: None,
+            right: tree.root.take(),
+        }));
+    }
+    // 高さ・ノード数の計算でスタックが溢れない
+    assert_eq!(tree.height(), N);
+    assert_eq!(count_nodes(&tree.root), N);
+
+    tree.rebalance();
+
+    assert_eq!(tree.height(), optimal_height(N));
+    assert!(tree.iter().copied().eq(0..N));
+}
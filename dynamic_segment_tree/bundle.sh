@@ -5,6 +5,8 @@ IFS=$'\n'
 files=(
     "alg"
     "dynamic_segment_tree"
+    "dynamic_segment_tree_2d"
+    "iterator"
     "node"
     "print_util"
 )
@@ -12,8 +12,12 @@ pub trait Monoid {
     fn op(left: &Self::Val, right: &Self::Val) -> Self::Val;
 }
 
+/// 可換なモノイド
+/// - 任意の`x`, `y`について`op(x, y) = op(y, x)`が成り立つ
+pub trait Commutative: Monoid {}
+
 pub mod monoids {
-    use super::Monoid;
+    use super::{Commutative, Monoid};
 
     /// 和
     #[derive(Debug, Clone)]
@@ -25,6 +29,47 @@ pub mod monoids {
             left + right
         }
     }
+    impl Commutative for Add {}
+
+    /// 最小値
+    #[derive(Debug, Clone)]
+    pub struct Min;
+    impl Monoid for Min {
+        type Val = isize;
+        const E: Self::Val = isize::MAX;
+        fn op(left: &Self::Val, right: &Self::Val) -> Self::Val {
+            *left.min(right)
+        }
+    }
+    impl Commutative for Min {}
+
+    /// 最大値
+    #[derive(Debug, Clone)]
+    pub struct Max;
+    impl Monoid for Max {
+        type Val = isize;
+        const E: Self::Val = isize::MIN;
+        fn op(left: &Self::Val, right: &Self::Val) -> Self::Val {
+            *left.max(right)
+        }
+    }
+    impl Commutative for Max {}
+
+    /// 最大公約数
+    #[derive(Debug, Clone)]
+    pub struct Gcd;
+    impl Monoid for Gcd {
+        type Val = usize;
+        const E: Self::Val = 0;
+        fn op(left: &Self::Val, right: &Self::Val) -> Self::Val {
+            let (mut a, mut b) = (*left, *right);
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        }
+    }
+    impl Commutative for Gcd {}
 
     /// 文字列
     #[derive(Debug, Clone)]
@@ -3,12 +3,17 @@
 
 use std::{
     fmt::{self, Debug},
+    mem,
     ops::{Bound::Unbounded, Deref, DerefMut, RangeBounds},
 };
 
 use crate::{
     alg::Monoid,
-    node::{delete, get, get_range, insert, Node, NodeInner},
+    iterator::{IntoIter, Keys, Range, Values},
+    node::{
+        delete, first, first_not, get, get_range, insert, join2, last, len, split_by, split_range,
+        Node, NodeInner,
+    },
 };
 
 /// 動的セグメント木
@@ -16,16 +21,12 @@ use crate::{
 /// - 遅延評価なし
 pub struct DynamicSegmentTree<K: Ord, M: Monoid> {
     pub root: Node<K, M>,
-    size: usize,
 }
 
 impl<K: Ord, M: Monoid> DynamicSegmentTree<K, M> {
     /// 動的セグ木の初期化
     pub fn new() -> Self {
-        Self {
-            root: None,
-            size: 0,
-        }
+        Self { root: None }
     }
 
     /// 1点取得（不変参照）
@@ -53,7 +54,6 @@ impl<K: Ord, M: Monoid> DynamicSegmentTree<K, M> {
             }
         } else {
             // ノードの新規作成
-            self.size += 1;
             NodeEntry {
                 root: &mut self.root,
                 key: Some(key),
@@ -66,12 +66,8 @@ impl<K: Ord, M: Monoid> DynamicSegmentTree<K, M> {
     /// - `key`：更新するキー
     /// - `value`：更新後の値
     pub fn insert(&mut self, key: K, value: M::Val) {
-        let (new_root, old_key_value) = insert(self.root.take(), key, value);
+        let (new_root, _) = insert(self.root.take(), key, value);
         self.root = new_root;
-        // 要素が追加された場合
-        if old_key_value.is_none() {
-            self.size += 1;
-        }
     }
 
     /// 要素の削除
@@ -80,12 +76,7 @@ impl<K: Ord, M: Monoid> DynamicSegmentTree<K, M> {
         let (new_root, old_key_value) = delete(self.root.take(), key);
         self.root = new_root;
         // 削除された要素を返す
-        if let Some((_, old_value)) = old_key_value {
-            self.size -= 1;
-            Some(old_value)
-        } else {
-            None
-        }
+        old_key_value.map(|(_, old_value)| old_value)
     }
 
     /// 区間の取得
@@ -98,7 +89,118 @@ impl<K: Ord, M: Monoid> DynamicSegmentTree<K, M> {
 
     /// 要素数を取得
     pub fn len(&self) -> usize {
-        self.size
+        len(&self.root)
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// 要素をキーの昇順に返すイテレータ
+    pub fn iter(&self) -> Range<'_, K, M> {
+        Range::new(&self.root, Unbounded, Unbounded)
+    }
+
+    /// 区間 `range` に含まれる要素をキーの昇順に返すイテレータ
+    /// - `accumulate()` で，区間の先頭からの集約を合わせて取得できる
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, M> {
+        Range::new(&self.root, range.start_bound(), range.end_bound())
+    }
+
+    /// キーを昇順に返すイテレータ
+    pub fn keys(&self) -> Keys<'_, K, M> {
+        Keys(self.iter())
+    }
+
+    /// 値をキーの昇順に返すイテレータ
+    pub fn values(&self) -> Values<'_, K, M> {
+        Values(self.iter())
+    }
+
+    /// キーが最小の要素
+    pub fn first(&self) -> Option<(&K, &M::Val)> {
+        first(&self.root).map(|node| (&node.key, &node.value))
+    }
+
+    /// キーが最大の要素
+    pub fn last(&self) -> Option<(&K, &M::Val)> {
+        last(&self.root).map(|node| (&node.key, &node.value))
+    }
+
+    /// `key` 以上で最小のキーをもつ要素
+    pub fn lower_bound(&self, key: &K) -> Option<(&K, &M::Val)> {
+        first_not(&self.root, &|k| k < key).map(|node| (&node.key, &node.value))
+    }
+
+    /// `key` より大きい最小のキーをもつ要素
+    pub fn upper_bound(&self, key: &K) -> Option<(&K, &M::Val)> {
+        first_not(&self.root, &|k| k <= key).map(|node| (&node.key, &node.value))
+    }
+
+    /// `key` 以上のキーをもつ要素を切り離し，新しい木として返す
+    /// - 計算量：$O(\log n)$
+    pub fn split_off(&mut self, key: &K) -> Self {
+        let (left, right) = split_by(self.root.take(), &|k| k < key);
+        self.root = left;
+        Self { root: right }
+    }
+
+    /// `other` の要素をすべて移動する
+    /// - `other` のキーがすべて自身のキーより大きい場合，$O(\log n)$ で連結する
+    /// - そうでない場合は1つずつ挿入する（キーが重複する場合は `other` の値で上書きする）
+    pub fn append(&mut self, other: &mut Self) {
+        let is_ordered = match (last(&self.root), first(&other.root)) {
+            (Some(l), Some(r)) => l.key < r.key,
+            _ => true,
+        };
+        if is_ordered {
+            self.root = join2(self.root.take(), other.root.take());
+        } else {
+            for (key, value) in mem::take(other) {
+                self.insert(key, value);
+            }
+        }
+    }
+
+    /// 区間 `range` に含まれる要素を切り離し，新しい木として返す
+    /// - 計算量：$O(\log n)$
+    pub fn remove_range<R: RangeBounds<K>>(&mut self, range: R) -> Self {
+        let (left, mid, right) =
+            split_range(self.root.take(), range.start_bound(), range.end_bound());
+        self.root = join2(left, right);
+        Self { root: mid }
+    }
+
+    /// 区間 `range` に含まれる要素を取り除き，キーの昇順に返すイテレータを返す
+    /// - 呼び出した時点で区間全体を`self`から切り離す（$O(\log n)$）．
+    ///   イテレータを最後まで進めずに破棄しても，区間の要素はすべて取り除かれる
+    pub fn drain_range<R: RangeBounds<K>>(&mut self, range: R) -> IntoIter<K, M> {
+        self.remove_range(range).into_iter()
+    }
+}
+
+impl<K: Ord, M: Monoid> Default for DynamicSegmentTree<K, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, K: Ord, M: Monoid> IntoIterator for &'a DynamicSegmentTree<K, M> {
+    type Item = (&'a K, &'a M::Val);
+    type IntoIter = Range<'a, K, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K: Ord, M: Monoid> IntoIterator for DynamicSegmentTree<K, M> {
+    type Item = (K, M::Val);
+    type IntoIter = IntoIter<K, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.root)
     }
 }
 
@@ -0,0 +1,213 @@
+//! 2次元の動的セグ木
+//! - x座標について動的に作成するセグメント木の各ノードに，
+//!   そのx座標の区間に含まれる点を y座標で並べた動的セグ木を持たせる
+//! - 内側のセグ木は (y, x) の順に，外側のセグ木は x の順に集約するため，演算は可換である必要がある
+//! - 点の削除によって空になった外側のノードは回収し，以降のノードの作成に再利用する
+
+use std::ops::{
+    Bound::{self, *},
+    Range, RangeBounds,
+};
+
+use crate::{
+    alg::{Commutative, Monoid},
+    dynamic_segment_tree::DynamicSegmentTree,
+};
+
+/// x座標の型
+type X = isize;
+
+/// 内側のセグ木のキーの区間
+type InnerRange<Y> = (Bound<(Y, X)>, Bound<(Y, X)>);
+
+/// 外側のセグ木のノード
+struct OuterNode<Y: Ord, M: Monoid> {
+    /// 区間に含まれる点を (y, x) の順に並べたもの
+    inner: DynamicSegmentTree<(Y, X), M>,
+    /// 左右の子の番号
+    children: [Option<usize>; 2],
+}
+
+impl<Y: Ord, M: Monoid> OuterNode<Y, M> {
+    fn new() -> Self {
+        Self {
+            inner: DynamicSegmentTree::new(),
+            children: [None, None],
+        }
+    }
+}
+
+/// 2次元の動的セグ木
+/// - x座標は `isize`，y座標は任意の順序付けられた型
+/// - 点の追加・削除：$O(\log W \log n)$，矩形の集約：$O(\log W \log n)$（$W$ はx座標の幅）
+pub struct DynamicSegmentTree2D<Y: Ord + Clone, M: Commutative> {
+    /// x座標の範囲
+    x_range: Range<X>,
+    /// 外側のセグ木のノード（0は根）
+    /// - 根以外のノードは，点を1つ以上含む
+    nodes: Vec<OuterNode<Y, M>>,
+    /// 回収したノードの番号
+    free: Vec<usize>,
+}
+
+impl<Y: Ord + Clone, M: Commutative> DynamicSegmentTree2D<Y, M> {
+    /// x座標が `x_range` に含まれる点を扱う2次元の動的セグ木を作成する
+    pub fn new(x_range: Range<X>) -> Self {
+        assert!(x_range.start < x_range.end, "x_range must not be empty");
+        assert!(
+            x_range.end.checked_sub(x_range.start).is_some(),
+            "the width of x_range must fit in isize"
+        );
+        Self {
+            x_range,
+            nodes: vec![OuterNode::new()],
+            free: vec![],
+        }
+    }
+
+    /// 外側のセグ木のノード数（回収したノードを除く）
+    pub fn node_count(&self) -> usize {
+        self.nodes.len() - self.free.len()
+    }
+
+    /// 点の数
+    pub fn len(&self) -> usize {
+        self.nodes[0].inner.len()
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.nodes[0].inner.is_empty()
+    }
+
+    /// 点 `(x, y)` の値（存在しない場合は単位元）
+    pub fn get(&self, (x, y): &(X, Y)) -> M::Val {
+        self.nodes[0].inner.get(&(y.clone(), *x))
+    }
+
+    /// 点 `(x, y)` に値 `value` を設定する
+    pub fn insert(&mut self, (x, y): (X, Y), value: M::Val) {
+        assert!(
+            self.x_range.contains(&x),
+            "x = {x} is out of {:?}",
+            self.x_range
+        );
+        for idx in self.path_mut(x) {
+            self.nodes[idx].inner.insert((y.clone(), x), value.clone());
+        }
+    }
+
+    /// 点 `(x, y)` を削除し，その値を返す
+    /// - 空になった外側のノードは回収する
+    pub fn remove(&mut self, (x, y): &(X, Y)) -> Option<M::Val> {
+        let key = (y.clone(), *x);
+        let old_value = self.nodes[0].inner.remove(&key)?;
+        let path = self.path_mut(*x);
+        for (depth, &idx) in path.iter().enumerate().skip(1) {
+            self.nodes[idx].inner.remove(&key);
+            // 子孫のノードはこの点のみを含むため，経路の残りをまとめて回収する
+            if self.nodes[idx].inner.is_empty() {
+                let parent = &mut self.nodes[path[depth - 1]];
+                let side = usize::from(parent.children[1] == Some(idx));
+                parent.children[side] = None;
+                for &idx in &path[depth..] {
+                    self.nodes[idx] = OuterNode::new();
+                    self.free.push(idx);
+                }
+                break;
+            }
+        }
+        Some(old_value)
+    }
+
+    /// 根からx座標 `x` の葉までのノードの番号（ノードが存在しない場合は作成する）
+    fn path_mut(&mut self, x: X) -> Vec<usize> {
+        let mut path = vec![0];
+        let (mut begin, mut end) = (self.x_range.start, self.x_range.end);
+        let mut idx = 0;
+        while end - begin > 1 {
+            let mid = begin + (end - begin) / 2;
+            let side = usize::from(x >= mid);
+            if side == 0 {
+                end = mid;
+            } else {
+                begin = mid;
+            }
+            idx = match self.nodes[idx].children[side] {
+                Some(child) => child,
+                None => {
+                    let child = self.free.pop().unwrap_or_else(|| {
+                        self.nodes.push(OuterNode::new());
+                        self.nodes.len() - 1
+                    });
+                    self.nodes[idx].children[side] = Some(child);
+                    child
+                }
+            };
+            path.push(idx);
+        }
+        path
+    }
+
+    /// x座標が `x_range`，y座標が `y_range` に含まれる点の値を集約する
+    pub fn get_range<RX, RY>(&self, x_range: RX, y_range: RY) -> M::Val
+    where
+        RX: RangeBounds<X>,
+        RY: RangeBounds<Y>,
+    {
+        let l = match x_range.start_bound() {
+            Included(&l) => l,
+            Excluded(&l) => l.saturating_add(1),
+            Unbounded => self.x_range.start,
+        };
+        let r = match x_range.end_bound() {
+            Included(&r) => r.saturating_add(1),
+            Excluded(&r) => r,
+            Unbounded => self.x_range.end,
+        };
+        // y座標が等しい点をすべて含むように，x座標の端点をつける
+        let y_begin = match y_range.start_bound() {
+            Included(y) => Included((y.clone(), X::MIN)),
+            Excluded(y) => Excluded((y.clone(), X::MAX)),
+            Unbounded => Unbounded,
+        };
+        let y_end = match y_range.end_bound() {
+            Included(y) => Included((y.clone(), X::MAX)),
+            Excluded(y) => Excluded((y.clone(), X::MIN)),
+            Unbounded => Unbounded,
+        };
+        let (begin, end) = (self.x_range.start, self.x_range.end);
+        self.get_range_inner(0, l, r, (y_begin, y_end), begin, end)
+    }
+
+    fn get_range_inner(
+        &self,
+        idx: usize,
+        l: X,
+        r: X,
+        y_range: InnerRange<Y>,
+        begin: X,
+        end: X,
+    ) -> M::Val {
+        // 区間を含まない
+        if end <= l || r <= begin {
+            M::E
+        }
+        // 区間を包含する
+        else if l <= begin && end <= r {
+            self.nodes[idx].inner.get_range(y_range)
+        }
+        // 区間が一部重なる
+        else {
+            let mid = begin + (end - begin) / 2;
+            let [left, right] = self.nodes[idx].children;
+            let l_val = left.map_or(M::E, |child| {
+                self.get_range_inner(child, l, r, y_range.clone(), begin, mid)
+            });
+            let r_val = right.map_or(M::E, |child| {
+                self.get_range_inner(child, l, r, y_range, mid, end)
+            });
+            M::op(&l_val, &r_val)
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! 動的セグ木のイテレータ
+
+#![allow(non_snake_case)]
+
+use std::ops::Bound::{self, *};
+
+use crate::{
+    alg::Monoid,
+    node::{count_while, Node, NodeInner},
+};
+
+/// 木を消費して，要素をキーの昇順に返すイテレータ
+pub struct IntoIter<K: Ord, M: Monoid> {
+    /// 未訪問のノード（左の子は切り離し済み）
+    stack: Vec<Box<NodeInner<K, M>>>,
+}
+
+impl<K: Ord, M: Monoid> IntoIter<K, M> {
+    pub fn new(root: Node<K, M>) -> Self {
+        let mut iter = Self { stack: vec![] };
+        iter.push_left(root);
+        iter
+    }
+
+    /// ノード `node` から左の子をたどり，スタックに積む
+    fn push_left(&mut self, mut node: Node<K, M>) {
+        while let Some(mut T) = node {
+            node = T.left.take();
+            self.stack.push(T);
+        }
+    }
+}
+
+impl<K: Ord, M: Monoid> Iterator for IntoIter<K, M> {
+    type Item = (K, M::Val);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let T = self.stack.pop()?;
+        let NodeInner {
+            key, value, right, ..
+        } = *T;
+        self.push_left(right);
+        Some((key, value))
+    }
+}
+
+/// 要素をキーの昇順に返すイテレータ
+/// - 両端から取り出せる
+pub struct Range<'a, K: Ord, M: Monoid> {
+    /// 前方の未訪問のノード（左の部分木は訪問済み）
+    front: Vec<&'a NodeInner<K, M>>,
+    /// 後方の未訪問のノード（右の部分木は訪問済み）
+    back: Vec<&'a NodeInner<K, M>>,
+    /// 残りの要素数
+    remaining: usize,
+}
+
+impl<'a, K: Ord, M: Monoid> Range<'a, K, M> {
+    /// 木 `root` のうち，区間 `(l, r)` に含まれる要素を返すイテレータ
+    pub fn new(root: &'a Node<K, M>, l: Bound<&K>, r: Bound<&K>) -> Self {
+        let is_before = |key: &K| match l {
+            Included(l) => key < l,
+            Excluded(l) => key <= l,
+            Unbounded => false,
+        };
+        let is_until = |key: &K| match r {
+            Included(r) => key <= r,
+            Excluded(r) => key < r,
+            Unbounded => true,
+        };
+        let remaining = count_while(root, &is_until).saturating_sub(count_while(root, &is_before));
+
+        // 区間の左端以上で最小のキーまでの経路
+        let mut front = vec![];
+        let mut node = root;
+        while let Some(T) = node {
+            if is_before(&T.key) {
+                node = &T.right;
+            } else {
+                front.push(T.as_ref());
+                node = &T.left;
+            }
+        }
+        // 区間の右端以下で最大のキーまでの経路
+        let mut back = vec![];
+        let mut node = root;
+        while let Some(T) = node {
+            if is_until(&T.key) {
+                back.push(T.as_ref());
+                node = &T.right;
+            } else {
+                node = &T.left;
+            }
+        }
+
+        Self {
+            front,
+            back,
+            remaining,
+        }
+    }
+
+    /// 先頭からの要素の集約を合わせて返すイテレータに変換する
+    pub fn accumulate(self) -> Accumulate<'a, K, M> {
+        Accumulate {
+            range: self,
+            acc: M::E,
+        }
+    }
+}
+
+impl<'a, K: Ord, M: Monoid> Iterator for Range<'a, K, M> {
+    type Item = (&'a K, &'a M::Val);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let T = self.front.pop()?;
+        // 右の子から左の子をたどる
+        let mut node = &T.right;
+        while let Some(child) = node {
+            self.front.push(child.as_ref());
+            node = &child.left;
+        }
+        Some((&T.key, &T.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Ord, M: Monoid> DoubleEndedIterator for Range<'_, K, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let T = self.back.pop()?;
+        // 左の子から右の子をたどる
+        let mut node = &T.left;
+        while let Some(child) = node {
+            self.back.push(child.as_ref());
+            node = &child.right;
+        }
+        Some((&T.key, &T.value))
+    }
+}
+
+impl<K: Ord, M: Monoid> ExactSizeIterator for Range<'_, K, M> {}
+
+/// キーを昇順に返すイテレータ
+pub struct Keys<'a, K: Ord, M: Monoid>(pub(crate) Range<'a, K, M>);
+
+impl<'a, K: Ord, M: Monoid> Iterator for Keys<'a, K, M> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K: Ord, M: Monoid> DoubleEndedIterator for Keys<'_, K, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K: Ord, M: Monoid> ExactSizeIterator for Keys<'_, K, M> {}
+
+/// 値をキーの昇順に返すイテレータ
+pub struct Values<'a, K: Ord, M: Monoid>(pub(crate) Range<'a, K, M>);
+
+impl<'a, K: Ord, M: Monoid> Iterator for Values<'a, K, M> {
+    type Item = &'a M::Val;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<K: Ord, M: Monoid> DoubleEndedIterator for Values<'_, K, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K: Ord, M: Monoid> ExactSizeIterator for Values<'_, K, M> {}
+
+/// 要素と，先頭からその要素までを集約した値を返すイテレータ
+/// - 集約した値は1つ前の値に `M::op` を1回適用して求める
+pub struct Accumulate<'a, K: Ord, M: Monoid> {
+    range: Range<'a, K, M>,
+    acc: M::Val,
+}
+
+impl<'a, K: Ord, M: Monoid> Iterator for Accumulate<'a, K, M> {
+    type Item = (&'a K, &'a M::Val, M::Val);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.range.next()?;
+        self.acc = M::op(&self.acc, value);
+        Some((key, value, self.acc.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<K: Ord, M: Monoid> ExactSizeIterator for Accumulate<'_, K, M> {}
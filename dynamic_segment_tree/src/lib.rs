@@ -1,5 +1,7 @@
 // セグ木
 pub mod alg;
 pub mod dynamic_segment_tree;
+pub mod dynamic_segment_tree_2d;
+pub mod iterator;
 pub mod node;
 pub mod print_util;
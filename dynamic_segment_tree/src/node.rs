@@ -20,6 +20,8 @@ pub struct NodeInner<K: Ord, M: Monoid> {
     pub value: M::Val,
     /// 部分木を集約した値
     acc: M::Val,
+    /// 部分木のノード数
+    size: usize,
     /// ノードの高さ
     pub level: usize,
     pub left: Node<K, M>,
@@ -33,6 +35,7 @@ impl<K: Ord, M: Monoid> NodeInner<K, M> {
             key,
             value: value.clone(),
             acc: value,
+            size: 1,
             level: 1,
             left: None,
             right: None,
@@ -48,9 +51,21 @@ impl<K: Ord, M: Monoid> NodeInner<K, M> {
             (_, Some(r)) => M::op(&self.value, &r.acc),
             _ => self.value.clone(),
         };
+        // ノード数を再計算
+        self.size = len(&self.left) + 1 + len(&self.right);
     }
 }
 
+/// 部分木のノード数
+pub fn len<K: Ord, M: Monoid>(root: &Node<K, M>) -> usize {
+    root.as_ref().map_or(0, |node| node.size)
+}
+
+/// 部分木のレベル（空の木は0）
+fn level<K: Ord, M: Monoid>(root: &Node<K, M>) -> usize {
+    root.as_ref().map_or(0, |node| node.level)
+}
+
 impl<K, M> Debug for NodeInner<K, M>
 where
     K: Ord + Debug,
@@ -345,3 +360,150 @@ fn delete_and_get_max<K: Ord, M: Monoid>(
     new_root = rebarance(new_root);
     (new_root, Some(right_most))
 }
+
+/// キーが `pred` を満たすノードの数
+/// - `pred` を満たすキーは，満たさないキーよりも小さいとする
+pub fn count_while<K: Ord, M: Monoid>(root: &Node<K, M>, pred: &impl Fn(&K) -> bool) -> usize {
+    let mut count = 0;
+    let mut node = root;
+    while let Some(T) = node {
+        if pred(&T.key) {
+            count += len(&T.left) + 1;
+            node = &T.right;
+        } else {
+            node = &T.left;
+        }
+    }
+    count
+}
+
+/// キーが `pred` を満たさないノードのうち，キーが最小のもの
+/// - `pred` を満たすキーは，満たさないキーよりも小さいとする
+pub fn first_not<'a, K: Ord, M: Monoid>(
+    root: &'a Node<K, M>,
+    pred: &impl Fn(&K) -> bool,
+) -> Option<&'a NodeInner<K, M>> {
+    let mut res = None;
+    let mut node = root;
+    while let Some(T) = node {
+        if pred(&T.key) {
+            node = &T.right;
+        } else {
+            res = Some(T.as_ref());
+            node = &T.left;
+        }
+    }
+    res
+}
+
+/// キーが最小のノード
+pub fn first<K: Ord, M: Monoid>(root: &Node<K, M>) -> Option<&NodeInner<K, M>> {
+    let mut node = root.as_ref()?;
+    while let Some(left) = &node.left {
+        node = left;
+    }
+    Some(node)
+}
+
+/// キーが最大のノード
+pub fn last<K: Ord, M: Monoid>(root: &Node<K, M>) -> Option<&NodeInner<K, M>> {
+    let mut node = root.as_ref()?;
+    while let Some(right) = &node.right {
+        node = right;
+    }
+    Some(node)
+}
+
+/// 左の木 `left`，キー `key`，右の木 `right` を連結する
+/// - `left` のキーはすべて `key` より小さく，`right` のキーはすべて `key` より大きいとする
+/// - レベルの高い方の木の端の経路を，もう一方の木と同じレベルになるまで下る
+/// - 計算量：$O(|\mathrm{level}(left) - \mathrm{level}(right)| + 1)$
+pub fn join<K: Ord, M: Monoid>(
+    left: Node<K, M>,
+    key: K,
+    value: M::Val,
+    right: Node<K, M>,
+) -> Node<K, M> {
+    let (left_level, right_level) = (level(&left), level(&right));
+    let mut T = match left_level.cmp(&right_level) {
+        // 左の木の右端を下る
+        Ordering::Greater => {
+            let mut T = left.unwrap();
+            T.right = join(T.right.take(), key, value, right);
+            T
+        }
+        // 右の木の左端を下る
+        Ordering::Less => {
+            let mut T = right.unwrap();
+            T.left = join(left, key, value, T.left.take());
+            T
+        }
+        // 同じレベルの木を子にもつノードをつくる
+        Ordering::Equal => {
+            let mut T = NodeInner::new(key, value).unwrap();
+            T.level = left_level + 1;
+            T.left = left;
+            T.right = right;
+            T
+        }
+    };
+    T.eval();
+    // 再平衡化
+    split(skew(Some(T)))
+}
+
+/// 左の木 `left` と右の木 `right` を連結する
+/// - `left` のキーはすべて `right` のキーより小さいとする
+pub fn join2<K: Ord, M: Monoid>(left: Node<K, M>, right: Node<K, M>) -> Node<K, M> {
+    if left.is_none() {
+        return right;
+    }
+    let (left, Some(max)) = delete_and_get_max(left) else {
+        unreachable!("left is not None");
+    };
+    join(left, max.key, max.value, right)
+}
+
+/// キーが `is_left` を満たすノードからなる木と，満たさないノードからなる木に分割する
+/// - `is_left` を満たすキーは，満たさないキーよりも小さいとする
+pub fn split_by<K: Ord, M: Monoid>(
+    root: Node<K, M>,
+    is_left: &impl Fn(&K) -> bool,
+) -> (Node<K, M>, Node<K, M>) {
+    let Some(T) = root else {
+        return (None, None);
+    };
+    let NodeInner {
+        key,
+        value,
+        left,
+        right,
+        ..
+    } = *T;
+    if is_left(&key) {
+        let (mid, right) = split_by(right, is_left);
+        (join(left, key, value, mid), right)
+    } else {
+        let (left, mid) = split_by(left, is_left);
+        (left, join(mid, key, value, right))
+    }
+}
+
+/// 区間 `(l, r)` に含まれるノードを取り除き，(区間より前, 区間内, 区間より後) の木に分割する
+pub fn split_range<K: Ord, M: Monoid>(
+    root: Node<K, M>,
+    l: Bound<&K>,
+    r: Bound<&K>,
+) -> (Node<K, M>, Node<K, M>, Node<K, M>) {
+    let (left, rest) = split_by(root, &|key| match l {
+        Included(l) => key < l,
+        Excluded(l) => key <= l,
+        Unbounded => false,
+    });
+    let (mid, right) = split_by(rest, &|key| match r {
+        Included(r) => key <= r,
+        Excluded(r) => key < r,
+        Unbounded => true,
+    });
+    (left, mid, right)
+}
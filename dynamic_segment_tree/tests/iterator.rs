@@ -0,0 +1,104 @@
+use std::{collections::BTreeMap, ops::Bound::*};
+
+use dynamic_segment_tree::{
+    alg::monoids::{Add, Str},
+    dynamic_segment_tree::DynamicSegmentTree,
+};
+use rand::prelude::*;
+
+#[test]
+fn test_iter() {
+    let mut seg = DynamicSegmentTree::<i32, Str>::new();
+    for (i, c) in ('A'..='E').enumerate() {
+        seg.insert(i as i32 * 2, c.to_string());
+    }
+    // {0: A, 2: B, 4: C, 6: D, 8: E}
+
+    assert_eq!(seg.keys().collect::<Vec<_>>(), vec![&0, &2, &4, &6, &8]);
+    assert_eq!(seg.values().rev().cloned().collect::<String>(), "EDCBA");
+    assert_eq!(seg.iter().len(), 5);
+
+    let mut iter = seg.range(1..7);
+    assert_eq!(iter.next(), Some((&2, &"B".to_string())));
+    assert_eq!(iter.next_back(), Some((&6, &"D".to_string())));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some((&4, &"C".to_string())));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(seg.first(), Some((&0, &"A".to_string())));
+    assert_eq!(seg.last(), Some((&8, &"E".to_string())));
+    assert_eq!(seg.lower_bound(&4), Some((&4, &"C".to_string())));
+    assert_eq!(seg.upper_bound(&4), Some((&6, &"D".to_string())));
+    assert_eq!(seg.lower_bound(&9), None);
+
+    // 区間の先頭からの集約
+    let prefix: Vec<_> = seg.range(2..).accumulate().map(|(_, _, acc)| acc).collect();
+    assert_eq!(prefix, vec!["B", "BC", "BCD", "BCDE"]);
+}
+
+#[test]
+fn test_empty() {
+    let seg = DynamicSegmentTree::<i32, Add>::new();
+
+    assert_eq!(seg.iter().next(), None);
+    assert_eq!(seg.first(), None);
+    assert_eq!(seg.last(), None);
+    assert_eq!(seg.upper_bound(&0), None);
+}
+
+#[test]
+fn test_random() {
+    const ITER: usize = 200;
+    const SIZE: usize = 300;
+
+    let mut rng = thread_rng();
+
+    for _ in 0..ITER {
+        let mut map = BTreeMap::new();
+        let mut seg = DynamicSegmentTree::<i32, Add>::new();
+        for _ in 0..rng.gen_range(0..SIZE) {
+            let (key, value) = (rng.gen_range(-500..500), rng.gen_range(-1000..1000));
+            map.insert(key, value);
+            seg.insert(key, value);
+        }
+
+        assert!(seg.iter().eq(map.iter()));
+        assert!(seg.keys().rev().eq(map.keys().rev()));
+        assert_eq!(seg.first(), map.first_key_value());
+        assert_eq!(seg.last(), map.last_key_value());
+
+        for _ in 0..10 {
+            let a = rng.gen_range(-600..600);
+            let b = rng.gen_range(a..600);
+
+            assert_eq!(seg.lower_bound(&a), map.range(a..).next());
+            assert_eq!(
+                seg.upper_bound(&a),
+                map.range((Excluded(a), Unbounded)).next()
+            );
+
+            // 前後から交互に取り出す
+            let mut iter = seg.range(a..=b);
+            let mut expected = map.range(a..=b);
+            assert_eq!(iter.len(), expected.clone().count());
+            loop {
+                let front = iter.next();
+                assert_eq!(front, expected.next());
+                let back = iter.next_back();
+                assert_eq!(back, expected.next_back());
+                if front.is_none() || back.is_none() {
+                    break;
+                }
+            }
+
+            // 接頭辞の集約
+            let mut sum = 0;
+            for (key, value, acc) in seg.range(..b).accumulate() {
+                sum += value;
+                assert_eq!(acc, sum);
+                assert_eq!(acc, seg.get_range(..=key));
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use dynamic_segment_tree::{
+    alg::monoids::{Add, Min},
+    dynamic_segment_tree_2d::DynamicSegmentTree2D,
+};
+use rand::prelude::*;
+
+#[test]
+fn test_rect_sum() {
+    let mut seg = DynamicSegmentTree2D::<isize, Add>::new(0..10);
+
+    seg.insert((1, 1), 3);
+    seg.insert((4, 2), 5);
+    seg.insert((4, 7), 2);
+    seg.insert((8, 2), -1);
+    // 同じy座標の点
+    seg.insert((6, 2), 10);
+
+    assert_eq!(seg.len(), 5);
+    assert_eq!(seg.get_range(.., ..), 19);
+    assert_eq!(seg.get_range(0..5, 0..5), 8);
+    assert_eq!(seg.get_range(4..=4, ..), 7);
+    assert_eq!(seg.get_range(.., 2..=2), 14);
+    assert_eq!(seg.get_range(5.., 2..3), 9);
+    assert_eq!(seg.get_range(2..4, ..), 0);
+    assert_eq!(seg.get(&(6, 2)), 10);
+
+    // 更新
+    seg.insert((6, 2), 1);
+    assert_eq!(seg.get_range(.., 2..=2), 5);
+
+    // 削除
+    assert_eq!(seg.remove(&(4, 2)), Some(5));
+    assert_eq!(seg.remove(&(4, 2)), None);
+    assert_eq!(seg.len(), 4);
+    assert_eq!(seg.get_range(0..5, 0..5), 3);
+    assert_eq!(seg.get(&(4, 2)), 0);
+}
+
+#[test]
+fn test_string_y() {
+    let mut seg = DynamicSegmentTree2D::<String, Min>::new(-1_000_000_000..1_000_000_000);
+
+    seg.insert((-5, "apple".to_string()), 4);
+    seg.insert((300_000_000, "banana".to_string()), 2);
+    seg.insert((0, "cherry".to_string()), 7);
+
+    assert_eq!(seg.get_range(.., ..), 2);
+    assert_eq!(seg.get_range(..300_000_000, ..), 4);
+    assert_eq!(seg.get_range(-10..10, "b".to_string()..), 7);
+    assert_eq!(seg.get_range(1..10, ..), isize::MAX);
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_range() {
+    let mut seg = DynamicSegmentTree2D::<isize, Add>::new(0..10);
+    seg.insert((10, 0), 1);
+}
+
+#[test]
+fn test_random() {
+    const ITER: usize = 2000;
+    const W: isize = 50;
+
+    let mut rng = thread_rng();
+    let mut sum = DynamicSegmentTree2D::<isize, Add>::new(-W..W);
+    let mut min = DynamicSegmentTree2D::<isize, Min>::new(-W..W);
+    let mut points = BTreeMap::new();
+
+    for _ in 0..ITER {
+        let point = (rng.gen_range(-W..W), rng.gen_range(-W..W));
+        if rng.gen_bool(0.7) {
+            let value = rng.gen_range(-1000..1000);
+            sum.insert(point, value);
+            min.insert(point, value);
+            points.insert(point, value);
+        } else {
+            let expected = points.remove(&point);
+            assert_eq!(sum.remove(&point), expected);
+            assert_eq!(min.remove(&point), expected);
+        }
+        assert_eq!(sum.len(), points.len());
+
+        let x1 = rng.gen_range(-W..=W);
+        let x2 = rng.gen_range(x1..=W);
+        let y1 = rng.gen_range(-W..=W);
+        let y2 = rng.gen_range(y1..=W);
+        let inside = points
+            .iter()
+            .filter(|&(&(x, y), _)| x1 <= x && x < x2 && y1 <= y && y <= y2)
+            .map(|(_, &v)| v);
+        assert_eq!(
+            sum.get_range(x1..x2, y1..=y2),
+            inside.clone().sum::<isize>()
+        );
+        assert_eq!(
+            min.get_range(x1..x2, y1..=y2),
+            inside.min().unwrap_or(isize::MAX)
+        );
+    }
+}
+
+/// 点の追加・削除を繰り返しても，外側のノードが増え続けない
+#[test]
+fn test_reclaim_nodes() {
+    let mut seg = DynamicSegmentTree2D::<isize, Add>::new(0..1 << 20);
+
+    for i in 0..1000 {
+        let x = (i * 7919) % (1 << 20);
+        seg.insert((x, i), 1);
+        seg.insert((x + 1, i), 1);
+        assert_eq!(seg.remove(&(x, i)), Some(1));
+        assert_eq!(seg.remove(&(x + 1, i)), Some(1));
+        assert!(seg.is_empty());
+        assert_eq!(seg.node_count(), 1);
+    }
+
+    // 同じ経路を共有する点のうち一方のみを削除した場合
+    seg.insert((10, 0), 5);
+    seg.insert((11, 0), 7);
+    let count = seg.node_count();
+    assert_eq!(seg.remove(&(11, 0)), Some(7));
+    assert!(seg.node_count() < count);
+    assert_eq!(seg.get_range(.., ..), 5);
+    seg.insert((11, 0), 2);
+    assert_eq!(seg.node_count(), count);
+    assert_eq!(seg.get_range(11..12, ..), 2);
+    assert_eq!(seg.get_range(.., ..), 7);
+}
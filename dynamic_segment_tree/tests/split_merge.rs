@@ -0,0 +1,190 @@
+use std::{collections::BTreeMap, ops::Bound::*};
+
+use dynamic_segment_tree::{
+    alg::monoids::{Add, Str},
+    dynamic_segment_tree::DynamicSegmentTree,
+    node::Node,
+};
+use rand::prelude::*;
+
+/// AA木の条件を満たすか検証し，部分木のレベルを返す
+fn validate<K: Ord, M: dynamic_segment_tree::alg::Monoid>(root: &Node<K, M>) -> usize {
+    let Some(node) = root else {
+        return 0;
+    };
+    let left_level = validate(&node.left);
+    let right_level = validate(&node.right);
+    // 左の子のレベルはちょうど1小さい
+    assert_eq!(left_level + 1, node.level);
+    // 右の子のレベルは等しいか1小さい
+    assert!(right_level == node.level || right_level + 1 == node.level);
+    // 右の孫のレベルは小さい
+    if let Some(right) = &node.right {
+        assert!(right.right.as_ref().map_or(0, |node| node.level) < node.level);
+    }
+    node.level
+}
+
+/// BTreeMapから動的セグ木を作成する
+fn from_map(map: &BTreeMap<i32, isize>) -> DynamicSegmentTree<i32, Add> {
+    let mut seg = DynamicSegmentTree::new();
+    for (&key, &value) in map {
+        seg.insert(key, value);
+    }
+    seg
+}
+
+#[test]
+fn test_split_off() {
+    let mut seg = DynamicSegmentTree::<i32, Str>::new();
+    for (i, c) in ('A'..='G').enumerate() {
+        seg.insert(i as i32 * 10, c.to_string());
+    }
+
+    let mut right = seg.split_off(&25);
+
+    assert_eq!(seg.len(), 3);
+    assert_eq!(right.len(), 4);
+    assert_eq!(&seg.get_range(..), "ABC");
+    assert_eq!(&right.get_range(..), "DEFG");
+    assert_eq!(&right.get_range(40..=50), "EF");
+    validate(&seg.root);
+    validate(&right.root);
+
+    // 元に戻す
+    seg.append(&mut right);
+    assert!(right.is_empty());
+    assert_eq!(seg.len(), 7);
+    assert_eq!(&seg.get_range(..), "ABCDEFG");
+    validate(&seg.root);
+}
+
+#[test]
+fn test_append_unordered() {
+    let mut a = DynamicSegmentTree::<i32, Str>::new();
+    let mut b = DynamicSegmentTree::<i32, Str>::new();
+    a.insert(1, "a".to_string());
+    a.insert(5, "e".to_string());
+    b.insert(3, "c".to_string());
+    b.insert(5, "E".to_string());
+
+    a.append(&mut b);
+
+    assert!(b.is_empty());
+    assert_eq!(a.len(), 3);
+    assert_eq!(&a.get_range(..), "acE");
+}
+
+#[test]
+fn test_remove_range() {
+    let mut seg = DynamicSegmentTree::<i32, Add>::new();
+    for i in 0..10 {
+        seg.insert(i, 1 << i);
+    }
+
+    let removed = seg.remove_range(3..7);
+    assert_eq!(removed.len(), 4);
+    assert_eq!(removed.get_range(..), 0b1111000);
+    assert_eq!(seg.len(), 6);
+    assert_eq!(seg.get_range(..), 0b1110000111);
+
+    // 古いものから順に取り除く
+    let drained: Vec<_> = seg.drain_range(..=7).collect();
+    assert_eq!(drained, vec![(0, 1), (1, 2), (2, 4), (7, 128)]);
+    assert_eq!(seg.len(), 2);
+    assert_eq!(seg.get_range(..), 0b1100000000);
+
+    // 空の区間
+    assert!(seg.remove_range((Excluded(8), Excluded(9))).is_empty());
+    assert_eq!(seg.len(), 2);
+    validate(&seg.root);
+}
+
+#[test]
+fn test_random() {
+    const ITER: usize = 300;
+    const SIZE: usize = 200;
+
+    let mut rng = thread_rng();
+
+    for _ in 0..ITER {
+        let mut map: BTreeMap<i32, isize> = (0..rng.gen_range(0..SIZE))
+            .map(|_| (rng.gen_range(-500..500), rng.gen_range(-1000..1000)))
+            .collect();
+        let mut seg = from_map(&map);
+
+        let a = rng.gen_range(-600..600);
+        let b = rng.gen_range(a..=600);
+
+        match rng.gen_range(0..3) {
+            // 分割して連結
+            0 => {
+                let mut right = seg.split_off(&a);
+                validate(&seg.root);
+                validate(&right.root);
+                assert_eq!(seg.len(), map.range(..a).count());
+                assert_eq!(right.len(), map.range(a..).count());
+                assert_eq!(seg.get_range(..), map.range(..a).map(|(_, v)| v).sum());
+                assert_eq!(right.get_range(..), map.range(a..).map(|(_, v)| v).sum());
+
+                seg.append(&mut right);
+                validate(&seg.root);
+                assert_eq!(seg.len(), map.len());
+            }
+            // 区間の削除
+            1 => {
+                let removed = seg.remove_range(a..=b);
+                validate(&seg.root);
+                validate(&removed.root);
+                assert_eq!(removed.len(), map.range(a..=b).count());
+                assert_eq!(
+                    removed.get_range(..),
+                    map.range(a..=b).map(|(_, v)| v).sum()
+                );
+                assert_eq!(seg.len(), map.len() - removed.len());
+                map.retain(|k, _| !(a..=b).contains(k));
+            }
+            // 区間の取り出し
+            _ => {
+                let drained: Vec<_> = seg.drain_range((Excluded(a), Excluded(b))).collect();
+                let expected: Vec<_> = map
+                    .iter()
+                    .filter(|&(&k, _)| a < k && k < b)
+                    .map(|(&k, &v)| (k, v))
+                    .collect();
+                assert_eq!(drained, expected);
+                validate(&seg.root);
+                map.retain(|&k, _| !(a < k && k < b));
+            }
+        }
+
+        // 残りの要素との比較
+        let rest: Vec<_> = seg.into_iter().collect();
+        assert_eq!(rest, map.into_iter().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_random_append() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let mut map = BTreeMap::new();
+        let mut seg = DynamicSegmentTree::<i32, Add>::new();
+        for _ in 0..rng.gen_range(1..20) {
+            // 大きさの異なる木を連結する
+            let len = rng.gen_range(0..100);
+            let offset = map.keys().next_back().map_or(0, |k| k + 1);
+            let mut other = DynamicSegmentTree::new();
+            for i in 0..len {
+                let value = rng.gen_range(-100..100);
+                other.insert(offset + i, value);
+                map.insert(offset + i, value);
+            }
+            seg.append(&mut other);
+            validate(&seg.root);
+            assert_eq!(seg.len(), map.len());
+            assert_eq!(seg.get_range(..), map.values().sum());
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! 代数的構造
+//! - モノイドは`dynamic_segment_tree::alg`のものを用いる
+//! - フェニック木はブロックを列の順に結合しないため，可換なモノイド（`Commutative`）のみを扱う
+
+pub use dynamic_segment_tree::alg::{monoids, Commutative, Monoid};
+
+/// 可換群（逆元をもつ可換なモノイド）
+pub trait Group: Commutative {
+    /// 逆元
+    fn inv(x: &Self::Val) -> Self::Val;
+
+    /// `x`を`n`個つなげたもの
+    fn mul(x: &Self::Val, mut n: usize) -> Self::Val {
+        let mut res = Self::E;
+        let mut x = x.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                res = Self::op(&res, &x);
+            }
+            x = Self::op(&x, &x);
+            n >>= 1;
+        }
+        res
+    }
+}
+
+impl Group for monoids::Add {
+    fn inv(x: &Self::Val) -> Self::Val {
+        -x
+    }
+
+    fn mul(x: &Self::Val, n: usize) -> Self::Val {
+        x * n as isize
+    }
+}
@@ -0,0 +1,167 @@
+//! # fenwick tree
+//! - 1点加算・区間取得を行うフェニック木（BIT）
+//! - 演算は可換であるとする（`Commutative`）
+
+use std::{
+    fmt::{self, Debug},
+    ops::{Bound, RangeBounds},
+};
+
+use crate::alg::{Commutative, Group};
+
+/// フェニック木
+/// - 内部の配列は1-indexed
+pub struct FenwickTree<M: Commutative> {
+    n: usize,
+    data: Vec<M::Val>,
+}
+
+impl<M: Commutative> FenwickTree<M> {
+    /// 長さ`n`のフェニック木を単位元で初期化する
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            data: vec![M::E; n + 1],
+        }
+    }
+
+    /// 配列からフェニック木を構築する
+    /// - 計算量：$O(n)$
+    pub fn from_vec(src: Vec<M::Val>) -> Self {
+        let n = src.len();
+        let mut data = vec![M::E];
+        data.extend(src);
+        for i in 1..=n {
+            let j = i + lsb(i);
+            if j <= n {
+                data[j] = M::op(&data[j], &data[i]);
+            }
+        }
+        Self { n, data }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// `i`番目の要素に`x`を加える
+    /// - 計算量：$O(\log n)$
+    pub fn add(&mut self, i: usize, x: M::Val) {
+        assert!(
+            i < self.n,
+            "index out of range: the len is {} but the index is {i}",
+            self.n
+        );
+        let mut i = i + 1;
+        while i <= self.n {
+            self.data[i] = M::op(&self.data[i], &x);
+            i += lsb(i);
+        }
+    }
+
+    /// 区間`[0, r)`の要素を集約する
+    /// - 計算量：$O(\log n)$
+    pub fn prefix_sum(&self, r: usize) -> M::Val {
+        assert!(
+            r <= self.n,
+            "index out of range: the len is {} but the end is {r}",
+            self.n
+        );
+        let mut res = M::E;
+        let mut i = r;
+        while i > 0 {
+            res = M::op(&self.data[i], &res);
+            i -= lsb(i);
+        }
+        res
+    }
+
+    /// `prefix_sum(r) >= w`となる最小の`r`
+    /// - 各要素が単位元以上であり，`prefix_sum`が単調増加であるとする
+    /// - そのような`r`が存在しない場合は`None`を返す
+    /// - 計算量：$O(\log n)$
+    pub fn lower_bound(&self, w: &M::Val) -> Option<usize>
+    where
+        M::Val: PartialOrd,
+    {
+        if &M::E >= w {
+            return Some(0);
+        }
+        // prefix_sum(pos) < w を保ちつつ，posを2冪ずつ伸ばす
+        let mut pos = 0;
+        let mut acc = M::E;
+        let mut step = if self.n == 0 { 0 } else { 1 << self.n.ilog2() };
+        while step > 0 {
+            if pos + step <= self.n {
+                let next = M::op(&acc, &self.data[pos + step]);
+                if &next < w {
+                    pos += step;
+                    acc = next;
+                }
+            }
+            step >>= 1;
+        }
+        (pos < self.n).then_some(pos + 1)
+    }
+}
+
+impl<G: Group> FenwickTree<G> {
+    /// 区間`range`の要素を集約する
+    /// - 計算量：$O(\log n)$
+    pub fn sum<R: RangeBounds<usize>>(&self, range: R) -> G::Val {
+        let (l, r) = parse_range(range, self.n);
+        G::op(&self.prefix_sum(r), &G::inv(&self.prefix_sum(l)))
+    }
+
+    /// `i`番目の要素
+    pub fn get(&self, i: usize) -> G::Val {
+        self.sum(i..=i)
+    }
+
+    /// `i`番目の要素を`x`に更新する
+    pub fn set(&mut self, i: usize, x: G::Val) {
+        let diff = G::op(&x, &G::inv(&self.get(i)));
+        self.add(i, diff);
+    }
+}
+
+impl<M: Commutative> FromIterator<M::Val> for FenwickTree<M> {
+    fn from_iter<I: IntoIterator<Item = M::Val>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<G: Group> Debug for FenwickTree<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.n).map(|i| self.get(i)))
+            .finish()
+    }
+}
+
+/// 最下位の立っているビット
+pub(crate) fn lsb(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+/// 区間`range`を長さ`n`の配列上の半開区間`[l, r)`に変換する
+pub(crate) fn parse_range<R: RangeBounds<usize>>(range: R, n: usize) -> (usize, usize) {
+    let l = match range.start_bound() {
+        Bound::Included(&l) => l,
+        Bound::Excluded(&l) => l + 1,
+        Bound::Unbounded => 0,
+    };
+    let r = match range.end_bound() {
+        Bound::Included(&r) => r + 1,
+        Bound::Excluded(&r) => r,
+        Bound::Unbounded => n,
+    };
+    assert!(l <= r && r <= n, "range [{l}, {r}) is out of [0, {n})");
+    (l, r)
+}
@@ -0,0 +1,92 @@
+//! # 2D fenwick tree
+//! - 2次元の1点加算・矩形取得を行うフェニック木
+
+use std::ops::RangeBounds;
+
+use crate::{
+    alg::{Commutative, Group},
+    fenwick_tree::{lsb, parse_range},
+};
+
+/// 2次元フェニック木
+/// - 内部の配列は1-indexed
+pub struct FenwickTree2D<M: Commutative> {
+    h: usize,
+    w: usize,
+    data: Vec<Vec<M::Val>>,
+}
+
+impl<M: Commutative> FenwickTree2D<M> {
+    /// `h`行`w`列のフェニック木を単位元で初期化する
+    pub fn new(h: usize, w: usize) -> Self {
+        Self {
+            h,
+            w,
+            data: vec![vec![M::E; w + 1]; h + 1],
+        }
+    }
+
+    /// (行数, 列数)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.h, self.w)
+    }
+
+    /// `(i, j)`成分に`x`を加える
+    /// - 計算量：$O(\log h \log w)$
+    pub fn add(&mut self, i: usize, j: usize, x: M::Val) {
+        assert!(
+            i < self.h && j < self.w,
+            "index out of range: the shape is {:?} but the index is {:?}",
+            self.shape(),
+            (i, j)
+        );
+        let mut i = i + 1;
+        while i <= self.h {
+            let mut j = j + 1;
+            while j <= self.w {
+                self.data[i][j] = M::op(&self.data[i][j], &x);
+                j += lsb(j);
+            }
+            i += lsb(i);
+        }
+    }
+
+    /// 矩形`[0, i) × [0, j)`の要素を集約する
+    /// - 計算量：$O(\log h \log w)$
+    pub fn prefix_sum(&self, i: usize, j: usize) -> M::Val {
+        assert!(i <= self.h && j <= self.w);
+        let mut res = M::E;
+        let mut i = i;
+        while i > 0 {
+            let mut j = j;
+            while j > 0 {
+                res = M::op(&self.data[i][j], &res);
+                j -= lsb(j);
+            }
+            i -= lsb(i);
+        }
+        res
+    }
+}
+
+impl<G: Group> FenwickTree2D<G> {
+    /// 矩形`rows × cols`の要素を集約する
+    /// - 計算量：$O(\log h \log w)$
+    pub fn sum<R, C>(&self, rows: R, cols: C) -> G::Val
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (u, d) = parse_range(rows, self.h);
+        let (l, r) = parse_range(cols, self.w);
+        // 包除原理
+        let pos = G::op(&self.prefix_sum(d, r), &self.prefix_sum(u, l));
+        let neg = G::op(&self.prefix_sum(u, r), &self.prefix_sum(d, l));
+        G::op(&pos, &G::inv(&neg))
+    }
+
+    /// `(i, j)`成分
+    pub fn get(&self, i: usize, j: usize) -> G::Val {
+        self.sum(i..=i, j..=j)
+    }
+}
@@ -0,0 +1,5 @@
+// フェニック木
+pub mod alg;
+pub mod fenwick_tree;
+pub mod fenwick_tree_2d;
+pub mod range_fenwick_tree;
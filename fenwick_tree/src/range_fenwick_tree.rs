@@ -0,0 +1,86 @@
+//! # range fenwick tree
+//! - 区間加算・区間取得を行うフェニック木
+//! - 2本のフェニック木で，区間`[0, r)`の和を`r * b1[0, r) - b2[0, r)`として表す
+
+use std::{
+    fmt::{self, Debug},
+    ops::RangeBounds,
+};
+
+use crate::{
+    alg::Group,
+    fenwick_tree::{parse_range, FenwickTree},
+};
+
+/// 区間加算に対応したフェニック木
+pub struct RangeFenwickTree<G: Group> {
+    /// 加算した値
+    b1: FenwickTree<G>,
+    /// 加算した値に位置をかけたもの
+    b2: FenwickTree<G>,
+}
+
+impl<G: Group> RangeFenwickTree<G> {
+    /// 長さ`n`のフェニック木を単位元で初期化する
+    pub fn new(n: usize) -> Self {
+        Self {
+            b1: FenwickTree::new(n),
+            b2: FenwickTree::new(n),
+        }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.b1.len()
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.b1.is_empty()
+    }
+
+    /// 区間`range`の各要素に`x`を加える
+    /// - 計算量：$O(\log n)$
+    pub fn add<R: RangeBounds<usize>>(&mut self, range: R, x: G::Val) {
+        let (l, r) = parse_range(range, self.len());
+        if l == r {
+            return;
+        }
+        self.b1.add(l, x.clone());
+        self.b2.add(l, G::mul(&x, l));
+        // 末尾以降への加算は区間の取得に影響しない
+        if r < self.len() {
+            self.b1.add(r, G::inv(&x));
+            self.b2.add(r, G::inv(&G::mul(&x, r)));
+        }
+    }
+
+    /// 区間`[0, r)`の要素を集約する
+    /// - 計算量：$O(\log n)$
+    pub fn prefix_sum(&self, r: usize) -> G::Val {
+        G::op(
+            &G::mul(&self.b1.prefix_sum(r), r),
+            &G::inv(&self.b2.prefix_sum(r)),
+        )
+    }
+
+    /// 区間`range`の要素を集約する
+    /// - 計算量：$O(\log n)$
+    pub fn sum<R: RangeBounds<usize>>(&self, range: R) -> G::Val {
+        let (l, r) = parse_range(range, self.len());
+        G::op(&self.prefix_sum(r), &G::inv(&self.prefix_sum(l)))
+    }
+
+    /// `i`番目の要素
+    pub fn get(&self, i: usize) -> G::Val {
+        self.sum(i..=i)
+    }
+}
+
+impl<G: Group> Debug for RangeFenwickTree<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.len()).map(|i| self.get(i)))
+            .finish()
+    }
+}
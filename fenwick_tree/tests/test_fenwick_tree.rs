@@ -0,0 +1,101 @@
+use fenwick_tree::{
+    alg::monoids::{Add, Max},
+    fenwick_tree::FenwickTree,
+};
+use rand::Rng;
+
+#[test]
+fn test_add() {
+    let mut ft = FenwickTree::<Add>::new(5);
+
+    ft.add(0, 3);
+    ft.add(2, 5);
+    ft.add(4, -1);
+    ft.add(2, 1);
+
+    assert_eq!(ft.len(), 5);
+    assert_eq!(ft.prefix_sum(0), 0);
+    assert_eq!(ft.prefix_sum(3), 9);
+    assert_eq!(ft.sum(..), 8);
+    assert_eq!(ft.sum(1..=2), 6);
+    assert_eq!(ft.sum(3..3), 0);
+    assert_eq!(ft.get(2), 6);
+
+    ft.set(2, -2);
+    assert_eq!(ft.sum(2..), -3);
+    assert_eq!(format!("{ft:?}"), "[3, 0, -2, 0, -1]");
+}
+
+#[test]
+fn test_from_vec() {
+    let ft: FenwickTree<Add> = (1..=10).collect();
+
+    for r in 0..=10 {
+        assert_eq!(ft.prefix_sum(r), (1..=r as isize).sum());
+    }
+}
+
+#[test]
+fn test_monoid() {
+    let mut ft = FenwickTree::<Max>::from_vec(vec![4, 1, 6, 2]);
+
+    assert_eq!(ft.prefix_sum(0), isize::MIN);
+    assert_eq!(ft.prefix_sum(2), 4);
+    ft.add(1, 5);
+    assert_eq!(ft.prefix_sum(2), 5);
+    assert_eq!(ft.prefix_sum(4), 6);
+
+    // 累積最大値は単調増加
+    assert_eq!(ft.lower_bound(&5), Some(2));
+    assert_eq!(ft.lower_bound(&6), Some(3));
+    assert_eq!(ft.lower_bound(&7), None);
+}
+
+#[test]
+fn test_lower_bound() {
+    let ft = FenwickTree::<Add>::from_vec(vec![1, 0, 2, 3, 0]);
+
+    assert_eq!(ft.lower_bound(&0), Some(0));
+    assert_eq!(ft.lower_bound(&1), Some(1));
+    assert_eq!(ft.lower_bound(&2), Some(3));
+    assert_eq!(ft.lower_bound(&3), Some(3));
+    assert_eq!(ft.lower_bound(&6), Some(4));
+    assert_eq!(ft.lower_bound(&7), None);
+
+    let empty = FenwickTree::<Add>::new(0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.lower_bound(&1), None);
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_range() {
+    let mut ft = FenwickTree::<Add>::new(3);
+    ft.add(3, 1);
+}
+
+#[test]
+fn test_random() {
+    let mut rng = rand::thread_rng();
+
+    for n in [1, 2, 7, 64, 100] {
+        let mut ft = FenwickTree::<Add>::new(n);
+        let mut arr = vec![0; n];
+
+        for _ in 0..1000 {
+            let i = rng.gen_range(0..n);
+            let x = rng.gen_range(0..100);
+            ft.add(i, x);
+            arr[i] += x;
+
+            let l = rng.gen_range(0..=n);
+            let r = rng.gen_range(l..=n);
+            assert_eq!(ft.sum(l..r), arr[l..r].iter().sum::<isize>());
+
+            // 非負なので累積和は単調増加
+            let w = rng.gen_range(0..=arr.iter().sum::<isize>() + 10);
+            let expected = (0..=n).find(|&r| arr[..r].iter().sum::<isize>() >= w);
+            assert_eq!(ft.lower_bound(&w), expected);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use fenwick_tree::{alg::monoids::Add, fenwick_tree_2d::FenwickTree2D};
+use rand::Rng;
+
+#[test]
+fn test_rect() {
+    let mut ft = FenwickTree2D::<Add>::new(3, 4);
+
+    ft.add(0, 0, 1);
+    ft.add(1, 2, 5);
+    ft.add(2, 3, -2);
+    ft.add(1, 2, 1);
+
+    assert_eq!(ft.shape(), (3, 4));
+    assert_eq!(ft.prefix_sum(2, 3), 7);
+    assert_eq!(ft.sum(.., ..), 5);
+    assert_eq!(ft.sum(1.., 2..), 4);
+    assert_eq!(ft.sum(1..2, ..2), 0);
+    assert_eq!(ft.get(1, 2), 6);
+}
+
+#[test]
+fn test_random() {
+    let mut rng = rand::thread_rng();
+    let (h, w) = (13, 8);
+    let mut ft = FenwickTree2D::<Add>::new(h, w);
+    let mut arr = vec![vec![0; w]; h];
+
+    for _ in 0..1000 {
+        let (i, j) = (rng.gen_range(0..h), rng.gen_range(0..w));
+        let x = rng.gen_range(-100..100);
+        ft.add(i, j, x);
+        arr[i][j] += x;
+
+        let u = rng.gen_range(0..=h);
+        let d = rng.gen_range(u..=h);
+        let l = rng.gen_range(0..=w);
+        let r = rng.gen_range(l..=w);
+        let expected: isize = arr[u..d]
+            .iter()
+            .map(|row| row[l..r].iter().sum::<isize>())
+            .sum();
+        assert_eq!(ft.sum(u..d, l..r), expected);
+    }
+}
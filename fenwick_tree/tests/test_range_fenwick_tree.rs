@@ -0,0 +1,39 @@
+use fenwick_tree::{alg::monoids::Add, range_fenwick_tree::RangeFenwickTree};
+use rand::Rng;
+
+#[test]
+fn test_range_add() {
+    let mut ft = RangeFenwickTree::<Add>::new(6);
+
+    ft.add(1..4, 2);
+    ft.add(3.., -1);
+    ft.add(..=0, 5);
+
+    assert_eq!(format!("{ft:?}"), "[5, 2, 2, 1, -1, -1]");
+    assert_eq!(ft.sum(..), 8);
+    assert_eq!(ft.sum(2..5), 2);
+    assert_eq!(ft.prefix_sum(4), 10);
+    assert_eq!(ft.get(3), 1);
+}
+
+#[test]
+fn test_random() {
+    let mut rng = rand::thread_rng();
+
+    for n in [1, 5, 32, 100] {
+        let mut ft = RangeFenwickTree::<Add>::new(n);
+        let mut arr = vec![0; n];
+
+        for _ in 0..1000 {
+            let l = rng.gen_range(0..=n);
+            let r = rng.gen_range(l..=n);
+            let x = rng.gen_range(-100..100);
+            ft.add(l..r, x);
+            arr[l..r].iter_mut().for_each(|v| *v += x);
+
+            let l = rng.gen_range(0..=n);
+            let r = rng.gen_range(l..=n);
+            assert_eq!(ft.sum(l..r), arr[l..r].iter().sum::<isize>());
+        }
+    }
+}
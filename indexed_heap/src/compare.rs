@@ -0,0 +1,34 @@
+//! 要素の比較方法
+
+use std::cmp::Ordering;
+
+/// ヒープの比較関数
+/// - `compare(a, b)` が `Less` のとき，`a` を `b` より先に取り出す
+pub trait Compare<T> {
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// 最小値から取り出す
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Min;
+impl<T: Ord> Compare<T> for Min {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// 最大値から取り出す
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Max;
+impl<T: Ord> Compare<T> for Max {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+/// クロージャによる比較
+impl<T, F: Fn(&T, &T) -> Ordering> Compare<T> for F {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
@@ -0,0 +1,295 @@
+//! # ハンドル付きd分ヒープ
+//! - `push` で返すハンドルにより，ヒープ内の要素の値の変更・削除を行う
+//! - 各要素のヒープ内の位置をスロットに記録し，要素を移動するたびに更新する
+//! - 削除した要素のスロットは再利用し，世代番号によって古いハンドルを区別する
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+};
+
+use crate::compare::{Compare, Min};
+
+/// ヒープ内の要素を指すハンドル
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    slot: usize,
+    generation: u32,
+}
+
+/// ハンドルが指す要素の情報
+struct Slot {
+    /// ヒープ内の位置（削除済みの場合は`None`）
+    pos: Option<usize>,
+    /// スロットを再利用した回数
+    generation: u32,
+}
+
+/// ハンドル付きd分ヒープ
+/// - `C`：比較関数（既定は最小値から取り出す）
+/// - `D`：各ノードの子の数（既定は2分ヒープ）
+pub struct IndexedHeap<T, C = Min, const D: usize = 2> {
+    /// ヒープ順に並べた (スロットの番号, 値)
+    data: Vec<(usize, T)>,
+    slots: Vec<Slot>,
+    /// 空いているスロットの番号
+    free: Vec<usize>,
+    cmp: C,
+}
+
+impl<T, C: Compare<T> + Default, const D: usize> IndexedHeap<T, C, D> {
+    /// 空のヒープを作成する
+    pub fn new() -> Self {
+        Self::with_comparator(C::default())
+    }
+
+    /// 配列からヒープを構築する
+    /// - `i`番目の要素のハンドルは`handles()[i]`
+    /// - 計算量：$O(n)$
+    pub fn from_vec(src: Vec<T>) -> Self {
+        Self::from_vec_with_comparator(src, C::default())
+    }
+}
+
+impl<T, C: Compare<T>, const D: usize> IndexedHeap<T, C, D> {
+    /// 比較関数`cmp`を用いる空のヒープを作成する
+    pub fn with_comparator(cmp: C) -> Self {
+        assert!(D >= 2, "the arity D must be at least 2");
+        Self {
+            data: vec![],
+            slots: vec![],
+            free: vec![],
+            cmp,
+        }
+    }
+
+    /// 比較関数`cmp`を用いて，配列からヒープを構築する
+    /// - 計算量：$O(n)$
+    pub fn from_vec_with_comparator(src: Vec<T>, cmp: C) -> Self {
+        let mut heap = Self::with_comparator(cmp);
+        heap.slots = (0..src.len())
+            .map(|i| Slot {
+                pos: Some(i),
+                generation: 0,
+            })
+            .collect();
+        heap.data = src.into_iter().enumerate().collect();
+        // 葉でないノードを後ろから順に下ろす
+        if heap.data.len() > 1 {
+            for pos in (0..=(heap.data.len() - 2) / D).rev() {
+                heap.sift_down(pos);
+            }
+        }
+        heap
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// ヒープ内のすべての要素のハンドル（スロットの番号順）
+    /// - `from_vec`で構築した直後は，元の配列の順に並ぶ
+    pub fn handles(&self) -> Vec<Handle> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.pos.is_some())
+            .map(|(i, slot)| Handle {
+                slot: i,
+                generation: slot.generation,
+            })
+            .collect()
+    }
+
+    /// ヒープ内の要素と，そのハンドルを順不同で列挙する
+    pub fn iter(&self) -> impl Iterator<Item = (Handle, &T)> {
+        self.data.iter().map(|(slot, value)| {
+            let handle = Handle {
+                slot: *slot,
+                generation: self.slots[*slot].generation,
+            };
+            (handle, value)
+        })
+    }
+
+    /// 先頭の要素を参照する
+    /// - 計算量：$O(1)$
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first().map(|(_, value)| value)
+    }
+
+    /// ハンドルが指す要素を参照する（削除済みの場合は`None`）
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.position(handle).map(|pos| &self.data[pos].1)
+    }
+
+    /// ハンドルが指す要素がヒープ内にあるか
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.position(handle).is_some()
+    }
+
+    /// 要素を追加し，そのハンドルを返す
+    /// - 計算量：$O(\log_D n)$
+    pub fn push(&mut self, value: T) -> Handle {
+        let pos = self.data.len();
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.slots[slot].pos = Some(pos);
+                slot
+            }
+            None => {
+                self.slots.push(Slot {
+                    pos: Some(pos),
+                    generation: 0,
+                });
+                self.slots.len() - 1
+            }
+        };
+        self.data.push((slot, value));
+        self.sift_up(pos);
+        Handle {
+            slot,
+            generation: self.slots[slot].generation,
+        }
+    }
+
+    /// 先頭の要素を取り出す
+    /// - 計算量：$O(D \log_D n)$
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            None
+        } else {
+            Some(self.remove_at(0))
+        }
+    }
+
+    /// ハンドルが指す要素を削除する（削除済みの場合は`None`）
+    /// - 計算量：$O(D \log_D n)$
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let pos = self.position(handle)?;
+        Some(self.remove_at(pos))
+    }
+
+    /// ハンドルが指す要素の値を，先に取り出される値`value`に変更する
+    /// - 計算量：$O(\log_D n)$
+    pub fn decrease_key(&mut self, handle: Handle, value: T) {
+        let pos = self
+            .position(handle)
+            .expect("the handle is not in the heap");
+        assert!(
+            self.cmp.compare(&value, &self.data[pos].1) != Ordering::Greater,
+            "the new value must not come after the current value"
+        );
+        self.data[pos].1 = value;
+        self.sift_up(pos);
+    }
+
+    /// ハンドルが指す要素の値を，後に取り出される値`value`に変更する
+    /// - 計算量：$O(D \log_D n)$
+    pub fn increase_key(&mut self, handle: Handle, value: T) {
+        let pos = self
+            .position(handle)
+            .expect("the handle is not in the heap");
+        assert!(
+            self.cmp.compare(&value, &self.data[pos].1) != Ordering::Less,
+            "the new value must not come before the current value"
+        );
+        self.data[pos].1 = value;
+        self.sift_down(pos);
+    }
+
+    /// ハンドルが指す要素のヒープ内の位置
+    fn position(&self, handle: Handle) -> Option<usize> {
+        self.slots
+            .get(handle.slot)
+            .filter(|slot| slot.generation == handle.generation)
+            .and_then(|slot| slot.pos)
+    }
+
+    /// 位置`pos`の要素を削除する
+    fn remove_at(&mut self, pos: usize) -> T {
+        let last = self.data.len() - 1;
+        self.swap(pos, last);
+        let (slot, value) = self.data.pop().unwrap();
+        // スロットを解放する
+        self.slots[slot].pos = None;
+        self.slots[slot].generation = self.slots[slot].generation.wrapping_add(1);
+        self.free.push(slot);
+        // 末尾から移した要素の位置を修正する
+        if pos < self.data.len() {
+            let pos = self.sift_up(pos);
+            self.sift_down(pos);
+        }
+        value
+    }
+
+    /// 位置`i`の要素が位置`j`の要素より先に取り出されるか
+    fn less(&self, i: usize, j: usize) -> bool {
+        self.cmp.compare(&self.data[i].1, &self.data[j].1) == Ordering::Less
+    }
+
+    /// 位置`i`, `j`の要素を入れ替える
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
+        self.slots[self.data[i].0].pos = Some(i);
+        self.slots[self.data[j].0].pos = Some(j);
+    }
+
+    /// 位置`pos`の要素を根に向かって移動し，移動後の位置を返す
+    fn sift_up(&mut self, mut pos: usize) -> usize {
+        while pos > 0 {
+            let parent = (pos - 1) / D;
+            if !self.less(pos, parent) {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+        pos
+    }
+
+    /// 位置`pos`の要素を葉に向かって移動する
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let first = pos * D + 1;
+            if first >= self.data.len() {
+                break;
+            }
+            let last = (first + D).min(self.data.len());
+            // 最も先に取り出される子
+            let child =
+                (first + 1..last).fold(first, |best, c| if self.less(c, best) { c } else { best });
+            if !self.less(child, pos) {
+                break;
+            }
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+}
+
+impl<T, C: Compare<T> + Default, const D: usize> Default for IndexedHeap<T, C, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C: Compare<T> + Default, const D: usize> FromIterator<T> for IndexedHeap<T, C, D> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T: Debug, C, const D: usize> Debug for IndexedHeap<T, C, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.data.iter().map(|(_, value)| value))
+            .finish()
+    }
+}
@@ -0,0 +1,3 @@
+// ハンドル付きヒープ
+pub mod compare;
+pub mod indexed_heap;
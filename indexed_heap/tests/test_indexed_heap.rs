@@ -0,0 +1,234 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BTreeSet, BinaryHeap},
+};
+
+use indexed_heap::{
+    compare::Max,
+    indexed_heap::{Handle, IndexedHeap},
+};
+use rand::Rng;
+
+#[test]
+fn test_push_pop() {
+    let mut heap = IndexedHeap::<isize>::new();
+
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek(), None);
+    assert_eq!(heap.pop(), None);
+
+    for x in [5, 1, 8, 3, 9, 2] {
+        heap.push(x);
+    }
+
+    assert_eq!(heap.len(), 6);
+    assert_eq!(heap.peek(), Some(&1));
+    assert_eq!(heap.pop(), Some(1));
+    assert_eq!(heap.pop(), Some(2));
+    assert_eq!(heap.pop(), Some(3));
+    assert_eq!(heap.len(), 3);
+}
+
+#[test]
+fn test_decrease_increase_key() {
+    let mut heap = IndexedHeap::<isize>::new();
+
+    let a = heap.push(10);
+    let b = heap.push(20);
+    let c = heap.push(30);
+
+    heap.decrease_key(c, 5);
+    assert_eq!(heap.peek(), Some(&5));
+    assert_eq!(heap.get(c), Some(&5));
+
+    heap.increase_key(c, 25);
+    heap.increase_key(a, 40);
+    assert_eq!(heap.get(a), Some(&40));
+
+    assert_eq!(heap.pop(), Some(20));
+    assert!(!heap.contains(b));
+    assert_eq!(heap.pop(), Some(25));
+    assert_eq!(heap.pop(), Some(40));
+}
+
+#[test]
+fn test_remove() {
+    let mut heap = IndexedHeap::<isize>::new();
+
+    let handles: Vec<Handle> = [4, 7, 1, 9, 3].into_iter().map(|x| heap.push(x)).collect();
+
+    assert_eq!(heap.remove(handles[2]), Some(1));
+    assert_eq!(heap.remove(handles[2]), None);
+    assert_eq!(heap.remove(handles[3]), Some(9));
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.peek(), Some(&3));
+
+    // 再利用されたスロットを古いハンドルで参照しない
+    let d = heap.push(0);
+    assert_eq!(heap.get(handles[2]), None);
+    assert_eq!(heap.get(d), Some(&0));
+    assert!(heap.contains(d));
+}
+
+#[test]
+#[should_panic]
+fn test_decrease_key_wrong_direction() {
+    let mut heap = IndexedHeap::<isize>::new();
+    let a = heap.push(10);
+    heap.decrease_key(a, 11);
+}
+
+#[test]
+#[should_panic]
+fn test_stale_handle() {
+    let mut heap = IndexedHeap::<isize>::new();
+    let a = heap.push(10);
+    heap.pop();
+    heap.increase_key(a, 11);
+}
+
+#[test]
+fn test_from_vec() {
+    let src = vec![6, 2, 9, 4, 4, 1, 7];
+    let mut heap = IndexedHeap::<isize, Max, 3>::from_vec(src.clone());
+    let handles = heap.handles();
+
+    for (i, &handle) in handles.iter().enumerate() {
+        assert_eq!(heap.get(handle), Some(&src[i]));
+    }
+
+    heap.decrease_key(handles[1], 100);
+    assert_eq!(heap.pop(), Some(100));
+
+    let mut sorted = vec![];
+    while let Some(x) = heap.pop() {
+        sorted.push(x);
+    }
+    assert_eq!(sorted, vec![9, 7, 6, 4, 4, 1]);
+}
+
+#[test]
+fn test_comparator() {
+    // 文字列の長さの短い順
+    let mut heap: IndexedHeap<_, _> =
+        IndexedHeap::with_comparator(|a: &&str, b: &&str| a.len().cmp(&b.len()).then(a.cmp(b)));
+
+    let x = heap.push("banana");
+    heap.push("fig");
+    heap.push("apple");
+
+    assert_eq!(heap.peek(), Some(&"fig"));
+    heap.decrease_key(x, "kiwi");
+    assert_eq!(heap.pop(), Some("fig"));
+    assert_eq!(heap.pop(), Some("kiwi"));
+    assert_eq!(heap.pop(), Some("apple"));
+    assert_eq!(format!("{heap:?}"), "[]");
+}
+
+/// 値の変更・削除を含むランダムな操作を`BTreeSet`と比較する
+fn random_operations<const D: usize>() {
+    let mut rng = rand::thread_rng();
+    let mut heap = IndexedHeap::<(isize, usize), _, D>::with_comparator(
+        |a: &(isize, usize), b: &(isize, usize)| a.cmp(b),
+    );
+    let mut set = BTreeSet::new();
+    let mut handles: Vec<(Handle, (isize, usize))> = vec![];
+
+    for id in 0..5000 {
+        match rng.gen_range(0..5) {
+            0 | 1 => {
+                let value = (rng.gen_range(-1000..1000), id);
+                handles.push((heap.push(value), value));
+                set.insert(value);
+            }
+            2 if !handles.is_empty() => {
+                let i = rng.gen_range(0..handles.len());
+                let (handle, old) = handles[i];
+                let new = (old.0 + rng.gen_range(-100..100), old.1);
+                match new.cmp(&old) {
+                    Ordering::Less => heap.decrease_key(handle, new),
+                    _ => heap.increase_key(handle, new),
+                }
+                set.remove(&old);
+                set.insert(new);
+                handles[i].1 = new;
+            }
+            3 if !handles.is_empty() => {
+                let i = rng.gen_range(0..handles.len());
+                let (handle, value) = handles.swap_remove(i);
+                assert_eq!(heap.remove(handle), Some(value));
+                set.remove(&value);
+            }
+            _ => {
+                let expected = set.pop_first();
+                assert_eq!(heap.pop(), expected);
+                if let Some(value) = expected {
+                    let i = handles.iter().position(|&(_, v)| v == value).unwrap();
+                    let (handle, _) = handles.swap_remove(i);
+                    assert!(!heap.contains(handle));
+                }
+            }
+        }
+        assert_eq!(heap.len(), set.len());
+        assert_eq!(heap.peek(), set.first());
+    }
+}
+
+#[test]
+fn test_random_binary() {
+    random_operations::<2>();
+}
+
+#[test]
+fn test_random_4ary() {
+    random_operations::<4>();
+}
+
+/// `decrease_key`を用いたダイクストラ法を，`BinaryHeap`による実装と比較する
+#[test]
+fn test_dijkstra() {
+    let mut rng = rand::thread_rng();
+    let n = 200;
+    let mut graph = vec![vec![]; n];
+    for _ in 0..2000 {
+        let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+        graph[u].push((v, rng.gen_range(1..100)));
+    }
+
+    // decrease_key による実装
+    let mut dist = vec![usize::MAX; n];
+    let mut handles: Vec<Option<Handle>> = vec![None; n];
+    let mut heap = IndexedHeap::<(usize, usize)>::new();
+    dist[0] = 0;
+    handles[0] = Some(heap.push((0, 0)));
+    while let Some((d, u)) = heap.pop() {
+        for &(v, w) in &graph[u] {
+            if d + w < dist[v] {
+                dist[v] = d + w;
+                match handles[v] {
+                    Some(handle) if heap.contains(handle) => heap.decrease_key(handle, (d + w, v)),
+                    _ => handles[v] = Some(heap.push((d + w, v))),
+                }
+            }
+        }
+    }
+
+    // BinaryHeap による実装
+    let mut expected = vec![usize::MAX; n];
+    let mut pq = BinaryHeap::new();
+    expected[0] = 0;
+    pq.push(Reverse((0, 0)));
+    while let Some(Reverse((d, u))) = pq.pop() {
+        if d > expected[u] {
+            continue;
+        }
+        for &(v, w) in &graph[u] {
+            if d + w < expected[v] {
+                expected[v] = d + w;
+                pq.push(Reverse((d + w, v)));
+            }
+        }
+    }
+
+    assert_eq!(dist, expected);
+}
@@ -0,0 +1,204 @@
+//! 区間をキーとする辞書型
+//! - 区間は半開区間 `[lo, hi)` として扱う
+
+#![allow(non_snake_case)]
+
+use std::{
+    fmt::{self, Debug},
+    ops::{Bound, Range},
+};
+
+use crate::node::{delete, get, get_mut, insert, overlaps, Node, NodeInner};
+
+/// 区間木
+/// - 平衡2分木（AA木）
+/// - 各ノードは部分木に含まれる区間の右端の最大値を持つ
+pub struct IntervalMap<K: Ord + Clone, V> {
+    pub root: Node<K, V>,
+    size: usize,
+}
+
+impl<K: Ord + Clone, V> IntervalMap<K, V> {
+    /// 区間木の初期化
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// 要素数を取得
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// 区間 `range` に対応する値の参照を取得する
+    pub fn get(&self, range: &Range<K>) -> Option<&V> {
+        get(&self.root, range).map(|node| &node.value)
+    }
+
+    /// 区間 `range` に対応する値の**可変**参照を取得する
+    pub fn get_mut(&mut self, range: &Range<K>) -> Option<&mut V> {
+        get_mut(&mut self.root, range)
+    }
+
+    /// 区間 `range` に値 `value` を挿入する
+    /// - 同じ区間がすでに存在する場合には更新し，もとの値を返す
+    /// - 空の区間を挿入しようとしたときパニックする
+    pub fn insert(&mut self, range: Range<K>, value: V) -> Option<V> {
+        assert!(range.start < range.end, "the interval must not be empty");
+        let (new_root, old_key_value) = insert(self.root.take(), range, value);
+        self.root = new_root;
+        if old_key_value.is_none() {
+            self.size += 1;
+        }
+        old_key_value.map(|(_, old_value)| old_value)
+    }
+
+    /// 区間 `range` を削除し，対応する値を返す
+    pub fn remove(&mut self, range: &Range<K>) -> Option<V> {
+        let (new_root, old_key_value) = delete(self.root.take(), range);
+        self.root = new_root;
+        let (_, old_value) = old_key_value?;
+        self.size -= 1;
+        Some(old_value)
+    }
+
+    /// 区間 `query` と共通部分を持つ区間を，左端の昇順に列挙する
+    /// - `query` が空のときは何も列挙しない
+    pub fn overlapping(&self, query: Range<K>) -> Overlapping<'_, K, V> {
+        let root = if query.start < query.end {
+            &self.root
+        } else {
+            &None
+        };
+        Overlapping::new(root, query.start, Bound::Excluded(query.end))
+    }
+
+    /// 点 `point` を含む区間を，左端の昇順に列挙する
+    pub fn stabbing(&self, point: K) -> Overlapping<'_, K, V> {
+        Overlapping::new(&self.root, point.clone(), Bound::Included(point))
+    }
+
+    /// 区間 `query` と共通部分を持つ区間が存在するか判定する
+    pub fn any_overlap(&self, query: Range<K>) -> bool {
+        self.overlapping(query).next().is_some()
+    }
+
+    /// すべての区間を左端の昇順に列挙する
+    pub fn iter(&self) -> Overlapping<'_, K, V> {
+        let mut iter = Overlapping {
+            unvisited: vec![],
+            start: None,
+            end: Bound::Unbounded,
+        };
+        iter.push_left_edge(&self.root);
+        iter
+    }
+
+    /// すべての要素を削除する
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.size = 0;
+    }
+}
+
+impl<K: Ord + Clone, V> Default for IntervalMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone + Debug, V: Debug> Debug for IntervalMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord + Clone, V> FromIterator<(Range<K>, V)> for IntervalMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (Range<K>, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (range, value) in iter {
+            map.insert(range, value);
+        }
+        map
+    }
+}
+
+// ----- iterator -----
+/// 区間 `[start, end)` と共通部分を持つ区間のイテレータ
+/// - `start` が `None` のときは下限なし
+pub struct Overlapping<'a, K: Ord + Clone, V> {
+    unvisited: Vec<&'a NodeInner<K, V>>,
+    start: Option<K>,
+    end: Bound<K>,
+}
+
+impl<'a, K: Ord + Clone, V> Overlapping<'a, K, V> {
+    fn new(root: &'a Node<K, V>, start: K, end: Bound<K>) -> Self {
+        let mut iter = Self {
+            unvisited: vec![],
+            start: Some(start),
+            end,
+        };
+        iter.push_left_edge(root);
+        iter
+    }
+
+    /// 部分木の右端がすべて `start` 以下であるとき，その部分木は探索しない
+    fn push_left_edge(&mut self, mut node: &'a Node<K, V>) {
+        while let Some(T) = node.as_deref() {
+            if self
+                .start
+                .as_ref()
+                .is_some_and(|start| T.max_end() <= start)
+            {
+                break;
+            }
+            self.unvisited.push(T);
+            node = &T.left;
+        }
+    }
+}
+
+impl<'a, K: Ord + Clone, V> Iterator for Overlapping<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(T) = self.unvisited.pop() {
+            // 左端が区間の右側にあるとき，以降の区間はすべて共通部分を持たない
+            let before_end = match &self.end {
+                Bound::Included(e) => &T.key.start <= e,
+                Bound::Excluded(e) => &T.key.start < e,
+                Bound::Unbounded => true,
+            };
+            if !before_end {
+                self.unvisited.clear();
+                return None;
+            }
+            self.push_left_edge(&T.right);
+            let hit = match &self.start {
+                Some(start) => overlaps(&T.key, start, self.end.as_ref()),
+                None => true,
+            };
+            if hit {
+                return Some((&T.key, &T.value));
+            }
+        }
+        None
+    }
+}
+
+impl<'a, K: Ord + Clone, V> IntoIterator for &'a IntervalMap<K, V> {
+    type IntoIter = Overlapping<'a, K, V>;
+    type Item = (&'a Range<K>, &'a V);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,2 @@
+pub mod interval_map;
+pub mod node;
@@ -0,0 +1,304 @@
+//! 区間木のノード
+
+#![allow(non_snake_case)]
+
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    mem,
+    ops::{Bound, Range},
+};
+
+/// AA木のノード
+pub type Node<K, V> = Option<Box<NodeInner<K, V>>>;
+
+/// 区間と値の組
+pub type Entry<K, V> = (Range<K>, V);
+
+pub struct NodeInner<K: Ord + Clone, V> {
+    /// 区間 `[lo, hi)`
+    pub key: Range<K>,
+    /// ノードが持つ値
+    pub value: V,
+    /// 部分木に含まれる区間の右端の最大値
+    max_end: K,
+    /// ノードの高さ
+    pub level: usize,
+    pub left: Node<K, V>,
+    pub right: Node<K, V>,
+}
+
+impl<K: Ord + Clone, V> NodeInner<K, V> {
+    /// ノードの作成
+    pub fn new(key: Range<K>, value: V) -> Node<K, V> {
+        Some(Box::new(NodeInner {
+            max_end: key.end.clone(),
+            key,
+            value,
+            level: 1,
+            left: None,
+            right: None,
+        }))
+    }
+
+    /// 部分木に含まれる区間の右端の最大値
+    pub fn max_end(&self) -> &K {
+        &self.max_end
+    }
+
+    /// ノードの値を再計算する
+    fn eval(&mut self) {
+        let mut max_end = &self.key.end;
+        if let Some(l) = &self.left {
+            max_end = max_end.max(&l.max_end);
+        }
+        if let Some(r) = &self.right {
+            max_end = max_end.max(&r.max_end);
+        }
+        self.max_end = max_end.clone();
+    }
+}
+
+impl<K, V> Debug for NodeInner<K, V>
+where
+    K: Ord + Clone + Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Node")
+            .field("key", &self.key)
+            .field("value", &self.value)
+            .field("max_end", &self.max_end)
+            .finish()
+    }
+}
+
+/// 区間を `(lo, hi)` の辞書順で比較する
+#[inline]
+fn cmp_range<K: Ord>(x: &Range<K>, y: &Range<K>) -> Ordering {
+    x.start.cmp(&y.start).then_with(|| x.end.cmp(&y.end))
+}
+
+/// skew操作
+/// ```text
+///   |        ⇓           ⇓
+/// 2 |    L ← T           L → T
+///   |   ↙ ↘   ↘   ==>   ↙   ↙ ↘
+/// 1 |  A   B   R       A   B   R
+/// ```
+fn skew<K: Ord + Clone, V>(node: Node<K, V>) -> Node<K, V> {
+    let mut T = node?;
+    if T.left.is_none() {
+        Some(T)
+    } else if T.level == T.left.as_ref().unwrap().level {
+        let mut L = T.left.unwrap();
+        // Tを更新
+        T.left = L.right;
+        T.eval();
+        // Lを更新
+        L.right = Some(T);
+        L.eval();
+        Some(L)
+    } else {
+        Some(T)
+    }
+}
+
+/// split操作
+/// ```text
+///   |                         ⇓
+/// 3 |                         R
+///   |    ⇓                   ↙ ↘
+/// 2 |    T → R → X   ==>    T   X
+///   |   ↙   ↙              ↙ ↘
+/// 1 |  A   B              A   B
+/// ```
+fn split<K: Ord + Clone, V>(node: Node<K, V>) -> Node<K, V> {
+    let mut T = node?;
+    if T.right.is_none() || T.right.as_ref().unwrap().right.is_none() {
+        Some(T)
+    } else if T.level == T.right.as_ref().unwrap().right.as_ref().unwrap().level {
+        let mut R = T.right.unwrap();
+        // Tを更新
+        T.right = R.left;
+        T.eval();
+        // Rを更新
+        R.left = Some(T);
+        R.eval();
+        R.level += 1; // Rのレベルを1上げる
+        Some(R)
+    } else {
+        Some(T)
+    }
+}
+
+/// 区間 `key` を持つノードの不変参照を取得する
+pub fn get<'a, K: Ord + Clone, V>(
+    root: &'a Node<K, V>,
+    key: &Range<K>,
+) -> Option<&'a NodeInner<K, V>> {
+    let T = root.as_ref()?;
+    match cmp_range(key, &T.key) {
+        Ordering::Less => get(&T.left, key),
+        Ordering::Greater => get(&T.right, key),
+        Ordering::Equal => Some(T),
+    }
+}
+
+/// 区間 `key` を持つノードの可変参照を取得する
+pub fn get_mut<'a, K: Ord + Clone, V>(
+    root: &'a mut Node<K, V>,
+    key: &Range<K>,
+) -> Option<&'a mut V> {
+    let T = root.as_mut()?;
+    match cmp_range(key, &T.key) {
+        Ordering::Less => get_mut(&mut T.left, key),
+        Ordering::Greater => get_mut(&mut T.right, key),
+        Ordering::Equal => Some(&mut T.value),
+    }
+}
+
+/// ノードの区間が区間 `[start, end)`（`end` が `Included` のときは `[start, end]`）と共通部分を持つか判定
+#[inline]
+pub fn overlaps<K: Ord>(key: &Range<K>, start: &K, end: Bound<&K>) -> bool {
+    start < &key.end
+        && match end {
+            Bound::Included(e) => &key.start <= e,
+            Bound::Excluded(e) => &key.start < e,
+            Bound::Unbounded => true,
+        }
+}
+
+/// 区間 `key` に `value` を挿入する
+/// - 区間がすでに存在する場合には更新し，もとの値を返す
+pub fn insert<K: Ord + Clone, V>(
+    root: Node<K, V>,
+    key: Range<K>,
+    value: V,
+) -> (Node<K, V>, Option<Entry<K, V>>) {
+    let Some(mut T) = root else {
+        return (NodeInner::new(key, value), None);
+    };
+    // 挿入
+    let old_key_value = match cmp_range(&key, &T.key) {
+        Ordering::Less => {
+            let (new_left, old_key_value) = insert(T.left, key, value);
+            T.left = new_left;
+            old_key_value
+        }
+        Ordering::Greater => {
+            let (new_right, old_key_value) = insert(T.right, key, value);
+            T.right = new_right;
+            old_key_value
+        }
+        Ordering::Equal => Some((
+            mem::replace(&mut T.key, key),
+            mem::replace(&mut T.value, value),
+        )),
+    };
+    // ノードの評価
+    T.eval();
+    // 再平衡化
+    let mut root = Some(T);
+    root = skew(root);
+    root = split(root);
+    (root, old_key_value)
+}
+
+/// 区間 `key` をもつノードを削除し，削除されたノードを返す
+/// - `root`：削除する木の根
+pub fn delete<K: Ord + Clone, V>(
+    root: Node<K, V>,
+    key: &Range<K>,
+) -> (Node<K, V>, Option<Entry<K, V>>) {
+    let Some(mut T) = root else {
+        return (None, None);
+    };
+    let (mut new_root, old_key_value) = match cmp_range(key, &T.key) {
+        Ordering::Less => {
+            let (new_left, old_key_value) = delete(T.left, key);
+            T.left = new_left;
+            (Some(T), old_key_value)
+        }
+        Ordering::Greater => {
+            let (new_right, old_key_value) = delete(T.right, key);
+            T.right = new_right;
+            (Some(T), old_key_value)
+        }
+        Ordering::Equal => {
+            if T.left.is_none() {
+                (T.right, Some((T.key, T.value)))
+            } else if T.right.is_none() {
+                (T.left, Some((T.key, T.value)))
+            } else {
+                // 左右の子を持つ場合，左の子の最大値を現在のノードに代入
+                let (new_left, right_most) = delete_and_get_max(T.left.take());
+                T.left = new_left;
+                let Some(right_most) = right_most else {
+                    unreachable!("T.left is not None");
+                };
+                let old_key_value = (
+                    mem::replace(&mut T.key, right_most.key),
+                    mem::replace(&mut T.value, right_most.value),
+                );
+                (Some(T), Some(old_key_value))
+            }
+        }
+    };
+    // 評価
+    if let Some(T) = &mut new_root {
+        T.eval();
+    }
+    // バランスの修正
+    let rebalanced = rebarance(new_root);
+    (rebalanced, old_key_value)
+}
+
+/// 削除後の頂点を再平衡化
+fn rebarance<K: Ord + Clone, V>(root: Node<K, V>) -> Node<K, V> {
+    let mut T = root?;
+    let left_level = T.left.as_ref().map_or(0, |node| node.level);
+    let right_level = T.right.as_ref().map_or(0, |node| node.level);
+    if left_level.min(right_level) < T.level - 1 {
+        T.level -= 1;
+        // 右が大きい場合，下げる
+        if right_level > T.level {
+            T.right.as_mut().unwrap().level = T.level;
+        }
+        // 同じレベルのノードをskew
+        T = skew(Some(T)).unwrap();
+        T.right = skew(T.right);
+        if let Some(mut right) = T.right.take() {
+            right.right = skew(right.right);
+            T.right.replace(right);
+        }
+        // 同じレベルのノードをsplit
+        T = split(Some(T)).unwrap();
+        T.right = split(T.right);
+        // ノードの再評価
+        T.eval();
+    }
+    Some(T)
+}
+
+/// nodeを根とする木のうち，値が最大のものを削除する
+/// - 戻り値：(新しい根, 削除されたノード)
+fn delete_and_get_max<K: Ord + Clone, V>(
+    root: Node<K, V>,
+) -> (Node<K, V>, Option<NodeInner<K, V>>) {
+    let Some(mut T) = root else {
+        return (None, None);
+    };
+    // 右の子の取り出し
+    let (new_right, right_most) = delete_and_get_max(T.right.take());
+    let Some(right_most) = right_most else {
+        return (T.left.take(), Some(*T));
+    };
+    T.right = new_right;
+    // ノードを再評価
+    T.eval();
+    let mut new_root = Some(T);
+    // 削除したので，再平衡化
+    new_root = rebarance(new_root);
+    (new_root, Some(right_most))
+}
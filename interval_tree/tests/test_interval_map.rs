@@ -0,0 +1,123 @@
+use std::ops::Range;
+
+use interval_tree::interval_map::IntervalMap;
+use rand::prelude::*;
+
+#[test]
+fn test_insert_remove() {
+    let mut map = IntervalMap::new();
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert(1..5, "a"), None);
+    assert_eq!(map.insert(3..4, "b"), None);
+    assert_eq!(map.insert(1..3, "c"), None);
+    assert_eq!(map.insert(1..5, "d"), Some("a"));
+    assert_eq!(map.len(), 3);
+
+    assert_eq!(format!("{map:?}"), r#"{1..3: "c", 1..5: "d", 3..4: "b"}"#);
+
+    assert_eq!(map.get(&(1..5)), Some(&"d"));
+    assert_eq!(map.get(&(1..4)), None);
+
+    assert_eq!(map.remove(&(1..4)), None);
+    assert_eq!(map.remove(&(1..5)), Some("d"));
+    assert_eq!(map.len(), 2);
+    assert_eq!(format!("{map:?}"), r#"{1..3: "c", 3..4: "b"}"#);
+}
+
+#[test]
+#[should_panic]
+fn test_insert_empty() {
+    let mut map = IntervalMap::new();
+    map.insert(3..3, ());
+}
+
+#[test]
+fn test_overlapping() {
+    let map: IntervalMap<_, _> = [
+        (0..3, 'a'),
+        (2..6, 'b'),
+        (4..5, 'c'),
+        (7..10, 'd'),
+        (8..9, 'e'),
+    ]
+    .into_iter()
+    .collect();
+
+    let keys = |iter: &mut dyn Iterator<Item = (&Range<i32>, &char)>| {
+        iter.map(|(_, &v)| v).collect::<String>()
+    };
+
+    assert_eq!(keys(&mut map.overlapping(0..1)), "a");
+    assert_eq!(keys(&mut map.overlapping(3..4)), "b");
+    assert_eq!(keys(&mut map.overlapping(2..5)), "abc");
+    assert_eq!(keys(&mut map.overlapping(6..7)), "");
+    assert_eq!(keys(&mut map.overlapping(5..8)), "bd");
+    assert_eq!(keys(&mut map.overlapping(4..4)), "");
+    assert_eq!(keys(&mut map.overlapping(-5..100)), "abcde");
+
+    assert_eq!(keys(&mut map.stabbing(2)), "ab");
+    assert_eq!(keys(&mut map.stabbing(3)), "b");
+    assert_eq!(keys(&mut map.stabbing(6)), "");
+    assert_eq!(keys(&mut map.stabbing(8)), "de");
+    assert_eq!(keys(&mut map.stabbing(10)), "");
+
+    assert!(map.any_overlap(5..8));
+    assert!(!map.any_overlap(6..7));
+    assert!(!map.any_overlap(10..20));
+}
+
+#[test]
+fn test_random() {
+    const ITER: usize = 2000;
+    const SIZE: i32 = 200;
+
+    let mut rng = thread_rng();
+
+    let mut map = IntervalMap::new();
+    let mut arr: Vec<(Range<i32>, usize)> = vec![];
+
+    for i in 0..ITER {
+        let lo = rng.gen_range(0..SIZE);
+        let hi = rng.gen_range(lo + 1..=SIZE);
+
+        // 挿入・削除
+        if rng.gen_bool(0.3) && !arr.is_empty() {
+            let idx = rng.gen_range(0..arr.len());
+            let (range, value) = arr.swap_remove(idx);
+            assert_eq!(map.remove(&range), Some(value));
+        } else {
+            let old = arr.iter().position(|(r, _)| r == &(lo..hi));
+            let expected = old.map(|idx| arr.swap_remove(idx).1);
+            assert_eq!(map.insert(lo..hi, i), expected);
+            arr.push((lo..hi, i));
+        }
+        assert_eq!(map.len(), arr.len());
+
+        // 区間の列挙
+        arr.sort_by_key(|(r, _)| (r.start, r.end));
+
+        let ql = rng.gen_range(0..SIZE);
+        let qr = rng.gen_range(ql + 1..=SIZE);
+        let expected: Vec<_> = arr
+            .iter()
+            .filter(|(r, _)| r.start < qr && ql < r.end)
+            .map(|(r, v)| (r.clone(), *v))
+            .collect();
+        let actual: Vec<_> = map
+            .overlapping(ql..qr)
+            .map(|(r, &v)| (r.clone(), v))
+            .collect();
+        assert_eq!(actual, expected);
+        assert_eq!(map.any_overlap(ql..qr), !expected.is_empty());
+
+        // 点を含む区間の列挙
+        let expected: Vec<_> = arr
+            .iter()
+            .filter(|(r, _)| r.contains(&ql))
+            .map(|(r, v)| (r.clone(), *v))
+            .collect();
+        let actual: Vec<_> = map.stabbing(ql).map(|(r, &v)| (r.clone(), v)).collect();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,101 @@
+//! 再帰実装と非再帰実装の遅延セグメント木の比較
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use lazy_segment_tree::{
+    alg::Monoid,
+    lazy_segment_tree::LazySegmentTree,
+    lazy_segment_tree_iterative::IterativeLazySegmentTree,
+    structures::{RMQandRUQ, RSQandRAQ},
+};
+
+const N: usize = 1 << 17;
+const Q: usize = 100_000;
+
+/// クエリ（区間作用なら`true`）
+type Query = (bool, usize, usize, isize);
+
+/// 疑似乱数によるクエリ列
+fn queries() -> Vec<Query> {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    (0..Q)
+        .map(|_| {
+            let a = next() as usize % (N + 1);
+            let b = next() as usize % (N + 1);
+            let x = (next() % 1000) as isize;
+            (next() % 2 == 0, a.min(b), a.max(b), x)
+        })
+        .collect()
+}
+
+/// 共通のインターフェース
+trait Segtree<T: Monoid<M = isize>> {
+    fn from_vec(src: Vec<T::X>) -> Self;
+    fn set_range(&mut self, left: usize, right: usize, val: isize);
+    fn get_range(&mut self, left: usize, right: usize) -> T::X;
+}
+
+impl<T: Monoid<M = isize>> Segtree<T> for LazySegmentTree<T> {
+    fn from_vec(src: Vec<T::X>) -> Self {
+        LazySegmentTree::from_vec(src)
+    }
+    fn set_range(&mut self, left: usize, right: usize, val: isize) {
+        LazySegmentTree::set_range(self, left, right, val)
+    }
+    fn get_range(&mut self, left: usize, right: usize) -> T::X {
+        LazySegmentTree::get_range(self, left, right)
+    }
+}
+
+impl<T: Monoid<M = isize>> Segtree<T> for IterativeLazySegmentTree<T> {
+    fn from_vec(src: Vec<T::X>) -> Self {
+        IterativeLazySegmentTree::from_vec(src)
+    }
+    fn set_range(&mut self, left: usize, right: usize, val: isize) {
+        IterativeLazySegmentTree::set_range(self, left, right, val)
+    }
+    fn get_range(&mut self, left: usize, right: usize) -> T::X {
+        IterativeLazySegmentTree::get_range(self, left, right)
+    }
+}
+
+/// クエリ列を処理する
+fn run<T: Monoid<M = isize>, S: Segtree<T>>(queries: &[Query]) {
+    let mut segtree = S::from_vec(vec![T::IX; N]);
+    for &(is_update, l, r, x) in queries {
+        if is_update {
+            segtree.set_range(l, r, x);
+        } else {
+            black_box(segtree.get_range(l, r));
+        }
+    }
+}
+
+fn bench(c: &mut Criterion) {
+    let queries = queries();
+    let mut group = c.benchmark_group("lazy_segment_tree");
+    group.sample_size(10);
+
+    group.bench_function(BenchmarkId::new("recursive", "RSQandRAQ"), |b| {
+        b.iter(|| run::<RSQandRAQ, LazySegmentTree<_>>(&queries))
+    });
+    group.bench_function(BenchmarkId::new("iterative", "RSQandRAQ"), |b| {
+        b.iter(|| run::<RSQandRAQ, IterativeLazySegmentTree<_>>(&queries))
+    });
+    group.bench_function(BenchmarkId::new("recursive", "RMQandRUQ"), |b| {
+        b.iter(|| run::<RMQandRUQ, LazySegmentTree<_>>(&queries))
+    });
+    group.bench_function(BenchmarkId::new("iterative", "RMQandRUQ"), |b| {
+        b.iter(|| run::<RMQandRUQ, IterativeLazySegmentTree<_>>(&queries))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -19,3 +19,17 @@ pub trait Monoid {
     /// 作用素の集約
     fn fp(x: &Self::M, p: usize) -> Self::M;
 }
+
+/// ## ActionMonoid
+/// - 作用素のみからなるモノイド（双対セグメント木で用いる）
+pub trait ActionMonoid {
+    /// 作用素のデータ型
+    type M: Clone + PartialEq;
+
+    /// 作用素Mの単位元
+    const IM: Self::M;
+
+    /// 作用素同士の演算
+    /// - `x`を作用させた後に`y`を作用させる
+    fn fm(x: &Self::M, y: &Self::M) -> Self::M;
+}
@@ -0,0 +1,194 @@
+//! # 双対セグメント木
+//! - 区間への作用と1点取得のみを行う
+//! - 作用させる前に区間の両端の経路上の作用素を子に伝搬するため，非可換な作用にも対応する
+//! - 伝搬により，各ノードの作用素はその子孫の作用素より後に作用させたものとなる
+
+use crate::alg::ActionMonoid;
+
+#[derive(Debug)]
+pub struct DualSegmentTree<A: ActionMonoid> {
+    size: usize,
+    log: u32,
+    offset: usize,
+    lazy: Vec<A::M>,
+}
+
+impl<A: ActionMonoid> DualSegmentTree<A> {
+    /// 新規作成
+    /// - 各要素の作用素は単位元
+    pub fn new(n: usize) -> Self {
+        let offset = n.next_power_of_two();
+        Self {
+            size: n,
+            log: offset.trailing_zeros(),
+            offset,
+            lazy: vec![A::IM; offset << 1],
+        }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// 区間の範囲を確認する
+    fn check_range(&self, left: usize, right: usize) {
+        assert!(
+            left <= right && right <= self.size,
+            "range [{left}, {right}) is out of [0, {})",
+            self.size
+        );
+    }
+
+    /// 子に作用素を伝搬
+    fn push(&mut self, idx: usize) {
+        if self.lazy[idx] == A::IM {
+            return;
+        }
+        let val = std::mem::replace(&mut self.lazy[idx], A::IM);
+        for child in [idx * 2, idx * 2 + 1] {
+            self.lazy[child] = A::fm(&self.lazy[child], &val);
+        }
+    }
+
+    /// 区間作用
+    /// - [left, right)
+    pub fn apply(&mut self, left: usize, right: usize, val: A::M) {
+        self.check_range(left, right);
+        if left == right {
+            return;
+        }
+        let (mut l, mut r) = (left + self.offset, right + self.offset);
+        // 両端より上にある作用素を先に伝搬する
+        for i in (1..=self.log).rev() {
+            if (l >> i) << i != l {
+                self.push(l >> i);
+            }
+            if (r >> i) << i != r {
+                self.push((r - 1) >> i);
+            }
+        }
+        while l < r {
+            if l & 1 == 1 {
+                self.lazy[l] = A::fm(&self.lazy[l], &val);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                self.lazy[r] = A::fm(&self.lazy[r], &val);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+    }
+
+    /// 1点取得
+    /// - `i`番目の要素に作用させた作用素を，葉から根の順に合成する
+    pub fn get(&self, i: usize) -> A::M {
+        self.check_range(i, i + 1);
+        let mut idx = i + self.offset;
+        let mut res = self.lazy[idx].clone();
+        while idx > 1 {
+            idx >>= 1;
+            res = A::fm(&res, &self.lazy[idx]);
+        }
+        res
+    }
+
+    /// 各要素の作用素を配列として取得する
+    pub fn to_vec(&self) -> Vec<A::M> {
+        (0..self.size).map(|i| self.get(i)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DualSegmentTree;
+    use crate::{
+        alg::ActionMonoid,
+        structures::{RAQ, RUQ},
+    };
+    use rand::Rng;
+
+    /// アフィン変換（mod 998244353）
+    struct Affine;
+
+    const MOD: u64 = 998244353;
+
+    impl ActionMonoid for Affine {
+        /// x -> a * x + b
+        type M = (u64, u64);
+        const IM: Self::M = (1, 0);
+        fn fm(x: &Self::M, y: &Self::M) -> Self::M {
+            (x.0 * y.0 % MOD, (x.1 * y.0 + y.1) % MOD)
+        }
+    }
+
+    #[test]
+    fn test_raq() {
+        let mut segtree = DualSegmentTree::<RAQ>::new(5);
+
+        segtree.apply(0, 3, 2);
+        segtree.apply(2, 5, 5);
+        segtree.apply(1, 2, -1);
+
+        assert_eq!(segtree.get(0), 2);
+        assert_eq!(segtree.get(2), 7);
+        assert_eq!(segtree.to_vec(), vec![2, 1, 7, 5, 5]);
+    }
+
+    #[test]
+    fn test_ruq() {
+        let mut segtree = DualSegmentTree::<RUQ>::new(6);
+
+        segtree.apply(0, 4, Some(1));
+        segtree.apply(2, 6, Some(2));
+        segtree.apply(1, 3, Some(3));
+
+        assert_eq!(
+            segtree.to_vec(),
+            vec![Some(1), Some(3), Some(3), Some(2), Some(2), Some(2)]
+        );
+
+        // 区間全体を覆うノードの作用素が，後から部分的に上書きされる場合
+        segtree.apply(0, 6, Some(4));
+        segtree.apply(5, 6, Some(5));
+        assert_eq!(segtree.get(0), Some(4));
+        assert_eq!(segtree.get(5), Some(5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_range() {
+        let segtree = DualSegmentTree::<RAQ>::new(3);
+        segtree.get(3);
+    }
+
+    /// 非可換な作用の愚直な実装との比較
+    #[test]
+    fn test_random_affine() {
+        let mut rng = rand::thread_rng();
+
+        for n in [1, 3, 8, 21] {
+            let mut segtree = DualSegmentTree::<Affine>::new(n);
+            let mut arr = vec![Affine::IM; n];
+
+            for _ in 0..2000 {
+                let l = rng.gen_range(0..=n);
+                let r = rng.gen_range(l..=n);
+                let f = (rng.gen_range(0..100), rng.gen_range(0..100));
+                segtree.apply(l, r, f);
+                arr[l..r].iter_mut().for_each(|g| *g = Affine::fm(g, &f));
+
+                let i = rng.gen_range(0..n);
+                assert_eq!(segtree.get(i), arr[i]);
+            }
+            assert_eq!(segtree.to_vec(), arr);
+        }
+    }
+}
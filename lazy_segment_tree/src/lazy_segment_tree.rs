@@ -4,6 +4,7 @@ use crate::alg::Monoid;
 
 #[derive(Debug)]
 pub struct LazySegmentTree<T: Monoid> {
+    size: usize,
     offset: usize,
     data: Vec<T::X>,
     lazy: Vec<T::M>,
@@ -14,12 +15,89 @@ impl<T: Monoid> LazySegmentTree<T> {
     pub fn new(n: usize) -> Self {
         let offset = n.next_power_of_two();
         Self {
+            size: n,
             offset,
             data: vec![T::IX; offset << 1],
             lazy: vec![T::IM; offset << 1],
         }
     }
 
+    /// 配列から作成
+    /// - 葉から順に値を計算する
+    /// - 計算量：$O(n)$
+    pub fn from_vec(src: Vec<T::X>) -> Self {
+        let mut segtree = Self::new(src.len());
+        let offset = segtree.offset;
+        for (i, x) in src.into_iter().enumerate() {
+            segtree.data[offset + i] = x;
+        }
+        for idx in (1..offset).rev() {
+            segtree.data[idx] = T::fx(&segtree.data[idx * 2], &segtree.data[idx * 2 + 1]);
+        }
+        segtree
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// 区間の範囲を確認する
+    /// - 末尾の埋め草を含む区間はパニックする
+    fn check_range(&self, left: usize, right: usize) {
+        assert!(
+            left <= right && right <= self.size,
+            "range [{left}, {right}) is out of [0, {})",
+            self.size
+        );
+    }
+
+    /// 1点取得
+    pub fn get(&mut self, i: usize) -> T::X {
+        self.get_range(i, i + 1)
+    }
+
+    /// 1点更新
+    /// - `i`番目の要素を`x`に置き換える
+    pub fn set(&mut self, i: usize, x: T::X) {
+        self.check_range(i, i + 1);
+        self.set_inner(i, x, 0, self.offset, 1);
+    }
+
+    fn set_inner(&mut self, i: usize, x: T::X, begin: usize, end: usize, idx: usize) {
+        // 遅延値を評価
+        self.eval(idx, end - begin);
+        if end - begin == 1 {
+            self.data[idx] = x;
+            return;
+        }
+        let mid = (begin + end) / 2;
+        if i < mid {
+            self.set_inner(i, x, begin, mid, idx * 2);
+            self.eval(idx * 2 + 1, end - mid);
+        } else {
+            self.eval(idx * 2, mid - begin);
+            self.set_inner(i, x, mid, end, idx * 2 + 1);
+        }
+        // 値を更新
+        self.data[idx] = T::fx(&self.data[idx * 2], &self.data[idx * 2 + 1]);
+    }
+
+    /// 遅延値をすべて評価し，配列として取得する
+    pub fn to_vec(&mut self) -> Vec<T::X> {
+        // 根から順に評価する
+        for idx in 1..self.offset << 1 {
+            let len = self.offset >> idx.ilog2();
+            self.eval(idx, len);
+        }
+        self.data[self.offset..self.offset + self.size].to_vec()
+    }
+
     /// 遅延値を評価
     fn eval(&mut self, idx: usize, len: usize) {
         if self.lazy[idx] == T::IM {
@@ -38,6 +116,7 @@ impl<T: Monoid> LazySegmentTree<T> {
     /// 区間加算
     /// - [left, right)
     pub fn set_range(&mut self, left: usize, right: usize, val: T::M) {
+        self.check_range(left, right);
         self.set_range_inner(left, right, val, 0, self.offset, 1);
     }
 
@@ -73,6 +152,7 @@ impl<T: Monoid> LazySegmentTree<T> {
     /// - 再帰実装
     /// - [left, right)
     pub fn get_range(&mut self, left: usize, right: usize) -> T::X {
+        self.check_range(left, right);
         self.get_range_inner(left, right, 0, self.offset, 1)
     }
 
@@ -103,3 +183,9 @@ impl<T: Monoid> LazySegmentTree<T> {
         }
     }
 }
+
+impl<T: Monoid> FromIterator<T::X> for LazySegmentTree<T> {
+    fn from_iter<I: IntoIterator<Item = T::X>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,284 @@
+//! # 非再帰の遅延セグメント木
+//! - AtCoder Library の `lazy_segtree` と同様に，葉から根に向かって区間を処理する
+//! - 区間の両端の経路上のノードのみ遅延値の伝搬・値の再計算を行う
+//! - 各ノードの値は自身の遅延値を作用させた後のものを持つ
+
+use crate::alg::Monoid;
+
+#[derive(Debug)]
+pub struct IterativeLazySegmentTree<T: Monoid> {
+    size: usize,
+    log: u32,
+    offset: usize,
+    data: Vec<T::X>,
+    lazy: Vec<T::M>,
+}
+
+impl<T: Monoid> IterativeLazySegmentTree<T> {
+    /// 新規作成
+    pub fn new(n: usize) -> Self {
+        let offset = n.next_power_of_two();
+        Self {
+            size: n,
+            log: offset.trailing_zeros(),
+            offset,
+            data: vec![T::IX; offset << 1],
+            lazy: vec![T::IM; offset << 1],
+        }
+    }
+
+    /// 配列から作成
+    /// - 計算量：$O(n)$
+    pub fn from_vec(src: Vec<T::X>) -> Self {
+        let mut segtree = Self::new(src.len());
+        let offset = segtree.offset;
+        for (i, x) in src.into_iter().enumerate() {
+            segtree.data[offset + i] = x;
+        }
+        for idx in (1..offset).rev() {
+            segtree.update(idx);
+        }
+        segtree
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// 区間の範囲を確認する
+    fn check_range(&self, left: usize, right: usize) {
+        assert!(
+            left <= right && right <= self.size,
+            "range [{left}, {right}) is out of [0, {})",
+            self.size
+        );
+    }
+
+    /// ノード`idx`が表す区間の長さ
+    fn width(&self, idx: usize) -> usize {
+        self.offset >> idx.ilog2()
+    }
+
+    /// 子の値から自身の値を再計算
+    fn update(&mut self, idx: usize) {
+        self.data[idx] = T::fx(&self.data[idx * 2], &self.data[idx * 2 + 1]);
+    }
+
+    /// ノード`idx`に作用させる
+    fn apply_node(&mut self, idx: usize, val: &T::M) {
+        self.data[idx] = T::fa(&self.data[idx], &T::fp(val, self.width(idx)));
+        if idx < self.offset {
+            self.lazy[idx] = T::fm(&self.lazy[idx], val);
+        }
+    }
+
+    /// 子に遅延値を伝搬
+    fn push(&mut self, idx: usize) {
+        if self.lazy[idx] == T::IM {
+            return;
+        }
+        let val = std::mem::replace(&mut self.lazy[idx], T::IM);
+        self.apply_node(idx * 2, &val);
+        self.apply_node(idx * 2 + 1, &val);
+    }
+
+    /// 葉`idx`の祖先の遅延値を根から順に伝搬
+    fn push_path(&mut self, idx: usize) {
+        for i in (1..=self.log).rev() {
+            self.push(idx >> i);
+        }
+    }
+
+    /// 1点取得
+    pub fn get(&mut self, i: usize) -> T::X {
+        self.check_range(i, i + 1);
+        let idx = i + self.offset;
+        self.push_path(idx);
+        self.data[idx].clone()
+    }
+
+    /// 1点更新
+    /// - `i`番目の要素を`x`に置き換える
+    pub fn set(&mut self, i: usize, x: T::X) {
+        self.check_range(i, i + 1);
+        let idx = i + self.offset;
+        self.push_path(idx);
+        self.data[idx] = x;
+        for i in 1..=self.log {
+            self.update(idx >> i);
+        }
+    }
+
+    /// 区間`[left, right)`の両端より上にあるノードの遅延値を伝搬
+    fn push_boundary(&mut self, left: usize, right: usize) {
+        for i in (1..=self.log).rev() {
+            // 区間の端がノードの境界と一致する場合は伝搬不要
+            if (left >> i) << i != left {
+                self.push(left >> i);
+            }
+            if (right >> i) << i != right {
+                self.push((right - 1) >> i);
+            }
+        }
+    }
+
+    /// 区間作用
+    /// - [left, right)
+    pub fn set_range(&mut self, left: usize, right: usize, val: T::M) {
+        self.check_range(left, right);
+        if left == right {
+            return;
+        }
+        let (left, right) = (left + self.offset, right + self.offset);
+        self.push_boundary(left, right);
+        // 区間を覆うノードに作用させる
+        let (mut l, mut r) = (left, right);
+        while l < r {
+            if l & 1 == 1 {
+                self.apply_node(l, &val);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                self.apply_node(r, &val);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        // 両端の経路上のノードを再計算
+        for i in 1..=self.log {
+            if (left >> i) << i != left {
+                self.update(left >> i);
+            }
+            if (right >> i) << i != right {
+                self.update((right - 1) >> i);
+            }
+        }
+    }
+
+    /// 区間取得
+    /// - [left, right)
+    pub fn get_range(&mut self, left: usize, right: usize) -> T::X {
+        self.check_range(left, right);
+        if left == right {
+            return T::IX;
+        }
+        let (mut l, mut r) = (left + self.offset, right + self.offset);
+        self.push_boundary(l, r);
+        // 左右から集約する（非可換な演算にも対応）
+        let (mut l_val, mut r_val) = (T::IX, T::IX);
+        while l < r {
+            if l & 1 == 1 {
+                l_val = T::fx(&l_val, &self.data[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                r_val = T::fx(&self.data[r], &r_val);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        T::fx(&l_val, &r_val)
+    }
+
+    /// 遅延値をすべて評価し，配列として取得する
+    pub fn to_vec(&mut self) -> Vec<T::X> {
+        for idx in 1..self.offset {
+            self.push(idx);
+        }
+        self.data[self.offset..self.offset + self.size].to_vec()
+    }
+}
+
+impl<T: Monoid> FromIterator<T::X> for IterativeLazySegmentTree<T> {
+    fn from_iter<I: IntoIterator<Item = T::X>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IterativeLazySegmentTree;
+    use crate::{
+        lazy_segment_tree::LazySegmentTree,
+        structures::{RMQandRUQ, RSQandRAQ},
+    };
+    use rand::Rng;
+
+    #[test]
+    fn test_raq() {
+        let mut segtree = IterativeLazySegmentTree::<RSQandRAQ>::new(5);
+        // -> [0, 0, 0, 0, 0]
+
+        segtree.set_range(0, 3, 4);
+        // -> [4, 4, 4, 0, 0]
+        segtree.set_range(2, 5, -1);
+        // -> [4, 4, 3, -1, -1]
+
+        assert_eq!(segtree.get_range(0, 5), 9);
+        assert_eq!(segtree.get_range(1, 4), 6);
+        assert_eq!(segtree.get_range(3, 3), 0);
+        assert_eq!(segtree.get(2), 3);
+
+        segtree.set(3, 10);
+        // -> [4, 4, 3, 10, -1]
+        assert_eq!(segtree.get_range(2, 5), 12);
+        assert_eq!(segtree.to_vec(), vec![4, 4, 3, 10, -1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_range() {
+        let mut segtree = IterativeLazySegmentTree::<RSQandRAQ>::new(5);
+        segtree.get_range(2, 6);
+    }
+
+    /// 再帰実装との比較
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+
+        for n in [1, 2, 7, 16, 33] {
+            let src: Vec<isize> = (0..n).map(|_| rng.gen_range(0..100)).collect();
+            let mut raq_iter = IterativeLazySegmentTree::<RSQandRAQ>::from_vec(src.clone());
+            let mut raq_rec = LazySegmentTree::<RSQandRAQ>::from_vec(src.clone());
+            let mut ruq_iter = IterativeLazySegmentTree::<RMQandRUQ>::from_vec(src.clone());
+            let mut ruq_rec = LazySegmentTree::<RMQandRUQ>::from_vec(src);
+
+            for _ in 0..2000 {
+                let l = rng.gen_range(0..=n);
+                let r = rng.gen_range(l..=n);
+                let x = rng.gen_range(-50..50);
+                match rng.gen_range(0..4) {
+                    0 => {
+                        raq_iter.set_range(l, r, x);
+                        raq_rec.set_range(l, r, x);
+                        ruq_iter.set_range(l, r, x);
+                        ruq_rec.set_range(l, r, x);
+                    }
+                    1 if l < n => {
+                        raq_iter.set(l, x);
+                        raq_rec.set(l, x);
+                        ruq_iter.set(l, x);
+                        ruq_rec.set(l, x);
+                    }
+                    2 => {
+                        assert_eq!(raq_iter.get_range(l, r), raq_rec.get_range(l, r));
+                        assert_eq!(ruq_iter.get_range(l, r), ruq_rec.get_range(l, r));
+                    }
+                    _ => {
+                        assert_eq!(raq_iter.to_vec(), raq_rec.to_vec());
+                        assert_eq!(ruq_iter.to_vec(), ruq_rec.to_vec());
+                    }
+                }
+            }
+        }
+    }
+}
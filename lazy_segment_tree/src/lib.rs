@@ -1,6 +1,9 @@
 #![allow(non_snake_case)]
 
-mod lazy_segment_tree;
+pub mod lazy_segment_tree;
+pub mod lazy_segment_tree_iterative;
 mod lazy_segment_tree_primitive;
-mod alg;
-mod structures;
+pub mod alg;
+pub mod dual_segment_tree;
+pub mod structures;
+pub mod segment_tree_beats;
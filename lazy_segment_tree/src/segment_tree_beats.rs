@@ -0,0 +1,502 @@
+//! # Segment Tree Beats
+//! - 区間chmin・区間chmax・区間加算・区間更新と，区間和・区間最大値・区間最小値の取得
+//! - 各ノードで最大値・最小値とその個数，2番目の値を持ち，
+//!   chmin（chmax）が最大値（最小値）のみに作用する場合に限り探索を打ち切る
+//! - 計算量：ならし $O(\log^2 n)$
+//! - 要素・引数は`isize`の任意の値をとれる．区間和は内部では`i128`で持ち，
+//!   取得する区間和が`isize`に収まらない場合はpanicする
+
+// データ型
+type T = isize;
+/// 区間和の型（要素数が $2^{64}$ 未満であればオーバーフローしない）
+type S = i128;
+
+/// 存在しない最大値
+/// - 2番目の値が存在するかは`max1 != min1`で判定し，この値そのものでは判定しない
+const NEG_INF: T = T::MIN;
+/// 存在しない最小値
+const INF: T = T::MAX;
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    /// 要素数（末尾の埋め草は数えない）
+    len: usize,
+    sum: S,
+    /// 最大値，2番目に大きい値，最大値の個数
+    max1: T,
+    max2: T,
+    max_cnt: usize,
+    /// 最小値，2番目に小さい値，最小値の個数
+    min1: T,
+    min2: T,
+    min_cnt: usize,
+    /// 子に伝搬していない加算
+    /// - 加算を重ねた途中の値は`isize`に収まらないことがあるため，`i128`で持つ
+    lazy: S,
+}
+
+impl Node {
+    /// 埋め草の葉
+    const EMPTY: Self = Self {
+        len: 0,
+        sum: 0,
+        max1: NEG_INF,
+        max2: NEG_INF,
+        max_cnt: 0,
+        min1: INF,
+        min2: INF,
+        min_cnt: 0,
+        lazy: 0,
+    };
+
+    /// 値`x`をもつ葉
+    fn leaf(x: T) -> Self {
+        Self {
+            len: 1,
+            sum: x as S,
+            max1: x,
+            max_cnt: 1,
+            min1: x,
+            min_cnt: 1,
+            ..Self::EMPTY
+        }
+    }
+
+    /// 子`l`, `r`を集約する
+    fn merge(l: &Self, r: &Self) -> Self {
+        let (max1, max2, max_cnt) = match l.max1.cmp(&r.max1) {
+            std::cmp::Ordering::Less => (r.max1, l.max1.max(r.max2), r.max_cnt),
+            std::cmp::Ordering::Greater => (l.max1, l.max2.max(r.max1), l.max_cnt),
+            std::cmp::Ordering::Equal => (l.max1, l.max2.max(r.max2), l.max_cnt + r.max_cnt),
+        };
+        let (min1, min2, min_cnt) = match l.min1.cmp(&r.min1) {
+            std::cmp::Ordering::Greater => (r.min1, l.min1.min(r.min2), r.min_cnt),
+            std::cmp::Ordering::Less => (l.min1, l.min2.min(r.min1), l.min_cnt),
+            std::cmp::Ordering::Equal => (l.min1, l.min2.min(r.min2), l.min_cnt + r.min_cnt),
+        };
+        Self {
+            len: l.len + r.len,
+            sum: l.sum + r.sum,
+            max1,
+            max2,
+            max_cnt,
+            min1,
+            min2,
+            min_cnt,
+            lazy: 0,
+        }
+    }
+
+    /// 値が1種類のみであるか（2番目の値が存在しないか）
+    fn is_uniform(&self) -> bool {
+        self.max1 == self.min1
+    }
+
+    /// すべての要素に`x`を加える
+    /// - 加えた結果の各要素は`isize`に収まるとする
+    fn add(&mut self, x: S) {
+        if self.len == 0 {
+            return;
+        }
+        let shift = |v: T| (v as S + x) as T;
+        self.sum += x * self.len as S;
+        if !self.is_uniform() {
+            self.max2 = shift(self.max2);
+            self.min2 = shift(self.min2);
+        }
+        self.max1 = shift(self.max1);
+        self.min1 = shift(self.min1);
+        self.lazy += x;
+    }
+
+    /// 最大値を`x`に下げる（`max2 < x < max1`であるとき）
+    fn chmin(&mut self, x: T) {
+        if self.max1 <= x {
+            return;
+        }
+        self.sum -= (self.max1 as S - x as S) * self.max_cnt as S;
+        // 最小値も最大値と同じ値である場合
+        if self.max1 == self.min1 {
+            self.min1 = x;
+        } else if self.max1 == self.min2 {
+            self.min2 = x;
+        }
+        self.max1 = x;
+    }
+
+    /// 最小値を`x`に上げる（`min1 < x < min2`であるとき）
+    fn chmax(&mut self, x: T) {
+        if self.min1 >= x {
+            return;
+        }
+        self.sum += (x as S - self.min1 as S) * self.min_cnt as S;
+        // 最大値も最小値と同じ値である場合
+        if self.min1 == self.max1 {
+            self.max1 = x;
+        } else if self.min1 == self.max2 {
+            self.max2 = x;
+        }
+        self.min1 = x;
+    }
+}
+
+/// 作用の種類
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    Chmin(T),
+    Chmax(T),
+    Add(T),
+}
+
+/// 取得する値の種類
+#[derive(Debug, Clone, Copy)]
+enum Query {
+    Sum,
+    Max,
+    Min,
+}
+
+/// ## SegmentTreeBeats
+/// - 区間chmin・区間chmax・区間加算・区間更新
+/// - 区間和・区間最大値・区間最小値
+/// - 対応している型はisizeのみ
+#[derive(Debug)]
+pub struct SegmentTreeBeats {
+    size: usize,
+    offset: usize,
+    data: Vec<Node>,
+}
+
+impl SegmentTreeBeats {
+    /// 新規作成
+    /// - 要素はすべて0
+    pub fn new(n: usize) -> Self {
+        Self::from_vec(vec![0; n])
+    }
+
+    /// 配列から作成
+    pub fn from_vec(src: Vec<T>) -> Self {
+        let size = src.len();
+        let offset = size.next_power_of_two();
+        let mut data = vec![Node::EMPTY; offset << 1];
+        for (i, x) in src.into_iter().enumerate() {
+            data[offset + i] = Node::leaf(x);
+        }
+        for idx in (1..offset).rev() {
+            data[idx] = Node::merge(&data[idx * 2], &data[idx * 2 + 1]);
+        }
+        Self { size, offset, data }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// 区間chmin
+    /// - [left, right) の各要素を`min(a_i, x)`に更新する
+    pub fn chmin(&mut self, left: usize, right: usize, x: T) {
+        self.apply(left, right, Action::Chmin(x));
+    }
+
+    /// 区間chmax
+    /// - [left, right) の各要素を`max(a_i, x)`に更新する
+    pub fn chmax(&mut self, left: usize, right: usize, x: T) {
+        self.apply(left, right, Action::Chmax(x));
+    }
+
+    /// 区間加算
+    /// - [left, right)
+    pub fn add(&mut self, left: usize, right: usize, x: T) {
+        self.apply(left, right, Action::Add(x));
+    }
+
+    /// 区間更新
+    /// - [left, right) の各要素を`x`に更新する
+    /// - chminとchmaxを続けて行う
+    pub fn assign(&mut self, left: usize, right: usize, x: T) {
+        self.apply(left, right, Action::Chmin(x));
+        self.apply(left, right, Action::Chmax(x));
+    }
+
+    /// 区間和
+    /// - [left, right)
+    /// - 区間和が`isize`に収まらない場合はpanicする
+    pub fn sum(&mut self, left: usize, right: usize) -> T {
+        let sum = self.get_range(left, right, Query::Sum);
+        T::try_from(sum).unwrap_or_else(|_| panic!("the sum {sum} overflows isize"))
+    }
+
+    /// 区間最大値
+    /// - [left, right)
+    /// - 区間が空のときは`isize::MIN`
+    pub fn max(&mut self, left: usize, right: usize) -> T {
+        self.get_range(left, right, Query::Max) as T
+    }
+
+    /// 区間最小値
+    /// - [left, right)
+    /// - 区間が空のときは`isize::MAX`
+    pub fn min(&mut self, left: usize, right: usize) -> T {
+        self.get_range(left, right, Query::Min) as T
+    }
+
+    /// 1点取得
+    pub fn get(&mut self, i: usize) -> T {
+        self.sum(i, i + 1)
+    }
+
+    /// 区間の範囲を確認する
+    fn check(&self, left: usize, right: usize) {
+        assert!(
+            left <= right && right <= self.size,
+            "range [{left}, {right}) is out of [0, {})",
+            self.size
+        );
+    }
+
+    /// 子に遅延値を伝搬
+    fn push(&mut self, idx: usize) {
+        let Node {
+            lazy, max1, min1, ..
+        } = self.data[idx];
+        for child in [idx * 2, idx * 2 + 1] {
+            let node = &mut self.data[child];
+            if lazy != 0 {
+                node.add(lazy);
+            }
+            // 親の最大値・最小値は子の値への作用の結果を表す
+            node.chmin(max1);
+            node.chmax(min1);
+        }
+        self.data[idx].lazy = 0;
+    }
+
+    fn apply(&mut self, left: usize, right: usize, action: Action) {
+        self.check(left, right);
+        self.apply_inner(left, right, action, 0, self.offset, 1);
+    }
+
+    fn apply_inner(
+        &mut self,
+        left: usize,
+        right: usize,
+        action: Action,
+        begin: usize,
+        end: usize,
+        idx: usize,
+    ) {
+        let node = &mut self.data[idx];
+        // 区間を含まない，または作用しても変化しない
+        let unchanged = match action {
+            Action::Chmin(x) => node.max1 <= x,
+            Action::Chmax(x) => node.min1 >= x,
+            Action::Add(_) => false,
+        };
+        if end <= left || right <= begin || unchanged {
+            return;
+        }
+        // 区間を包含し，最大値（最小値）のみに作用するとき
+        // （葉は値が1種類のみであるため，必ずここで終わる）
+        if left <= begin && end <= right {
+            match action {
+                Action::Chmin(x) if node.is_uniform() || node.max2 < x => return node.chmin(x),
+                Action::Chmax(x) if node.is_uniform() || node.min2 > x => return node.chmax(x),
+                Action::Add(x) => return node.add(x as S),
+                _ => (),
+            }
+        }
+        // 区間が重なるとき
+        self.push(idx);
+        let mid = (begin + end) / 2;
+        self.apply_inner(left, right, action, begin, mid, idx * 2);
+        self.apply_inner(left, right, action, mid, end, idx * 2 + 1);
+        self.data[idx] = Node::merge(&self.data[idx * 2], &self.data[idx * 2 + 1]);
+    }
+
+    fn get_range(&mut self, left: usize, right: usize, query: Query) -> S {
+        self.check(left, right);
+        self.get_range_inner(left, right, query, 0, self.offset, 1)
+    }
+
+    fn get_range_inner(
+        &mut self,
+        left: usize,
+        right: usize,
+        query: Query,
+        begin: usize,
+        end: usize,
+        idx: usize,
+    ) -> S {
+        // 区間を含まない
+        if end <= left || right <= begin {
+            match query {
+                Query::Sum => 0,
+                Query::Max => NEG_INF as S,
+                Query::Min => INF as S,
+            }
+        }
+        // 区間を包含する
+        else if left <= begin && end <= right {
+            let node = &self.data[idx];
+            match query {
+                Query::Sum => node.sum,
+                Query::Max => node.max1 as S,
+                Query::Min => node.min1 as S,
+            }
+        }
+        // 区間が重なる
+        else {
+            self.push(idx);
+            let mid = (begin + end) / 2;
+            let l_val = self.get_range_inner(left, right, query, begin, mid, idx * 2);
+            let r_val = self.get_range_inner(left, right, query, mid, end, idx * 2 + 1);
+            match query {
+                Query::Sum => l_val + r_val,
+                Query::Max => l_val.max(r_val),
+                Query::Min => l_val.min(r_val),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SegmentTreeBeats;
+    use rand::Rng;
+
+    #[test]
+    fn test_chmin_chmax() {
+        let mut seg = SegmentTreeBeats::from_vec(vec![5, 1, 4, 1, 3]);
+
+        seg.chmin(0, 5, 3);
+        // -> [3, 1, 3, 1, 3]
+        assert_eq!(seg.sum(0, 5), 11);
+        assert_eq!(seg.max(0, 5), 3);
+        assert_eq!(seg.min(1, 4), 1);
+
+        seg.chmax(1, 4, 2);
+        // -> [3, 2, 3, 2, 3]
+        assert_eq!(seg.sum(0, 5), 13);
+        assert_eq!(seg.min(0, 5), 2);
+
+        seg.add(2, 5, -4);
+        // -> [3, 2, -1, -2, -1]
+        assert_eq!(seg.sum(0, 5), 1);
+        assert_eq!(seg.min(0, 5), -2);
+        assert_eq!(seg.max(2, 5), -1);
+
+        seg.assign(0, 3, 7);
+        // -> [7, 7, 7, -2, -1]
+        assert_eq!(seg.sum(0, 5), 18);
+        assert_eq!(seg.get(3), -2);
+        assert_eq!(seg.max(3, 3), isize::MIN);
+        assert_eq!(seg.min(3, 3), isize::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_range() {
+        let mut seg = SegmentTreeBeats::new(3);
+        seg.chmin(0, 4, 0);
+    }
+
+    /// `isize`の端の値を引数にとる場合
+    #[test]
+    fn test_extreme() {
+        let mut seg = SegmentTreeBeats::from_vec(vec![5, 3]);
+
+        seg.chmin(0, 2, isize::MIN);
+        assert_eq!(seg.max(0, 2), isize::MIN);
+        assert_eq!(seg.get(1), isize::MIN);
+
+        seg.chmax(0, 1, isize::MAX);
+        // -> [MAX, MIN]
+        assert_eq!(seg.sum(0, 2), -1);
+        assert_eq!(seg.min(0, 2), isize::MIN);
+
+        seg.chmax(0, 2, isize::MAX);
+        assert_eq!(seg.min(0, 2), isize::MAX);
+        seg.add(0, 2, -isize::MAX);
+        assert_eq!(seg.sum(0, 2), 0);
+
+        let mut seg = SegmentTreeBeats::from_vec(vec![isize::MIN, 0, isize::MAX, 7]);
+        seg.chmin(0, 4, 0);
+        // -> [MIN, 0, 0, 0]
+        assert_eq!(seg.max(0, 4), 0);
+        assert_eq!(seg.sum(0, 4), isize::MIN);
+        seg.add(1, 4, 1);
+        // -> [MIN, 1, 1, 1]
+        assert_eq!(seg.max(0, 1), isize::MIN);
+        assert_eq!(seg.sum(1, 4), 3);
+
+        // 子に伝搬していない加算の合計は`isize`に収まらなくてよい
+        let mut seg = SegmentTreeBeats::from_vec(vec![isize::MIN, isize::MIN]);
+        seg.add(0, 2, isize::MAX);
+        // -> [-1, -1]
+        seg.add(0, 2, isize::MAX);
+        // -> [MAX - 1, MAX - 1]
+        assert_eq!(seg.max(0, 2), isize::MAX - 1);
+        assert_eq!(seg.get(0), isize::MAX - 1);
+        seg.add(0, 1, isize::MIN);
+        // -> [-2, MAX - 1]
+        assert_eq!(seg.min(0, 2), -2);
+        assert_eq!(seg.sum(0, 2), isize::MAX - 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_sum_overflow() {
+        let mut seg = SegmentTreeBeats::from_vec(vec![5, 3]);
+        seg.chmin(0, 2, isize::MIN);
+        seg.sum(0, 2);
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+
+        for n in [1, 2, 5, 16, 37] {
+            let mut arr: Vec<isize> = (0..n).map(|_| rng.gen_range(-100..100)).collect();
+            let mut seg = SegmentTreeBeats::from_vec(arr.clone());
+
+            for _ in 0..3000 {
+                let l = rng.gen_range(0..=n);
+                let r = rng.gen_range(l..=n);
+                let x = rng.gen_range(-100..100);
+                match rng.gen_range(0..7) {
+                    0 => {
+                        seg.chmin(l, r, x);
+                        arr[l..r].iter_mut().for_each(|v| *v = (*v).min(x));
+                    }
+                    1 => {
+                        seg.chmax(l, r, x);
+                        arr[l..r].iter_mut().for_each(|v| *v = (*v).max(x));
+                    }
+                    2 => {
+                        seg.add(l, r, x / 10);
+                        arr[l..r].iter_mut().for_each(|v| *v += x / 10);
+                    }
+                    3 => {
+                        seg.assign(l, r, x);
+                        arr[l..r].iter_mut().for_each(|v| *v = x);
+                    }
+                    4 => assert_eq!(seg.sum(l, r), arr[l..r].iter().sum::<isize>()),
+                    5 => assert_eq!(
+                        seg.max(l, r),
+                        arr[l..r].iter().copied().max().unwrap_or(isize::MIN)
+                    ),
+                    _ => assert_eq!(
+                        seg.min(l, r),
+                        arr[l..r].iter().copied().min().unwrap_or(isize::MAX)
+                    ),
+                }
+            }
+        }
+    }
+}
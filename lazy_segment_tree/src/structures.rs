@@ -1,4 +1,4 @@
-use crate::alg::Monoid;
+use crate::alg::{ActionMonoid, Monoid};
 
 /// ## RSQandRAQ
 /// - 区間加算
@@ -51,10 +51,37 @@ impl Monoid for RMQandRUQ {
 }
 
 
+/// ## RAQ
+/// - 区間加算
+#[derive(Debug)]
+pub struct RAQ;
+
+impl ActionMonoid for RAQ {
+    type M = isize;
+    const IM: Self::M = 0;
+    fn fm(x: &Self::M, y: &Self::M) -> Self::M {
+        x + y
+    }
+}
+
+/// ## RUQ
+/// - 区間更新（`None`は更新なし）
+#[derive(Debug)]
+pub struct RUQ;
+
+impl ActionMonoid for RUQ {
+    type M = Option<isize>;
+    const IM: Self::M = None;
+    fn fm(x: &Self::M, y: &Self::M) -> Self::M {
+        y.or(*x)
+    }
+}
+
 #[cfg(test)]
 mod test_abstract_lazy_segment_tree {
     use super::*;
     use crate::lazy_segment_tree::LazySegmentTree;
+    use rand::Rng;
 
     /// 区間加算・区間和のテスト
     #[test]
@@ -135,4 +162,79 @@ mod test_abstract_lazy_segment_tree {
         assert_eq!(segtree.get_range(1, 3), 5);
         assert_eq!(segtree.get_range(2, 4), 5);
     }
+
+    /// 配列からの構築・1点取得・1点更新のテスト
+    #[test]
+    fn test_from_vec() {
+        // 2冪でない長さ
+        let mut segtree: LazySegmentTree<RSQandRAQ> = (1..=5).collect();
+        // -> [1, 2, 3, 4, 5]
+
+        assert_eq!(segtree.len(), 5);
+        assert_eq!(segtree.get_range(0, 5), 15);
+        assert_eq!(segtree.get_range(1, 4), 9);
+        assert_eq!(segtree.get(4), 5);
+
+        segtree.set_range(0, 3, 10);
+        // -> [11, 12, 13, 4, 5]
+
+        segtree.set(1, 0);
+        // -> [11, 0, 13, 4, 5]
+
+        assert_eq!(segtree.get(1), 0);
+        assert_eq!(segtree.get_range(0, 3), 24);
+        assert_eq!(segtree.to_vec(), vec![11, 0, 13, 4, 5]);
+    }
+
+    /// 区間更新のモノイドで1点更新を行うテスト
+    #[test]
+    fn test_set_ruq() {
+        let mut segtree = LazySegmentTree::<RMQandRUQ>::from_vec(vec![5, 3, 8]);
+
+        segtree.set_range(0, 3, 6);
+        // -> [6, 6, 6]
+        segtree.set(2, 1);
+        // -> [6, 6, 1]
+
+        assert_eq!(segtree.get_range(0, 2), 6);
+        assert_eq!(segtree.get_range(0, 3), 1);
+        assert_eq!(segtree.to_vec(), vec![6, 6, 1]);
+    }
+
+    /// 埋め草を含む区間の取得はパニックする
+    #[test]
+    #[should_panic]
+    fn test_out_of_range() {
+        let mut segtree = LazySegmentTree::<RSQandRAQ>::new(3);
+        segtree.get_range(0, 4);
+    }
+
+    /// 愚直な配列との比較
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+
+        for n in [1, 3, 8, 13] {
+            let mut arr: Vec<isize> = (0..n).map(|_| rng.gen_range(0..100)).collect();
+            let mut segtree = LazySegmentTree::<RSQandRAQ>::from_vec(arr.clone());
+
+            for _ in 0..1000 {
+                let l = rng.gen_range(0..=n);
+                let r = rng.gen_range(l..=n);
+                let x = rng.gen_range(-50..50);
+                match rng.gen_range(0..4) {
+                    0 => {
+                        segtree.set_range(l, r, x);
+                        arr[l..r].iter_mut().for_each(|v| *v += x);
+                    }
+                    1 if l < n => {
+                        segtree.set(l, x);
+                        arr[l] = x;
+                    }
+                    2 => assert_eq!(segtree.get_range(l, r), arr[l..r].iter().sum::<isize>()),
+                    _ => assert_eq!(segtree.to_vec(), arr),
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,55 @@
+//! 直線の係数と座標の型
+
+use std::fmt::Debug;
+
+/// 直線 `y = a x + b` の係数・座標として使える整数型
+pub trait Coef: Copy + Ord + Debug {
+    /// 直線の値の型
+    type Value: Copy + Ord + Debug;
+
+    /// `a * x + b` を計算する
+    fn eval(a: Self, b: Self, x: Self) -> Self::Value;
+
+    /// 座標を `i128` に変換する
+    fn to_i128(self) -> i128;
+
+    /// `i128` から座標に変換する
+    fn from_i128(x: i128) -> Self;
+}
+
+/// 値は `i128` で計算するため，オーバーフローしない
+impl Coef for i64 {
+    type Value = i128;
+
+    fn eval(a: Self, b: Self, x: Self) -> Self::Value {
+        a as i128 * x as i128 + b as i128
+    }
+
+    fn to_i128(self) -> i128 {
+        self as i128
+    }
+
+    fn from_i128(x: i128) -> Self {
+        x as i64
+    }
+}
+
+/// 値（途中の `a * x` を含む）が `i128` の範囲を超える場合は panic する
+/// - 飽和させると直線の大小関係が正しく保たれないため，範囲外の値は扱わない
+impl Coef for i128 {
+    type Value = i128;
+
+    fn eval(a: Self, b: Self, x: Self) -> Self::Value {
+        a.checked_mul(x)
+            .and_then(|ax| ax.checked_add(b))
+            .unwrap_or_else(|| panic!("a * x + b overflows i128 (a = {a}, b = {b}, x = {x})"))
+    }
+
+    fn to_i128(self) -> i128 {
+        self
+    }
+
+    fn from_i128(x: i128) -> Self {
+        x
+    }
+}
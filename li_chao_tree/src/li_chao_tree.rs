@@ -0,0 +1,254 @@
+//! # Li Chao Tree
+//! - 直線・線分を追加し，ある点での最小値・最大値を求める
+//! - 各ノードは，区間の中点で最も良い直線を1本だけ持つ
+//! - ノードは必要になったときに作成する
+//!
+//! 座標の指定方法は次の2通り
+//! - 固定：あらかじめ与えた座標の集合（座標圧縮）
+//! - 動的：整数の区間（`DynamicSegmentTree`のように疎に扱う）
+
+use std::ops::Range;
+
+use crate::coef::Coef;
+
+/// 直線 `y = a x + b` の係数 `(a, b)`
+pub type Line<T> = (T, T);
+
+/// 最小値をとる直線（下側の包絡線）
+const LOWER: usize = 0;
+/// 最大値をとる直線（上側の包絡線）
+const UPPER: usize = 1;
+
+struct Node<T> {
+    /// 下側・上側の包絡線について，区間の中点で最も良い直線
+    lines: [Option<Line<T>>; 2],
+    /// 左右の子の番号
+    children: [Option<usize>; 2],
+}
+
+impl<T> Node<T> {
+    fn new() -> Self {
+        Self {
+            lines: [None, None],
+            children: [None, None],
+        }
+    }
+}
+
+/// Li Chao Tree
+/// - 内部では座標を番号の区間 `[begin, end)` で扱う
+pub struct LiChaoTree<T: Coef> {
+    /// 固定の場合の座標（番号 `i` の座標は `xs[i]`，動的な場合は番号がそのまま座標）
+    xs: Option<Vec<T>>,
+    begin: i128,
+    end: i128,
+    /// ノード（0は根）
+    nodes: Vec<Node<T>>,
+}
+
+impl<T: Coef> LiChaoTree<T> {
+    /// 座標の集合 `xs` 上で最小値・最大値を求める
+    pub fn new(mut xs: Vec<T>) -> Self {
+        xs.sort();
+        xs.dedup();
+        Self {
+            begin: 0,
+            end: xs.len() as i128,
+            xs: Some(xs),
+            nodes: vec![Node::new()],
+        }
+    }
+
+    /// 区間 `range` に含まれる整数の座標上で最小値・最大値を求める
+    pub fn with_range(range: Range<T>) -> Self {
+        let (begin, end) = (range.start.to_i128(), range.end.to_i128());
+        assert!(
+            end.checked_sub(begin).is_some_and(|width| width >= 0),
+            "invalid range: {range:?}"
+        );
+        Self {
+            xs: None,
+            begin,
+            end,
+            nodes: vec![Node::new()],
+        }
+    }
+
+    /// 番号 `i` の座標
+    fn x_at(&self, i: i128) -> T {
+        match &self.xs {
+            Some(xs) => xs[i as usize],
+            None => T::from_i128(i),
+        }
+    }
+
+    /// 座標 `x` 以上である最小の番号
+    fn index_lower_bound(&self, x: T) -> i128 {
+        match &self.xs {
+            Some(xs) => xs.partition_point(|&v| v < x) as i128,
+            None => x.to_i128().clamp(self.begin, self.end),
+        }
+    }
+
+    /// 座標 `x` の番号
+    fn index_of(&self, x: T) -> i128 {
+        let i = self.index_lower_bound(x);
+        assert!(
+            self.begin <= i && i < self.end && self.x_at(i) == x,
+            "x = {x:?} is not in the domain"
+        );
+        i
+    }
+
+    /// ノード `idx` の子（存在しない場合は作成する）
+    fn child(&mut self, idx: usize, side: usize) -> usize {
+        match self.nodes[idx].children[side] {
+            Some(child) => child,
+            None => {
+                let child = self.nodes.len();
+                self.nodes.push(Node::new());
+                self.nodes[idx].children[side] = Some(child);
+                child
+            }
+        }
+    }
+
+    /// 直線 `y = a x + b` を追加する
+    /// - 計算量：$O(\log n)$
+    pub fn add_line(&mut self, a: T, b: T) {
+        if self.begin < self.end {
+            self.insert(LOWER, (a, b), 0, self.begin, self.end);
+            self.insert(UPPER, (a, b), 0, self.begin, self.end);
+        }
+    }
+
+    /// `l <= x < r` の範囲のみに存在する線分 `y = a x + b` を追加する
+    /// - 計算量：$O(\log^2 n)$
+    pub fn add_segment(&mut self, l: T, r: T, a: T, b: T) {
+        let (l, r) = (self.index_lower_bound(l), self.index_lower_bound(r));
+        if l < r {
+            self.add_segment_inner((a, b), l, r, 0, self.begin, self.end);
+        }
+    }
+
+    fn add_segment_inner(
+        &mut self,
+        line: Line<T>,
+        l: i128,
+        r: i128,
+        idx: usize,
+        begin: i128,
+        end: i128,
+    ) {
+        // 区間を含まない
+        if end <= l || r <= begin {
+            return;
+        }
+        // 区間を包含する
+        if l <= begin && end <= r {
+            self.insert(LOWER, line, idx, begin, end);
+            self.insert(UPPER, line, idx, begin, end);
+            return;
+        }
+        // 区間が一部重なる
+        let mid = begin + (end - begin) / 2;
+        let left = self.child(idx, 0);
+        self.add_segment_inner(line, l, r, left, begin, mid);
+        let right = self.child(idx, 1);
+        self.add_segment_inner(line, l, r, right, mid, end);
+    }
+
+    /// 包絡線 `env` について，`u` が `v` より良いか
+    fn is_better(env: usize, u: T::Value, v: T::Value) -> bool {
+        if env == LOWER {
+            u < v
+        } else {
+            u > v
+        }
+    }
+
+    /// ノード `idx`（区間 `[begin, end)`）以下に直線 `line` を追加する
+    fn insert(
+        &mut self,
+        env: usize,
+        mut line: Line<T>,
+        mut idx: usize,
+        mut begin: i128,
+        mut end: i128,
+    ) {
+        loop {
+            let Some(cur) = self.nodes[idx].lines[env] else {
+                self.nodes[idx].lines[env] = Some(line);
+                return;
+            };
+            let mid = begin + (end - begin) / 2;
+            let (x_left, x_mid) = (self.x_at(begin), self.x_at(mid));
+            let left_better = Self::is_better(
+                env,
+                T::eval(line.0, line.1, x_left),
+                T::eval(cur.0, cur.1, x_left),
+            );
+            let mid_better = Self::is_better(
+                env,
+                T::eval(line.0, line.1, x_mid),
+                T::eval(cur.0, cur.1, x_mid),
+            );
+            // 中点で良い方をノードに残し，もう一方を子に渡す
+            if mid_better {
+                self.nodes[idx].lines[env] = Some(line);
+                line = cur;
+            }
+            if end - begin == 1 {
+                return;
+            }
+            // 左端と中点で優劣が入れ替わる場合，交点は左側にある
+            if left_better != mid_better {
+                idx = self.child(idx, 0);
+                end = mid;
+            } else {
+                idx = self.child(idx, 1);
+                begin = mid;
+            }
+        }
+    }
+
+    /// 座標 `x` における直線の最小値（直線が存在しない場合は`None`）
+    /// - 計算量：$O(\log n)$
+    pub fn min_at(&self, x: T) -> Option<T::Value> {
+        self.get(LOWER, x)
+    }
+
+    /// 座標 `x` における直線の最大値（直線が存在しない場合は`None`）
+    /// - 計算量：$O(\log n)$
+    pub fn max_at(&self, x: T) -> Option<T::Value> {
+        self.get(UPPER, x)
+    }
+
+    fn get(&self, env: usize, x: T) -> Option<T::Value> {
+        let i = self.index_of(x);
+        let mut res: Option<T::Value> = None;
+        let (mut idx, mut begin, mut end) = (0, self.begin, self.end);
+        loop {
+            if let Some((a, b)) = self.nodes[idx].lines[env] {
+                let value = T::eval(a, b, x);
+                if res.is_none_or(|res| Self::is_better(env, value, res)) {
+                    res = Some(value);
+                }
+            }
+            if end - begin == 1 {
+                return res;
+            }
+            let mid = begin + (end - begin) / 2;
+            let side = usize::from(i >= mid);
+            let Some(child) = self.nodes[idx].children[side] else {
+                return res;
+            };
+            idx = child;
+            if side == 0 {
+                end = mid;
+            } else {
+                begin = mid;
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+// Li Chao Tree
+pub mod coef;
+pub mod li_chao_tree;
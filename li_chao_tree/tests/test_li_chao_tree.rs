@@ -0,0 +1,168 @@
+use li_chao_tree::li_chao_tree::LiChaoTree;
+use rand::Rng;
+
+/// 愚直に最小値・最大値を求める
+fn naive(lines: &[(i64, i64, i64, i64)], x: i64) -> (Option<i128>, Option<i128>) {
+    let values = lines
+        .iter()
+        .filter(|&&(l, r, _, _)| l <= x && x < r)
+        .map(|&(_, _, a, b)| a as i128 * x as i128 + b as i128);
+    (values.clone().min(), values.max())
+}
+
+#[test]
+fn test_lines() {
+    let mut lct = LiChaoTree::<i64>::new(vec![-3, 0, 1, 5, 10]);
+
+    assert_eq!(lct.min_at(0), None);
+
+    lct.add_line(1, 0); // y = x
+    lct.add_line(-1, 4); // y = -x + 4
+    lct.add_line(0, 3); // y = 3
+
+    assert_eq!(lct.min_at(-3), Some(-3));
+    assert_eq!(lct.min_at(1), Some(1));
+    assert_eq!(lct.min_at(5), Some(-1));
+    assert_eq!(lct.max_at(-3), Some(7));
+    assert_eq!(lct.max_at(1), Some(3));
+    assert_eq!(lct.max_at(10), Some(10));
+}
+
+#[test]
+fn test_segments() {
+    let mut lct = LiChaoTree::<i64>::with_range(-100..100);
+
+    lct.add_segment(0, 10, 2, -5);
+    lct.add_segment(5, 50, -1, 0);
+
+    assert_eq!(lct.min_at(-1), None);
+    assert_eq!(lct.min_at(0), Some(-5));
+    assert_eq!(lct.min_at(9), Some(-9));
+    assert_eq!(lct.max_at(9), Some(13));
+    assert_eq!(lct.min_at(10), Some(-10));
+    assert_eq!(lct.max_at(50), None);
+}
+
+#[test]
+#[should_panic]
+fn test_not_in_domain() {
+    let lct = LiChaoTree::<i64>::new(vec![1, 2, 4]);
+    lct.min_at(3);
+}
+
+#[test]
+fn test_overflow() {
+    let mut lct = LiChaoTree::with_range(i64::MIN..i64::MAX);
+
+    lct.add_line(i64::MAX, i64::MAX);
+    lct.add_line(i64::MIN, 0);
+
+    let x = i64::MAX - 1;
+    assert_eq!(
+        lct.max_at(x),
+        Some(i64::MAX as i128 * x as i128 + i64::MAX as i128)
+    );
+    assert_eq!(lct.min_at(x), Some(i64::MIN as i128 * x as i128));
+    assert_eq!(
+        lct.min_at(i64::MIN),
+        Some(i64::MAX as i128 * i64::MIN as i128 + i64::MAX as i128)
+    );
+}
+
+#[test]
+fn test_i128() {
+    let mut lct = LiChaoTree::<i128>::with_range(-(1 << 80)..(1 << 80));
+
+    lct.add_line(1 << 40, 0);
+    lct.add_line(-(1 << 40), 1 << 100);
+
+    assert_eq!(lct.min_at(1 << 60), Some(0));
+    assert_eq!(lct.min_at(1 << 61), Some((1 << 100) - (1 << 101)));
+    assert_eq!(lct.max_at(-(1 << 79)), Some((1 << 100) + (1 << 119)));
+}
+
+/// `i128` の範囲の境界
+#[test]
+fn test_i128_boundary() {
+    let mut lct = LiChaoTree::<i128>::new(vec![-1, 0, 1]);
+
+    lct.add_line(i128::MAX - 1, 1);
+    lct.add_line(i128::MIN + 1, -1);
+
+    assert_eq!(lct.max_at(1), Some(i128::MAX));
+    assert_eq!(lct.min_at(1), Some(i128::MIN));
+    assert_eq!(lct.max_at(-1), Some(i128::MAX - 1));
+    assert_eq!(lct.min_at(-1), Some(i128::MIN + 3));
+    assert_eq!(lct.min_at(0), Some(-1));
+}
+
+#[test]
+#[should_panic(expected = "overflows i128")]
+fn test_i128_overflow() {
+    let mut lct = LiChaoTree::<i128>::new(vec![0, 1]);
+
+    lct.add_line(i128::MAX, 0);
+    // x = 1 での値が i128::MAX + 1 となる
+    lct.add_line(i128::MAX, 1);
+}
+
+#[test]
+fn test_random_fixed() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let xs: Vec<i64> = (0..rng.gen_range(1..40))
+            .map(|_| rng.gen_range(-1_000_000_000_000..1_000_000_000_000))
+            .collect();
+        let mut lct = LiChaoTree::new(xs.clone());
+        let mut lines = vec![];
+
+        for _ in 0..200 {
+            let a = rng.gen_range(-1_000_000_000..1_000_000_000);
+            let b = rng.gen_range(-1_000_000_000_000_000_000..1_000_000_000_000_000_000);
+            if rng.gen_bool(0.5) {
+                lct.add_line(a, b);
+                lines.push((i64::MIN, i64::MAX, a, b));
+            } else {
+                let l = xs[rng.gen_range(0..xs.len())];
+                let r = xs[rng.gen_range(0..xs.len())];
+                lct.add_segment(l, r, a, b);
+                lines.push((l, r, a, b));
+            }
+
+            let x = xs[rng.gen_range(0..xs.len())];
+            let (min, max) = naive(&lines, x);
+            assert_eq!(lct.min_at(x), min);
+            assert_eq!(lct.max_at(x), max);
+        }
+    }
+}
+
+#[test]
+fn test_random_dynamic() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let mut lct = LiChaoTree::<i64>::with_range(-1000..1000);
+        let mut lines = vec![];
+
+        for _ in 0..300 {
+            let a = rng.gen_range(-100..100);
+            let b = rng.gen_range(-10000..10000);
+            if rng.gen_bool(0.3) {
+                lct.add_line(a, b);
+                lines.push((i64::MIN, i64::MAX, a, b));
+            } else {
+                let l = rng.gen_range(-1100..1100);
+                let r = rng.gen_range(l..1100);
+                lct.add_segment(l, r, a, b);
+                lines.push((l, r, a, b));
+            }
+
+            let x = rng.gen_range(-1000..1000);
+            let (min, max) = naive(&lines, x);
+            assert_eq!(lct.min_at(x), min);
+            assert_eq!(lct.max_at(x), max);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! # フィボナッチヒープ
+//! - 根リスト・子リストは循環する双方向連結リストで持つ
+//! - 追加・併合・キーの減少は，リストをつなぎかえるのみで行い，
+//!   木の統合（consolidate）は最小値の取り出しの際にまとめて行う
+//!
+//! | 操作 | 計算量（償却） |
+//! | :-- | :-- |
+//! | `push` / `peek_min` / `meld` / `decrease_key` | $O(1)$ |
+//! | `pop_min` | $O(\log n)$ |
+
+use std::{
+    ptr::NonNull,
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    handle::{is_alive, Alive, Owner},
+    meldable_heap::MeldableHeap,
+};
+
+type NodePtr<K, V> = NonNull<Node<K, V>>;
+
+/// フィボナッチヒープのノード
+pub struct Node<K, V> {
+    key: K,
+    value: V,
+    parent: Option<NodePtr<K, V>>,
+    /// 子リストのいずれかのノード
+    child: Option<NodePtr<K, V>>,
+    /// 兄弟（循環リスト）
+    left: NodePtr<K, V>,
+    right: NodePtr<K, V>,
+    /// 子の数
+    degree: usize,
+    /// 根でなくなった後に子を失ったか
+    mark: bool,
+    alive: Alive,
+}
+
+impl<K, V> Node<K, V> {
+    /// 自身のみからなる循環リストとしてノードを確保する
+    fn new_ptr(key: K, value: V) -> NodePtr<K, V> {
+        let node = Box::new(Self {
+            key,
+            value,
+            parent: None,
+            child: None,
+            left: NonNull::dangling(),
+            right: NonNull::dangling(),
+            degree: 0,
+            mark: false,
+            alive: Rc::new(()),
+        });
+        let mut ptr = NonNull::from(Box::leak(node));
+        unsafe {
+            ptr.as_mut().left = ptr;
+            ptr.as_mut().right = ptr;
+        }
+        ptr
+    }
+}
+
+/// フィボナッチヒープ内の要素を指すハンドル
+pub struct Handle<K, V> {
+    ptr: NodePtr<K, V>,
+    alive: Weak<()>,
+    owner: Owner,
+}
+
+impl<K, V> Clone for Handle<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            alive: self.alive.clone(),
+            owner: self.owner.clone(),
+        }
+    }
+}
+
+/// 循環リスト`a`, `b`を連結する
+unsafe fn splice<K, V>(a: NodePtr<K, V>, b: NodePtr<K, V>) {
+    let a_right = (*a.as_ptr()).right;
+    let b_left = (*b.as_ptr()).left;
+    (*a.as_ptr()).right = b;
+    (*b.as_ptr()).left = a;
+    (*b_left.as_ptr()).right = a_right;
+    (*a_right.as_ptr()).left = b_left;
+}
+
+/// ノード`x`を循環リストから取り除く
+unsafe fn unlink<K, V>(x: NodePtr<K, V>) {
+    let (left, right) = ((*x.as_ptr()).left, (*x.as_ptr()).right);
+    (*left.as_ptr()).right = right;
+    (*right.as_ptr()).left = left;
+    (*x.as_ptr()).left = x;
+    (*x.as_ptr()).right = x;
+}
+
+/// 循環リストのノードを`x`から順に列挙する
+unsafe fn siblings<K, V>(x: NodePtr<K, V>) -> Vec<NodePtr<K, V>> {
+    let mut res = vec![x];
+    let mut cur = (*x.as_ptr()).right;
+    while cur != x {
+        res.push(cur);
+        cur = (*cur.as_ptr()).right;
+    }
+    res
+}
+
+/// フィボナッチヒープ
+pub struct FibonacciHeap<K: Ord, V> {
+    /// キーが最小の根（根リストの代表）
+    min: Option<NodePtr<K, V>>,
+    size: usize,
+    owner: Owner,
+}
+
+impl<K: Ord, V> FibonacciHeap<K, V> {
+    /// ハンドルが指すノード（ヒープ内にない場合は`None`）
+    fn node_of(&self, handle: &Handle<K, V>) -> Option<NodePtr<K, V>> {
+        (is_alive(&handle.alive) && handle.owner.same(&self.owner)).then_some(handle.ptr)
+    }
+
+    /// 根リストにノード`x`（単独の循環リスト）を加える
+    unsafe fn add_root(&mut self, x: NodePtr<K, V>) {
+        (*x.as_ptr()).parent = None;
+        (*x.as_ptr()).mark = false;
+        match self.min {
+            Some(min) => {
+                splice(min, x);
+                if (*x.as_ptr()).key < (*min.as_ptr()).key {
+                    self.min = Some(x);
+                }
+            }
+            None => self.min = Some(x),
+        }
+    }
+
+    /// 根`y`を根`x`の子にする
+    unsafe fn link(x: NodePtr<K, V>, y: NodePtr<K, V>) {
+        (*y.as_ptr()).parent = Some(x);
+        (*y.as_ptr()).mark = false;
+        match (*x.as_ptr()).child {
+            Some(child) => splice(child, y),
+            None => (*x.as_ptr()).child = Some(y),
+        }
+        (*x.as_ptr()).degree += 1;
+    }
+
+    /// 次数の等しい根を，次数がすべて異なるまで統合する
+    unsafe fn consolidate(&mut self, start: NodePtr<K, V>) {
+        let mut roots: Vec<Option<NodePtr<K, V>>> = vec![];
+        for mut x in siblings(start) {
+            unlink(x);
+            let mut d = (*x.as_ptr()).degree;
+            while let Some(mut y) = roots.get_mut(d).and_then(Option::take) {
+                if (*y.as_ptr()).key < (*x.as_ptr()).key {
+                    std::mem::swap(&mut x, &mut y);
+                }
+                Self::link(x, y);
+                d += 1;
+            }
+            if roots.len() <= d {
+                roots.resize(d + 1, None);
+            }
+            roots[d] = Some(x);
+        }
+        self.min = None;
+        for x in roots.into_iter().flatten() {
+            self.add_root(x);
+        }
+    }
+
+    /// ノード`x`を親`y`から切り離し，根リストに加える
+    unsafe fn cut(&mut self, x: NodePtr<K, V>, y: NodePtr<K, V>) {
+        if (*y.as_ptr()).child == Some(x) {
+            let right = (*x.as_ptr()).right;
+            (*y.as_ptr()).child = (right != x).then_some(right);
+        }
+        unlink(x);
+        (*y.as_ptr()).degree -= 1;
+        self.add_root(x);
+    }
+
+    /// 子を失ったノード`y`から根に向かって，2つ目の子を失ったノードを切り離す
+    unsafe fn cascading_cut(&mut self, mut y: NodePtr<K, V>) {
+        while let Some(z) = (*y.as_ptr()).parent {
+            if !(*y.as_ptr()).mark {
+                (*y.as_ptr()).mark = true;
+                return;
+            }
+            self.cut(y, z);
+            y = z;
+        }
+    }
+}
+
+impl<K: Ord, V> MeldableHeap<K, V> for FibonacciHeap<K, V> {
+    type Handle = Handle<K, V>;
+
+    fn new() -> Self {
+        Self {
+            min: None,
+            size: 0,
+            owner: Owner::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+
+    fn push(&mut self, key: K, value: V) -> Handle<K, V> {
+        let ptr = Node::new_ptr(key, value);
+        unsafe { self.add_root(ptr) };
+        self.size += 1;
+        Handle {
+            ptr,
+            alive: Rc::downgrade(unsafe { &(*ptr.as_ptr()).alive }),
+            owner: self.owner.clone(),
+        }
+    }
+
+    fn peek_min(&self) -> Option<(&K, &V)> {
+        self.min.map(|min| unsafe {
+            let node = &*min.as_ptr();
+            (&node.key, &node.value)
+        })
+    }
+
+    fn pop_min(&mut self) -> Option<(K, V)> {
+        let z = self.min?;
+        unsafe {
+            // 子をすべて根リストに移す
+            if let Some(child) = (*z.as_ptr()).child.take() {
+                for x in siblings(child) {
+                    (*x.as_ptr()).parent = None;
+                    (*x.as_ptr()).mark = false;
+                }
+                splice(z, child);
+            }
+            let right = (*z.as_ptr()).right;
+            unlink(z);
+            if right == z {
+                self.min = None;
+            } else {
+                self.consolidate(right);
+            }
+            self.size -= 1;
+            let node = Box::from_raw(z.as_ptr());
+            Some((node.key, node.value))
+        }
+    }
+
+    fn contains(&self, handle: &Handle<K, V>) -> bool {
+        self.node_of(handle).is_some()
+    }
+
+    fn decrease_key(&mut self, handle: &Handle<K, V>, key: K) {
+        let x = self.node_of(handle).expect("the handle is not in the heap");
+        unsafe {
+            assert!(
+                key <= (*x.as_ptr()).key,
+                "the new key must not be greater than the current key"
+            );
+            (*x.as_ptr()).key = key;
+            if let Some(y) = (*x.as_ptr()).parent {
+                if (*x.as_ptr()).key < (*y.as_ptr()).key {
+                    self.cut(x, y);
+                    self.cascading_cut(y);
+                }
+            }
+            let min = self.min.unwrap();
+            if (*x.as_ptr()).key < (*min.as_ptr()).key {
+                self.min = Some(x);
+            }
+        }
+    }
+
+    fn meld(&mut self, mut other: Self) {
+        other.owner.merge_into(&self.owner);
+        let Some(other_min) = other.min.take() else {
+            return;
+        };
+        self.size += std::mem::take(&mut other.size);
+        match self.min {
+            Some(min) => unsafe {
+                splice(min, other_min);
+                if (*other_min.as_ptr()).key < (*min.as_ptr()).key {
+                    self.min = Some(other_min);
+                }
+            },
+            None => self.min = Some(other_min),
+        }
+    }
+}
+
+impl<K: Ord, V> Default for FibonacciHeap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> Drop for FibonacciHeap<K, V> {
+    fn drop(&mut self) {
+        // 木が深くなりうるため，再帰せずに解放する
+        let mut stack: Vec<NodePtr<K, V>> = self.min.take().into_iter().collect();
+        while let Some(x) = stack.pop() {
+            unsafe {
+                for y in siblings(x) {
+                    if let Some(child) = (*y.as_ptr()).child {
+                        stack.push(child);
+                    }
+                    drop(Box::from_raw(y.as_ptr()));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! ハンドルの有効性の判定
+
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+/// ノードの生存を表すトークン
+/// - ノードが持ち，ハンドルは弱参照を持つ
+/// - ノードを解放すると，ハンドルから参照できなくなる
+pub(crate) type Alive = Rc<()>;
+
+/// ノードが生存しているか
+pub(crate) fn is_alive(alive: &Weak<()>) -> bool {
+    alive.strong_count() > 0
+}
+
+/// ヒープの識別子
+/// - 併合したヒープの識別子は，併合先の識別子を指す（Union-Find）
+#[derive(Clone)]
+pub(crate) struct Owner(Rc<RefCell<Option<Owner>>>);
+
+impl Owner {
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(None)))
+    }
+
+    /// 代表の識別子（経路圧縮を行う）
+    fn root(&self) -> Owner {
+        let mut path = vec![];
+        let mut cur = self.clone();
+        loop {
+            let next = cur.0.borrow().clone();
+            match next {
+                Some(next) => {
+                    path.push(cur);
+                    cur = next;
+                }
+                None => break,
+            }
+        }
+        for owner in path {
+            *owner.0.borrow_mut() = Some(cur.clone());
+        }
+        cur
+    }
+
+    /// 同じヒープの識別子か
+    pub fn same(&self, other: &Owner) -> bool {
+        Rc::ptr_eq(&self.root().0, &other.root().0)
+    }
+
+    /// 識別子`self`を`into`に併合する
+    pub fn merge_into(&self, into: &Owner) {
+        let (from, into) = (self.root(), into.root());
+        if !Rc::ptr_eq(&from.0, &into.0) {
+            *from.0.borrow_mut() = Some(into);
+        }
+    }
+}
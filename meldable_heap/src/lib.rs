@@ -0,0 +1,5 @@
+// 併合可能なヒープ
+pub mod fibonacci_heap;
+mod handle;
+pub mod meldable_heap;
+pub mod pairing_heap;
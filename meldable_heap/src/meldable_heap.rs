@@ -0,0 +1,39 @@
+//! 併合可能なヒープの共通のインターフェース
+
+/// 併合可能なヒープ
+/// - キー`K`の小さい順に，値`V`とともに取り出す
+pub trait MeldableHeap<K: Ord, V>: Sized {
+    /// ヒープ内の要素を指すハンドル
+    type Handle: Clone;
+
+    /// 空のヒープを作成する
+    fn new() -> Self;
+
+    /// 要素数
+    fn len(&self) -> usize;
+
+    /// 空判定
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 要素を追加し，そのハンドルを返す
+    fn push(&mut self, key: K, value: V) -> Self::Handle;
+
+    /// キーが最小の要素を参照する
+    fn peek_min(&self) -> Option<(&K, &V)>;
+
+    /// キーが最小の要素を取り出す
+    fn pop_min(&mut self) -> Option<(K, V)>;
+
+    /// ハンドルが指す要素がヒープ内にあるか
+    fn contains(&self, handle: &Self::Handle) -> bool;
+
+    /// ハンドルが指す要素のキーを，より小さいキー`key`に変更する
+    /// - ハンドルが指す要素がヒープ内にない場合や，キーが大きくなる場合は panic する
+    fn decrease_key(&mut self, handle: &Self::Handle, key: K);
+
+    /// ヒープ`other`のすべての要素を移す
+    /// - `other`のハンドルは，併合後のヒープでそのまま使える
+    fn meld(&mut self, other: Self);
+}
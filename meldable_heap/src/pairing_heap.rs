@@ -0,0 +1,230 @@
+//! # ペアリングヒープ
+//! - 各ノードは最左の子と右の兄弟を持つ（left-child, right-sibling 表現）
+//! - `prev`は，最左の子であれば親を，そうでなければ左の兄弟を指す
+//! - 最小値の取り出しでは，根の子を左から2つずつ組にして併合した後，右から順に併合する（2-pass）
+//!
+//! | 操作 | 計算量（償却） |
+//! | :-- | :-- |
+//! | `push` / `peek_min` / `meld` | $O(1)$ |
+//! | `decrease_key` | $O(\log n)$（$o(\log n)$ であることが知られている） |
+//! | `pop_min` | $O(\log n)$ |
+
+use std::{
+    ptr::NonNull,
+    rc::{Rc, Weak},
+};
+
+use crate::{
+    handle::{is_alive, Alive, Owner},
+    meldable_heap::MeldableHeap,
+};
+
+type NodePtr<K, V> = NonNull<Node<K, V>>;
+
+/// ペアリングヒープのノード
+pub struct Node<K, V> {
+    key: K,
+    value: V,
+    /// 最左の子
+    child: Option<NodePtr<K, V>>,
+    /// 右の兄弟
+    next: Option<NodePtr<K, V>>,
+    /// 親（最左の子の場合）または左の兄弟
+    prev: Option<NodePtr<K, V>>,
+    alive: Alive,
+}
+
+impl<K, V> Node<K, V> {
+    fn new_ptr(key: K, value: V) -> NodePtr<K, V> {
+        let node = Box::new(Self {
+            key,
+            value,
+            child: None,
+            next: None,
+            prev: None,
+            alive: Rc::new(()),
+        });
+        NonNull::from(Box::leak(node))
+    }
+}
+
+/// ペアリングヒープ内の要素を指すハンドル
+pub struct Handle<K, V> {
+    ptr: NodePtr<K, V>,
+    alive: Weak<()>,
+    owner: Owner,
+}
+
+impl<K, V> Clone for Handle<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            alive: self.alive.clone(),
+            owner: self.owner.clone(),
+        }
+    }
+}
+
+/// 2つの木の根`a`, `b`を併合し，新しい根を返す
+/// - `a`, `b`は兄弟を持たないとする
+unsafe fn link<K: Ord, V>(a: NodePtr<K, V>, b: NodePtr<K, V>) -> NodePtr<K, V> {
+    let (a, b) = if (*b.as_ptr()).key < (*a.as_ptr()).key {
+        (b, a)
+    } else {
+        (a, b)
+    };
+    // bをaの最左の子にする
+    if let Some(child) = (*a.as_ptr()).child {
+        (*child.as_ptr()).prev = Some(b);
+    }
+    (*b.as_ptr()).next = (*a.as_ptr()).child;
+    (*b.as_ptr()).prev = Some(a);
+    (*a.as_ptr()).child = Some(b);
+    a
+}
+
+/// 兄弟のリスト`first`, ...を2-passで併合し，新しい根を返す
+unsafe fn merge_pairs<K: Ord, V>(first: Option<NodePtr<K, V>>) -> Option<NodePtr<K, V>> {
+    // 兄弟を切り離して列挙する
+    let mut trees = vec![];
+    let mut cur = first;
+    while let Some(x) = cur {
+        cur = (*x.as_ptr()).next.take();
+        (*x.as_ptr()).prev = None;
+        trees.push(x);
+    }
+    // 左から2つずつ組にして併合する
+    let paired: Vec<_> = trees
+        .chunks(2)
+        .map(|pair| match *pair {
+            [a, b] => link(a, b),
+            [a] => a,
+            _ => unreachable!(),
+        })
+        .collect();
+    // 右から順に併合する
+    paired.into_iter().rev().reduce(|acc, x| link(x, acc))
+}
+
+/// ペアリングヒープ
+pub struct PairingHeap<K: Ord, V> {
+    root: Option<NodePtr<K, V>>,
+    size: usize,
+    owner: Owner,
+}
+
+impl<K: Ord, V> PairingHeap<K, V> {
+    /// ハンドルが指すノード（ヒープ内にない場合は`None`）
+    fn node_of(&self, handle: &Handle<K, V>) -> Option<NodePtr<K, V>> {
+        (is_alive(&handle.alive) && handle.owner.same(&self.owner)).then_some(handle.ptr)
+    }
+
+    /// 根`x`の木を併合する
+    unsafe fn meld_root(&mut self, x: NodePtr<K, V>) {
+        self.root = Some(match self.root {
+            Some(root) => link(root, x),
+            None => x,
+        });
+    }
+}
+
+impl<K: Ord, V> MeldableHeap<K, V> for PairingHeap<K, V> {
+    type Handle = Handle<K, V>;
+
+    fn new() -> Self {
+        Self {
+            root: None,
+            size: 0,
+            owner: Owner::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+
+    fn push(&mut self, key: K, value: V) -> Handle<K, V> {
+        let ptr = Node::new_ptr(key, value);
+        unsafe { self.meld_root(ptr) };
+        self.size += 1;
+        Handle {
+            ptr,
+            alive: Rc::downgrade(unsafe { &(*ptr.as_ptr()).alive }),
+            owner: self.owner.clone(),
+        }
+    }
+
+    fn peek_min(&self) -> Option<(&K, &V)> {
+        self.root.map(|root| unsafe {
+            let node = &*root.as_ptr();
+            (&node.key, &node.value)
+        })
+    }
+
+    fn pop_min(&mut self) -> Option<(K, V)> {
+        let root = self.root?;
+        unsafe {
+            self.root = merge_pairs((*root.as_ptr()).child.take());
+            self.size -= 1;
+            let node = Box::from_raw(root.as_ptr());
+            Some((node.key, node.value))
+        }
+    }
+
+    fn contains(&self, handle: &Handle<K, V>) -> bool {
+        self.node_of(handle).is_some()
+    }
+
+    fn decrease_key(&mut self, handle: &Handle<K, V>, key: K) {
+        let x = self.node_of(handle).expect("the handle is not in the heap");
+        unsafe {
+            assert!(
+                key <= (*x.as_ptr()).key,
+                "the new key must not be greater than the current key"
+            );
+            (*x.as_ptr()).key = key;
+            // 根でなければ，部分木を切り離して根と併合する
+            if let Some(prev) = (*x.as_ptr()).prev.take() {
+                let next = (*x.as_ptr()).next.take();
+                if (*prev.as_ptr()).child == Some(x) {
+                    (*prev.as_ptr()).child = next;
+                } else {
+                    (*prev.as_ptr()).next = next;
+                }
+                if let Some(next) = next {
+                    (*next.as_ptr()).prev = Some(prev);
+                }
+                self.meld_root(x);
+            }
+        }
+    }
+
+    fn meld(&mut self, mut other: Self) {
+        other.owner.merge_into(&self.owner);
+        let Some(other_root) = other.root.take() else {
+            return;
+        };
+        self.size += std::mem::take(&mut other.size);
+        unsafe { self.meld_root(other_root) };
+    }
+}
+
+impl<K: Ord, V> Default for PairingHeap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> Drop for PairingHeap<K, V> {
+    fn drop(&mut self) {
+        // 木が深くなりうるため，再帰せずに解放する
+        let mut stack: Vec<NodePtr<K, V>> = self.root.take().into_iter().collect();
+        while let Some(x) = stack.pop() {
+            unsafe {
+                let node = Box::from_raw(x.as_ptr());
+                stack.extend(node.child);
+                stack.extend(node.next);
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeSet;
+
+use meldable_heap::{
+    fibonacci_heap::FibonacciHeap, meldable_heap::MeldableHeap, pairing_heap::PairingHeap,
+};
+use rand::Rng;
+
+fn basic<H: MeldableHeap<isize, &'static str>>() {
+    let mut heap = H::new();
+
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek_min(), None);
+    assert_eq!(heap.pop_min(), None);
+
+    heap.push(5, "five");
+    let three = heap.push(3, "three");
+    let eight = heap.push(8, "eight");
+    heap.push(1, "one");
+
+    assert_eq!(heap.len(), 4);
+    assert_eq!(heap.peek_min(), Some((&1, &"one")));
+    assert_eq!(heap.pop_min(), Some((1, "one")));
+
+    heap.decrease_key(&eight, 2);
+    assert_eq!(heap.pop_min(), Some((2, "eight")));
+    assert!(!heap.contains(&eight));
+    assert!(heap.contains(&three));
+
+    heap.decrease_key(&three, 3);
+    assert_eq!(heap.pop_min(), Some((3, "three")));
+    assert_eq!(heap.pop_min(), Some((5, "five")));
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_basic() {
+    basic::<FibonacciHeap<_, _>>();
+    basic::<PairingHeap<_, _>>();
+}
+
+fn meld<H: MeldableHeap<isize, usize>>() {
+    let mut a = H::new();
+    let mut b = H::new();
+
+    let a_handles: Vec<_> = [4, 9, 2].into_iter().map(|k| a.push(k, 0)).collect();
+    let b_handles: Vec<_> = [7, 1, 6].into_iter().map(|k| b.push(k, 1)).collect();
+
+    // 併合前は，他のヒープのハンドルは使えない
+    assert!(!a.contains(&b_handles[0]));
+    assert!(!b.contains(&a_handles[0]));
+
+    a.meld(b);
+    assert_eq!(a.len(), 6);
+    assert!(a.contains(&b_handles[0]));
+
+    // 併合したヒープのハンドルをそのまま使える
+    a.decrease_key(&b_handles[0], 0);
+    a.meld(H::new());
+
+    let mut c = H::new();
+    let c_handle = c.push(3, 2);
+    c.meld(a);
+    c.decrease_key(&a_handles[1], -1);
+    assert!(c.contains(&c_handle));
+
+    let mut popped = vec![];
+    while let Some(x) = c.pop_min() {
+        popped.push(x);
+    }
+    assert_eq!(
+        popped,
+        vec![(-1, 0), (0, 1), (1, 1), (2, 0), (3, 2), (4, 0), (6, 1)]
+    );
+}
+
+#[test]
+fn test_meld() {
+    meld::<FibonacciHeap<_, _>>();
+    meld::<PairingHeap<_, _>>();
+}
+
+fn foreign_handle<H: MeldableHeap<isize, ()>>() {
+    let mut a = H::new();
+    let mut b = H::new();
+    let handle = a.push(1, ());
+    b.push(2, ());
+    b.decrease_key(&handle, 0);
+}
+
+#[test]
+#[should_panic(expected = "not in the heap")]
+fn test_foreign_handle_fibonacci() {
+    foreign_handle::<FibonacciHeap<_, _>>();
+}
+
+#[test]
+#[should_panic(expected = "not in the heap")]
+fn test_foreign_handle_pairing() {
+    foreign_handle::<PairingHeap<_, _>>();
+}
+
+fn stale_handle<H: MeldableHeap<isize, ()>>() {
+    let mut heap = H::new();
+    let handle = heap.push(1, ());
+    heap.pop_min();
+    heap.push(2, ());
+    heap.decrease_key(&handle, 0);
+}
+
+#[test]
+#[should_panic(expected = "not in the heap")]
+fn test_stale_handle_fibonacci() {
+    stale_handle::<FibonacciHeap<_, _>>();
+}
+
+#[test]
+#[should_panic(expected = "not in the heap")]
+fn test_stale_handle_pairing() {
+    stale_handle::<PairingHeap<_, _>>();
+}
+
+#[test]
+#[should_panic(expected = "must not be greater")]
+fn test_increase_key() {
+    let mut heap = FibonacciHeap::new();
+    let handle = heap.push(1, ());
+    heap.decrease_key(&handle, 2);
+}
+
+/// 乱択テストのキー（キーの重複を避けるため番号をつける）
+type Key = (isize, usize);
+
+/// ランダムな操作を`BTreeSet`と比較する
+fn random_operations<H: MeldableHeap<Key, usize>>() {
+    let mut rng = rand::thread_rng();
+    let mut heaps: Vec<H> = (0..4).map(|_| H::new()).collect();
+    let mut sets: Vec<BTreeSet<Key>> = vec![BTreeSet::new(); 4];
+    // 各ヒープの要素の (ハンドル, キー)
+    let mut handles: Vec<Vec<(H::Handle, Key)>> = vec![vec![]; 4];
+
+    for id in 0..20000 {
+        let i = rng.gen_range(0..4);
+        match rng.gen_range(0..10) {
+            0..=3 => {
+                let key = (rng.gen_range(-10000..10000), id);
+                handles[i].push((heaps[i].push(key, id), key));
+                sets[i].insert(key);
+            }
+            4..=5 if !handles[i].is_empty() => {
+                let j = rng.gen_range(0..handles[i].len());
+                let (handle, old) = handles[i][j].clone();
+                let new = (old.0 - rng.gen_range(0..1000), old.1);
+                heaps[i].decrease_key(&handle, new);
+                sets[i].remove(&old);
+                sets[i].insert(new);
+                handles[i][j].1 = new;
+            }
+            6 => {
+                // ヒープ i に別のヒープ j を併合し，j を空にする
+                let j = (i + rng.gen_range(1..4)) % 4;
+                let other = std::mem::replace(&mut heaps[j], H::new());
+                heaps[i].meld(other);
+                let set = std::mem::take(&mut sets[j]);
+                sets[i].extend(set);
+                let moved = std::mem::take(&mut handles[j]);
+                handles[i].extend(moved);
+            }
+            _ => {
+                let expected = sets[i].pop_first();
+                let popped = heaps[i].pop_min();
+                assert_eq!(popped.map(|(key, _)| key), expected);
+                if let Some((key, value)) = popped {
+                    assert_eq!(key.1, value);
+                    let j = handles[i].iter().position(|(_, k)| *k == key).unwrap();
+                    let (handle, _) = handles[i].swap_remove(j);
+                    assert!(!heaps[i].contains(&handle));
+                }
+            }
+        }
+        for (heap, set) in heaps.iter().zip(&sets) {
+            assert_eq!(heap.len(), set.len());
+            assert_eq!(heap.peek_min().map(|(key, _)| key), set.first());
+        }
+    }
+}
+
+#[test]
+fn test_random_fibonacci() {
+    random_operations::<FibonacciHeap<_, _>>();
+}
+
+#[test]
+fn test_random_pairing() {
+    random_operations::<PairingHeap<_, _>>();
+}
+
+/// 深い木を再帰せずに解放できる
+#[test]
+fn test_drop_deep() {
+    let n = 1_000_000;
+
+    // 降順に追加すると，ペアリングヒープは1本の鎖になる
+    let mut pairing = PairingHeap::new();
+    for i in (0..n).rev() {
+        pairing.push(i, ());
+    }
+    drop(pairing);
+
+    let mut fibonacci = FibonacciHeap::new();
+    for i in 0..n {
+        fibonacci.push(i, ());
+    }
+    fibonacci.pop_min();
+    drop(fibonacci);
+}
@@ -0,0 +1,150 @@
+//! キーの比較回数を数え，償却計算量を確かめる
+
+use std::cell::Cell;
+
+use meldable_heap::{
+    fibonacci_heap::FibonacciHeap, meldable_heap::MeldableHeap, pairing_heap::PairingHeap,
+};
+use rand::{seq::SliceRandom, Rng};
+
+thread_local! {
+    static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// 比較回数を数えるキー
+#[derive(Debug, PartialEq, Eq)]
+struct Key(u64);
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        COMPARISONS.with(|c| c.set(c.get() + 1));
+        self.0.cmp(&other.0)
+    }
+}
+
+/// `f`の実行中の比較回数
+fn count(f: impl FnOnce()) -> usize {
+    let before = COMPARISONS.with(Cell::get);
+    f();
+    COMPARISONS.with(Cell::get) - before
+}
+
+/// 各操作の1回あたりの比較回数
+#[derive(Debug)]
+struct Cost {
+    push: f64,
+    decrease_key: f64,
+    meld: f64,
+    pop_min: f64,
+}
+
+/// `n`要素の追加，`n`回のキーの減少，`n`回の併合，`n`回の最小値の取り出しを行う
+fn measure<H: MeldableHeap<Key, usize>>(n: usize) -> Cost {
+    let mut rng = rand::thread_rng();
+    let mut heap = H::new();
+    let mut keys: Vec<u64> = (0..n)
+        .map(|_| rng.gen_range(n as u64..(4 * n) as u64))
+        .collect();
+    let mut handles = vec![];
+
+    let push = count(|| {
+        for (i, &key) in keys.iter().enumerate() {
+            handles.push(heap.push(Key(key), i));
+        }
+    });
+
+    // 木を作ってからキーを減少させる
+    heap.pop_min();
+    let mut order: Vec<usize> = (1..n).collect();
+    order.shuffle(&mut rng);
+    let decrease_key = count(|| {
+        for &i in &order {
+            keys[i] -= rng.gen_range(0..n as u64);
+            if heap.contains(&handles[i]) {
+                heap.decrease_key(&handles[i], Key(keys[i]));
+            }
+        }
+    });
+
+    let meld = count(|| {
+        for i in 0..n {
+            let mut other = H::new();
+            other.push(Key(i as u64), n + i);
+            heap.meld(other);
+        }
+    });
+
+    let pop_min = count(|| while heap.pop_min().is_some() {});
+
+    Cost {
+        push: push as f64 / n as f64,
+        decrease_key: decrease_key as f64 / n as f64,
+        meld: meld as f64 / n as f64,
+        pop_min: pop_min as f64 / (2 * n) as f64,
+    }
+}
+
+/// 償却計算量の上界を満たすことを確かめる
+/// - `push`, `meld`：$O(1)$
+/// - `decrease_key`：1回あたり`decrease_key_bound`回以下
+/// - `pop_min`：$O(\log n)$
+fn check_bounds<H: MeldableHeap<Key, usize>>(decrease_key_bound: f64) {
+    for n in [1 << 10, 1 << 13, 1 << 16] {
+        let cost = measure::<H>(n);
+        let log_n = (n as f64).log2();
+
+        // 1回の追加・併合は，高々1回の比較（追加した要素との比較）で済む
+        assert!(cost.push <= 1.0, "n = {n}: {cost:?}");
+        assert!(cost.meld <= 1.0, "n = {n}: {cost:?}");
+        assert!(cost.decrease_key <= decrease_key_bound, "n = {n}: {cost:?}");
+        assert!(cost.pop_min <= 2.0 * log_n, "n = {n}: {cost:?}");
+    }
+}
+
+#[test]
+fn test_fibonacci_heap() {
+    // キーの確認，親・最小値との比較に加え，切り離した要素と最小値との比較が償却で高々2回
+    check_bounds::<FibonacciHeap<_, _>>(5.0);
+}
+
+#[test]
+fn test_pairing_heap() {
+    // キーの確認と，切り離した部分木と根との比較（併合のコストは取り出しで支払う）
+    check_bounds::<PairingHeap<_, _>>(2.0);
+}
+
+/// 追加した要素がすべて根（の子）になった状態から，最初の取り出しは $O(n)$ 回の比較を要するが，
+/// 2回目以降は $O(\log n)$ 回で済む
+fn first_pop_is_amortized<H: MeldableHeap<Key, usize>>() {
+    let n = 1 << 14;
+    let mut heap = H::new();
+    for i in 0..n {
+        heap.push(Key(i as u64), i);
+    }
+
+    let first = count(|| {
+        heap.pop_min();
+    });
+    assert!(first >= n / 2, "first pop: {first}");
+
+    let rest = count(|| while heap.pop_min().is_some() {});
+    let log_n = (n as f64).log2();
+    assert!(
+        (first + rest) as f64 <= 4.0 * n as f64 * log_n,
+        "total: {}",
+        first + rest
+    );
+    assert!((rest as f64) / (n as f64) <= 4.0 * log_n, "rest: {rest}");
+}
+
+#[test]
+fn test_first_pop() {
+    first_pop_is_amortized::<FibonacciHeap<_, _>>();
+    first_pop_is_amortized::<PairingHeap<_, _>>();
+}
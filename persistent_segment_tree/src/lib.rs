@@ -0,0 +1,4 @@
+// 永続セグ木
+pub mod persistent_segment_tree;
+
+pub use dynamic_segment_tree::alg;
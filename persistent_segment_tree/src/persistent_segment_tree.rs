@@ -0,0 +1,265 @@
+//! # persistent segment tree
+//! - 1点更新のたびに新しい版（バージョン）をつくる永続セグメント木
+//! - 更新では根から葉までの経路のみを複製し，それ以外のノードは以前の版と共有する
+//! - ノードはすべて1つの配列（アリーナ）に格納し，子は番号で参照する
+
+use std::fmt::{self, Debug};
+
+use crate::alg::{monoids::Add, Monoid};
+
+/// ノードの番号
+type NodeId = u32;
+
+/// すべての要素が単位元である部分木（子は自分自身）
+const NIL: NodeId = 0;
+
+struct Node<M: Monoid> {
+    val: M::Val,
+    left: NodeId,
+    right: NodeId,
+}
+
+/// 版
+/// - 版の根となるノードの番号を持つ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Version(NodeId);
+
+/// 永続セグメント木
+pub struct PersistentSegmentTree<M: Monoid> {
+    n: usize,
+    nodes: Vec<Node<M>>,
+}
+
+impl<M: Monoid> PersistentSegmentTree<M> {
+    /// 長さ`n`の永続セグメント木を作成する
+    pub fn new(n: usize) -> Self {
+        Self::with_capacity(n, 0)
+    }
+
+    /// 長さ`n`の永続セグメント木を，`updates`回の更新分の領域を確保して作成する
+    pub fn with_capacity(n: usize, updates: usize) -> Self {
+        let height = n.next_power_of_two().trailing_zeros() as usize + 1;
+        let mut nodes = Vec::with_capacity(1 + updates * height);
+        nodes.push(Node {
+            val: M::E,
+            left: NIL,
+            right: NIL,
+        });
+        Self { n, nodes }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// これまでに作成したノードの数
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// すべての要素が単位元である版
+    pub fn empty(&self) -> Version {
+        Version(NIL)
+    }
+
+    /// 配列`src`を要素にもつ版を作成する
+    /// - 計算量：$O(n)$
+    pub fn build(&mut self, src: Vec<M::Val>) -> Version {
+        assert_eq!(src.len(), self.n, "the length of src must be {}", self.n);
+        if self.n == 0 {
+            return self.empty();
+        }
+        let mut src = src.into_iter();
+        Version(self.build_inner(&mut src, 0, self.n))
+    }
+
+    fn build_inner(
+        &mut self,
+        src: &mut impl Iterator<Item = M::Val>,
+        begin: usize,
+        end: usize,
+    ) -> NodeId {
+        if end - begin == 1 {
+            let val = src.next().unwrap();
+            return self.alloc(val, NIL, NIL);
+        }
+        let mid = (begin + end) / 2;
+        let left = self.build_inner(src, begin, mid);
+        let right = self.build_inner(src, mid, end);
+        self.alloc_merged(left, right)
+    }
+
+    /// 版`version`の`i`番目の要素を`x`に更新した版を作成する
+    /// - 計算量：$O(\log n)$
+    pub fn set(&mut self, version: Version, i: usize, x: M::Val) -> Version {
+        self.update(version, i, |_| x)
+    }
+
+    /// 版`version`の`i`番目の要素に`x`を加えた版を作成する
+    /// - 計算量：$O(\log n)$
+    pub fn add(&mut self, version: Version, i: usize, x: M::Val) -> Version {
+        self.update(version, i, |old| M::op(old, &x))
+    }
+
+    fn update(&mut self, version: Version, i: usize, f: impl FnOnce(&M::Val) -> M::Val) -> Version {
+        assert!(
+            i < self.n,
+            "index out of range: the len is {} but the index is {i}",
+            self.n
+        );
+        Version(self.update_inner(version.0, i, f, 0, self.n))
+    }
+
+    fn update_inner(
+        &mut self,
+        node: NodeId,
+        i: usize,
+        f: impl FnOnce(&M::Val) -> M::Val,
+        begin: usize,
+        end: usize,
+    ) -> NodeId {
+        if end - begin == 1 {
+            let val = f(&self.nodes[node as usize].val);
+            return self.alloc(val, NIL, NIL);
+        }
+        let Node { left, right, .. } = self.nodes[node as usize];
+        let mid = (begin + end) / 2;
+        // 更新する側の子のみを複製する
+        if i < mid {
+            let left = self.update_inner(left, i, f, begin, mid);
+            self.alloc_merged(left, right)
+        } else {
+            let right = self.update_inner(right, i, f, mid, end);
+            self.alloc_merged(left, right)
+        }
+    }
+
+    /// 版`version`の`i`番目の要素
+    /// - 計算量：$O(\log n)$
+    pub fn get(&self, version: Version, i: usize) -> M::Val {
+        assert!(
+            i < self.n,
+            "index out of range: the len is {} but the index is {i}",
+            self.n
+        );
+        let (mut node, mut begin, mut end) = (version.0, 0, self.n);
+        while end - begin > 1 {
+            let mid = (begin + end) / 2;
+            if i < mid {
+                (node, end) = (self.nodes[node as usize].left, mid);
+            } else {
+                (node, begin) = (self.nodes[node as usize].right, mid);
+            }
+        }
+        self.nodes[node as usize].val.clone()
+    }
+
+    /// 版`version`の区間`[left, right)`の要素を集約する
+    /// - 計算量：$O(\log n)$
+    pub fn get_range(&self, version: Version, left: usize, right: usize) -> M::Val {
+        assert!(
+            left <= right && right <= self.n,
+            "range [{left}, {right}) is out of [0, {})",
+            self.n
+        );
+        self.get_range_inner(version.0, left, right, 0, self.n)
+    }
+
+    fn get_range_inner(
+        &self,
+        node: NodeId,
+        left: usize,
+        right: usize,
+        begin: usize,
+        end: usize,
+    ) -> M::Val {
+        // 区間を含まない
+        if node == NIL || end <= left || right <= begin {
+            M::E
+        }
+        // 区間を包含する
+        else if left <= begin && end <= right {
+            self.nodes[node as usize].val.clone()
+        }
+        // 区間が重なる
+        else {
+            let Node {
+                left: l, right: r, ..
+            } = self.nodes[node as usize];
+            let mid = (begin + end) / 2;
+            let l_val = self.get_range_inner(l, left, right, begin, mid);
+            let r_val = self.get_range_inner(r, left, right, mid, end);
+            M::op(&l_val, &r_val)
+        }
+    }
+
+    /// 版`version`の要素を配列にする
+    pub fn to_vec(&self, version: Version) -> Vec<M::Val> {
+        (0..self.n).map(|i| self.get(version, i)).collect()
+    }
+
+    /// 新しいノードを作成する
+    fn alloc(&mut self, val: M::Val, left: NodeId, right: NodeId) -> NodeId {
+        let id = NodeId::try_from(self.nodes.len()).expect("too many nodes");
+        self.nodes.push(Node { val, left, right });
+        id
+    }
+
+    /// 子`left`, `right`を集約したノードを作成する
+    fn alloc_merged(&mut self, left: NodeId, right: NodeId) -> NodeId {
+        let val = M::op(
+            &self.nodes[left as usize].val,
+            &self.nodes[right as usize].val,
+        );
+        self.alloc(val, left, right)
+    }
+}
+
+/// 和のモノイドでは，各位置の値を個数とみなして`k`番目の要素を探索できる
+/// - 最大値などを求めるモノイドでは差分が意味をもたないため，`Add`に限る
+impl PersistentSegmentTree<Add> {
+    /// 版`hi`と版`lo`の差分をとった配列において，`k`番目（0-indexed）の要素がある位置
+    /// - 各位置の値を個数とみなし，差分の個数が非負であるとする
+    /// - 差分の個数の合計が`k`以下の場合は`None`を返す
+    /// - 計算量：$O(\log n)$
+    ///
+    /// 接頭辞`a[0, r)`の各値の出現回数を版`r`として持てば，
+    /// `kth_diff(versions[l], versions[r], k)`は`a[l, r)`で`k`番目に小さい値となる
+    pub fn kth_diff(&self, lo: Version, hi: Version, k: usize) -> Option<usize> {
+        let diff =
+            |lo: NodeId, hi: NodeId| self.nodes[hi as usize].val - self.nodes[lo as usize].val;
+        let mut k = k as isize;
+        if self.n == 0 || diff(lo.0, hi.0) <= k {
+            return None;
+        }
+        let (mut lo, mut hi) = (lo.0, hi.0);
+        let (mut begin, mut end) = (0, self.n);
+        while end - begin > 1 {
+            let (lo_node, hi_node) = (&self.nodes[lo as usize], &self.nodes[hi as usize]);
+            let mid = (begin + end) / 2;
+            let left = diff(lo_node.left, hi_node.left);
+            if k < left {
+                (lo, hi, end) = (lo_node.left, hi_node.left, mid);
+            } else {
+                k -= left;
+                (lo, hi, begin) = (lo_node.right, hi_node.right, mid);
+            }
+        }
+        Some(begin)
+    }
+}
+
+impl<M: Monoid> Debug for PersistentSegmentTree<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PersistentSegmentTree")
+            .field("len", &self.n)
+            .field("node_count", &self.nodes.len())
+            .finish()
+    }
+}
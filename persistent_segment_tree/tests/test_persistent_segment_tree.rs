@@ -0,0 +1,141 @@
+use persistent_segment_tree::{
+    alg::monoids::{Add, Str},
+    persistent_segment_tree::PersistentSegmentTree,
+};
+use rand::Rng;
+
+#[test]
+fn test_versions() {
+    let mut seg = PersistentSegmentTree::<Add>::new(5);
+
+    let v0 = seg.build(vec![1, 2, 3, 4, 5]);
+    let v1 = seg.set(v0, 2, 10);
+    let v2 = seg.add(v1, 0, 5);
+    let v3 = seg.set(v0, 4, 0);
+
+    assert_eq!(seg.to_vec(seg.empty()), vec![0; 5]);
+    assert_eq!(seg.to_vec(v0), vec![1, 2, 3, 4, 5]);
+    assert_eq!(seg.to_vec(v1), vec![1, 2, 10, 4, 5]);
+    assert_eq!(seg.to_vec(v2), vec![6, 2, 10, 4, 5]);
+    assert_eq!(seg.to_vec(v3), vec![1, 2, 3, 4, 0]);
+
+    assert_eq!(seg.get_range(v0, 0, 5), 15);
+    assert_eq!(seg.get_range(v1, 1, 3), 12);
+    assert_eq!(seg.get_range(v2, 0, 1), 6);
+    assert_eq!(seg.get_range(v3, 3, 5), 4);
+    assert_eq!(seg.get_range(v3, 2, 2), 0);
+    assert_eq!(seg.get(v2, 2), 10);
+}
+
+#[test]
+fn test_noncommutative() {
+    let mut seg = PersistentSegmentTree::<Str>::new(4);
+
+    let v0 = seg.build(["a", "b", "c", "d"].map(String::from).to_vec());
+    let v1 = seg.add(v0, 1, "x".to_string());
+
+    assert_eq!(seg.get_range(v0, 0, 4), "abcd");
+    assert_eq!(seg.get_range(v1, 0, 4), "abxcd");
+    assert_eq!(seg.get_range(v1, 1, 3), "bxc");
+}
+
+#[test]
+fn test_sharing() {
+    let n = 1 << 10;
+    let mut seg = PersistentSegmentTree::<Add>::new(n);
+    let mut version = seg.empty();
+
+    for i in 0..1000 {
+        version = seg.add(version, i % n, 1);
+    }
+
+    // 1回の更新で増えるノードは根から葉までの11個のみ
+    assert_eq!(seg.node_count(), 1 + 1000 * 11);
+    assert_eq!(seg.get_range(version, 0, n), 1000);
+}
+
+#[test]
+fn test_kth_smallest() {
+    let a = [5, 1, 4, 1, 3, 9, 2, 6];
+
+    // 座標圧縮
+    let mut vals = a.to_vec();
+    vals.sort();
+    vals.dedup();
+
+    // 版r：a[0, r)に含まれる各値の出現回数
+    let mut seg = PersistentSegmentTree::<Add>::new(vals.len());
+    let mut versions = vec![seg.empty()];
+    for x in a {
+        let i = vals.binary_search(&x).unwrap();
+        let last = *versions.last().unwrap();
+        versions.push(seg.add(last, i, 1));
+    }
+
+    let kth =
+        |l: usize, r: usize, k: usize| seg.kth_diff(versions[l], versions[r], k).map(|i| vals[i]);
+
+    assert_eq!(kth(0, 8, 0), Some(1));
+    assert_eq!(kth(0, 8, 1), Some(1));
+    assert_eq!(kth(0, 8, 7), Some(9));
+    assert_eq!(kth(2, 5, 1), Some(3));
+    assert_eq!(kth(4, 8, 3), Some(9));
+    assert_eq!(kth(4, 8, 4), None);
+    assert_eq!(kth(3, 3, 0), None);
+}
+
+#[test]
+fn test_random() {
+    let mut rng = rand::thread_rng();
+    let n = 37;
+
+    let mut seg = PersistentSegmentTree::<Add>::with_capacity(n, 2000);
+    let init: Vec<isize> = (0..n).map(|_| rng.gen_range(-100..100)).collect();
+    let mut versions = vec![seg.build(init.clone())];
+    let mut arrays = vec![init];
+
+    for _ in 0..2000 {
+        // 過去の任意の版から更新する
+        let v = rng.gen_range(0..versions.len());
+        let i = rng.gen_range(0..n);
+        let x = rng.gen_range(-100..100);
+        versions.push(seg.set(versions[v], i, x));
+        let mut arr = arrays[v].clone();
+        arr[i] = x;
+        arrays.push(arr);
+
+        let v = rng.gen_range(0..versions.len());
+        let l = rng.gen_range(0..=n);
+        let r = rng.gen_range(l..=n);
+        assert_eq!(
+            seg.get_range(versions[v], l, r),
+            arrays[v][l..r].iter().sum::<isize>()
+        );
+    }
+}
+
+#[test]
+fn test_random_kth() {
+    let mut rng = rand::thread_rng();
+    let m = 20;
+    let a: Vec<usize> = (0..300).map(|_| rng.gen_range(0..m)).collect();
+
+    let mut seg = PersistentSegmentTree::<Add>::new(m);
+    let mut versions = vec![seg.empty()];
+    for &x in &a {
+        let last = *versions.last().unwrap();
+        versions.push(seg.add(last, x, 1));
+    }
+
+    for _ in 0..1000 {
+        let l = rng.gen_range(0..=a.len());
+        let r = rng.gen_range(l..=a.len());
+        let k = rng.gen_range(0..=r - l);
+        let mut sorted = a[l..r].to_vec();
+        sorted.sort();
+        assert_eq!(
+            seg.kth_diff(versions[l], versions[r], k),
+            sorted.get(k).copied()
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! 古典的な赤黒木
+//! - 赤いノードは左右どちらの子にもなれる（2-3-4木と対応する）
+//! - 挿入はOkasakiの再平衡化，削除は黒高さの不足を親に伝えながら解消する
+
+#![allow(non_snake_case)]
+
+use std::{cmp::Ordering, mem};
+
+use crate::{
+    node::{is_red, rotate_left, rotate_right, Color, RbTreeNode, RbTreeNodeInner},
+    variant::Variant,
+};
+
+/// 古典的な赤黒木
+pub struct Classic;
+
+type Node<K, V> = Box<RbTreeNodeInner<K, V>>;
+
+/// 削除の結果：(新しい部分木, 削除された要素, 黒高さが1減ったか)
+type Deleted<K, V> = (RbTreeNode<K, V>, Option<(K, V)>, bool);
+
+/// 部分木`T`を子`child`で置き換えたときの結果
+/// - `T`が黒いとき，`child`が赤ければ黒くして黒高さを保つ
+fn replace_with_child<K: Ord, V>(
+    T: RbTreeNodeInner<K, V>,
+    child: RbTreeNode<K, V>,
+) -> Deleted<K, V> {
+    let short = T.color == Color::Black && !is_red(&child);
+    let mut child = child;
+    if let Some(C) = child.as_mut() {
+        C.color = Color::Black;
+    }
+    (child, Some((T.key, T.value)), short)
+}
+
+/// 左部分木の黒高さが1不足しているとき，これを解消する
+/// - 戻り値：(新しい部分木, なお黒高さが1不足しているか)
+fn fix_left<K: Ord, V>(mut T: Node<K, V>) -> (Node<K, V>, bool) {
+    if is_red(&T.left) {
+        T.left.as_mut().unwrap().color = Color::Black;
+        return (T, false);
+    }
+    if is_red(&T.right) {
+        // 兄弟が赤いとき，回転して兄弟を黒くしてから解消する
+        let mut S = rotate_left(T);
+        S.color = Color::Black;
+        let mut T = S.left.take().unwrap();
+        T.color = Color::Red;
+        (T, _) = fix_left(T);
+        S.left = Some(T);
+        S.update();
+        return (S, false);
+    }
+    let S = T.right.as_mut().unwrap();
+    if is_red(&S.right) {
+        let color = T.color;
+        let mut S = rotate_left(T);
+        S.color = color;
+        S.left.as_mut().unwrap().color = Color::Black;
+        S.right.as_mut().unwrap().color = Color::Black;
+        (S, false)
+    } else if is_red(&S.left) {
+        T.right = T.right.take().map(rotate_right);
+        let color = T.color;
+        let mut S = rotate_left(T);
+        S.color = color;
+        S.left.as_mut().unwrap().color = Color::Black;
+        S.right.as_mut().unwrap().color = Color::Black;
+        (S, false)
+    } else {
+        // 兄弟とその子がすべて黒いとき，兄弟を赤くして不足を親に伝える
+        S.color = Color::Red;
+        let short = T.color == Color::Black;
+        T.color = Color::Black;
+        T.update();
+        (T, short)
+    }
+}
+
+/// 右部分木の黒高さが1不足しているとき，これを解消する
+/// - 戻り値：(新しい部分木, なお黒高さが1不足しているか)
+fn fix_right<K: Ord, V>(mut T: Node<K, V>) -> (Node<K, V>, bool) {
+    if is_red(&T.right) {
+        T.right.as_mut().unwrap().color = Color::Black;
+        return (T, false);
+    }
+    if is_red(&T.left) {
+        let mut S = rotate_right(T);
+        S.color = Color::Black;
+        let mut T = S.right.take().unwrap();
+        T.color = Color::Red;
+        (T, _) = fix_right(T);
+        S.right = Some(T);
+        S.update();
+        return (S, false);
+    }
+    let S = T.left.as_mut().unwrap();
+    if is_red(&S.left) {
+        let color = T.color;
+        let mut S = rotate_right(T);
+        S.color = color;
+        S.left.as_mut().unwrap().color = Color::Black;
+        S.right.as_mut().unwrap().color = Color::Black;
+        (S, false)
+    } else if is_red(&S.right) {
+        T.left = T.left.take().map(rotate_left);
+        let color = T.color;
+        let mut S = rotate_right(T);
+        S.color = color;
+        S.left.as_mut().unwrap().color = Color::Black;
+        S.right.as_mut().unwrap().color = Color::Black;
+        (S, false)
+    } else {
+        S.color = Color::Red;
+        let short = T.color == Color::Black;
+        T.color = Color::Black;
+        T.update();
+        (T, short)
+    }
+}
+
+/// 左部分木を削除した結果をつなぎ直す
+fn after_delete_left<K: Ord, V>(mut T: Node<K, V>, deleted: Deleted<K, V>) -> Deleted<K, V> {
+    let (new_left, removed, short) = deleted;
+    T.left = new_left;
+    T.update();
+    if short {
+        let (T, short) = fix_left(T);
+        (Some(T), removed, short)
+    } else {
+        (Some(T), removed, false)
+    }
+}
+
+/// 右部分木を削除した結果をつなぎ直す
+fn after_delete_right<K: Ord, V>(mut T: Node<K, V>, deleted: Deleted<K, V>) -> Deleted<K, V> {
+    let (new_right, removed, short) = deleted;
+    T.right = new_right;
+    T.update();
+    if short {
+        let (T, short) = fix_right(T);
+        (Some(T), removed, short)
+    } else {
+        (Some(T), removed, false)
+    }
+}
+
+fn delete_min_inner<K: Ord, V>(root: RbTreeNode<K, V>) -> Deleted<K, V> {
+    let Some(mut T) = root else {
+        return (None, None, false);
+    };
+    if T.left.is_none() {
+        let right = T.right.take();
+        return replace_with_child(*T, right);
+    }
+    let deleted = delete_min_inner(T.left.take());
+    after_delete_left(T, deleted)
+}
+
+fn delete_max_inner<K: Ord, V>(root: RbTreeNode<K, V>) -> Deleted<K, V> {
+    let Some(mut T) = root else {
+        return (None, None, false);
+    };
+    if T.right.is_none() {
+        let left = T.left.take();
+        return replace_with_child(*T, left);
+    }
+    let deleted = delete_max_inner(T.right.take());
+    after_delete_right(T, deleted)
+}
+
+fn delete_inner<K: Ord, V>(root: RbTreeNode<K, V>, key: &K) -> Deleted<K, V> {
+    let Some(mut T) = root else {
+        return (None, None, false);
+    };
+    match key.cmp(&T.key) {
+        Ordering::Less => {
+            let deleted = delete_inner(T.left.take(), key);
+            after_delete_left(T, deleted)
+        }
+        Ordering::Greater => {
+            let deleted = delete_inner(T.right.take(), key);
+            after_delete_right(T, deleted)
+        }
+        Ordering::Equal => {
+            if T.right.is_none() {
+                let left = T.left.take();
+                return replace_with_child(*T, left);
+            }
+            // 右部分木の最小値を現在のノードに代入
+            let (new_right, min, short) = delete_min_inner(T.right.take());
+            let (min_key, min_value) = min.unwrap();
+            let removed = (
+                mem::replace(&mut T.key, min_key),
+                mem::replace(&mut T.value, min_value),
+            );
+            after_delete_right(T, (new_right, Some(removed), short))
+        }
+    }
+}
+
+impl Variant for Classic {
+    fn balance<K: Ord, V>(mut T: Node<K, V>) -> Node<K, V> {
+        T.update();
+        if T.color == Color::Red {
+            return T;
+        }
+        // 赤いノードが連続している箇所を，赤い根と2つの黒い子に組み替える
+        if is_red(&T.left) {
+            let L = T.left.as_ref().unwrap();
+            if is_red(&L.right) && !is_red(&L.left) {
+                T.left = T.left.take().map(rotate_left);
+            }
+            if is_red(&T.left.as_ref().unwrap().left) {
+                let mut L = rotate_right(T);
+                L.color = Color::Red;
+                L.left.as_mut().unwrap().color = Color::Black;
+                L.right.as_mut().unwrap().color = Color::Black;
+                return L;
+            }
+        }
+        if is_red(&T.right) {
+            let R = T.right.as_ref().unwrap();
+            if is_red(&R.left) && !is_red(&R.right) {
+                T.right = T.right.take().map(rotate_right);
+            }
+            if is_red(&T.right.as_ref().unwrap().right) {
+                let mut R = rotate_left(T);
+                R.color = Color::Red;
+                R.left.as_mut().unwrap().color = Color::Black;
+                R.right.as_mut().unwrap().color = Color::Black;
+                return R;
+            }
+        }
+        T
+    }
+
+    fn delete<K: Ord, V>(root: RbTreeNode<K, V>, key: &K) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+        let (new_root, removed, _) = delete_inner(root, key);
+        (new_root, removed)
+    }
+
+    fn delete_min<K: Ord, V>(root: RbTreeNode<K, V>) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+        let (new_root, min, _) = delete_min_inner(root);
+        (new_root, min)
+    }
+
+    fn delete_max<K: Ord, V>(root: RbTreeNode<K, V>) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+        let (new_root, max, _) = delete_max_inner(root);
+        (new_root, max)
+    }
+}
@@ -0,0 +1,67 @@
+#![allow(non_snake_case)]
+
+use std::ops::{Bound, RangeBounds, RangeFull};
+
+use crate::node::{RbTreeNode, RbTreeNodeInner};
+
+// ----- iterator -----
+/// 区間に含まれる要素を昇順に返すイテレータ
+pub struct RbTreeRange<'a, K: 'a + Ord, V: 'a, R: RangeBounds<K>> {
+    unvisited: Vec<&'a RbTreeNodeInner<K, V>>,
+    range: R,
+}
+
+/// すべての要素を昇順に返すイテレータ
+pub type RbTreeIterator<'a, K, V> = RbTreeRange<'a, K, V, RangeFull>;
+
+impl<'a, K: Ord, V, R: RangeBounds<K>> RbTreeRange<'a, K, V, R> {
+    /// `root`を根とする木のうち，`range`に含まれる要素のイテレータを作成する
+    pub fn new(root: &'a RbTreeNode<K, V>, range: R) -> Self {
+        let mut iter = RbTreeRange {
+            unvisited: vec![],
+            range,
+        };
+        // 左端以上の要素へ続く経路を積む
+        let mut node = root;
+        while let Some(T) = node.as_deref() {
+            let is_ge_start = match iter.range.start_bound() {
+                Bound::Included(s) => s <= &T.key,
+                Bound::Excluded(s) => s < &T.key,
+                Bound::Unbounded => true,
+            };
+            if is_ge_start {
+                iter.unvisited.push(T);
+                node = &T.left;
+            } else {
+                node = &T.right;
+            }
+        }
+        iter
+    }
+
+    fn push_left_edge(&mut self, mut node: &'a RbTreeNode<K, V>) {
+        while let Some(T) = node.as_deref() {
+            self.unvisited.push(T);
+            node = &T.left;
+        }
+    }
+}
+
+impl<'a, K: Ord, V, R: RangeBounds<K>> Iterator for RbTreeRange<'a, K, V, R> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let T = self.unvisited.pop()?;
+        let is_le_end = match self.range.end_bound() {
+            Bound::Included(e) => &T.key <= e,
+            Bound::Excluded(e) => &T.key < e,
+            Bound::Unbounded => true,
+        };
+        if !is_le_end {
+            self.unvisited.clear();
+            return None;
+        }
+        self.push_left_edge(&T.right);
+        Some((&T.key, &T.value))
+    }
+}
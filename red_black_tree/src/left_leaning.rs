@@ -0,0 +1,173 @@
+//! 左傾赤黒木（Left-Leaning Red-Black Tree）
+//! - 赤いノードは必ず左の子になる（2-3木と1対1に対応する）
+
+#![allow(non_snake_case)]
+
+use std::mem;
+
+use crate::{
+    node::{get, is_red, rotate_left, rotate_right, Color, RbTreeNode, RbTreeNodeInner},
+    variant::Variant,
+};
+
+/// 左傾赤黒木
+pub struct LeftLeaning;
+
+type Node<K, V> = Box<RbTreeNodeInner<K, V>>;
+
+/// 右に傾いた赤いリンクを左に傾ける
+fn lean_left<K: Ord, V>(T: Node<K, V>) -> Node<K, V> {
+    let color = T.color;
+    let mut R = rotate_left(T);
+    R.color = color;
+    R.left.as_mut().unwrap().color = Color::Red;
+    R
+}
+
+/// 左に傾いた赤いリンクを右に傾ける
+fn lean_right<K: Ord, V>(T: Node<K, V>) -> Node<K, V> {
+    let color = T.color;
+    let mut L = rotate_right(T);
+    L.color = color;
+    L.right.as_mut().unwrap().color = Color::Red;
+    L
+}
+
+/// 左の子か左の孫が赤くなるように，右から赤いリンクを借りる
+fn move_red_left<K: Ord, V>(mut T: Node<K, V>) -> Node<K, V> {
+    T.flip_colors();
+    if is_red(&T.right.as_ref().unwrap().left) {
+        T.right = T.right.take().map(lean_right);
+        T = lean_left(T);
+        T.flip_colors();
+    }
+    T
+}
+
+/// 右の子か右の孫が赤くなるように，左から赤いリンクを借りる
+fn move_red_right<K: Ord, V>(mut T: Node<K, V>) -> Node<K, V> {
+    T.flip_colors();
+    if is_red(&T.left.as_ref().unwrap().left) {
+        T = lean_right(T);
+        T.flip_colors();
+    }
+    T
+}
+
+/// 削除の前に，根が2ノードであれば赤くして3ノードとみなす
+fn prepare_root<K: Ord, V>(root: &mut Node<K, V>) {
+    if !is_red(&root.left) && !is_red(&root.right) {
+        root.color = Color::Red;
+    }
+}
+
+fn delete_min_inner<K: Ord, V>(mut T: Node<K, V>) -> (RbTreeNode<K, V>, (K, V)) {
+    if T.left.is_none() {
+        return (None, (T.key, T.value));
+    }
+    if !is_red(&T.left) && !is_red(&T.left.as_ref().unwrap().left) {
+        T = move_red_left(T);
+    }
+    let (new_left, min) = delete_min_inner(T.left.take().unwrap());
+    T.left = new_left;
+    (Some(LeftLeaning::balance(T)), min)
+}
+
+fn delete_max_inner<K: Ord, V>(mut T: Node<K, V>) -> (RbTreeNode<K, V>, (K, V)) {
+    if is_red(&T.left) {
+        T = lean_right(T);
+    }
+    if T.right.is_none() {
+        return (None, (T.key, T.value));
+    }
+    if !is_red(&T.right) && !is_red(&T.right.as_ref().unwrap().left) {
+        T = move_red_right(T);
+    }
+    let (new_right, max) = delete_max_inner(T.right.take().unwrap());
+    T.right = new_right;
+    (Some(LeftLeaning::balance(T)), max)
+}
+
+/// `key`が部分木に存在することを仮定して削除する
+fn delete_inner<K: Ord, V>(mut T: Node<K, V>, key: &K) -> (RbTreeNode<K, V>, (K, V)) {
+    let removed;
+    if key < &T.key {
+        if !is_red(&T.left) && !is_red(&T.left.as_ref().unwrap().left) {
+            T = move_red_left(T);
+        }
+        (T.left, removed) = delete_inner(T.left.take().unwrap(), key);
+    } else {
+        if is_red(&T.left) {
+            T = lean_right(T);
+        }
+        if key == &T.key && T.right.is_none() {
+            return (None, (T.key, T.value));
+        }
+        if !is_red(&T.right) && !is_red(&T.right.as_ref().unwrap().left) {
+            T = move_red_right(T);
+        }
+        if key == &T.key {
+            // 右部分木の最小値を現在のノードに代入
+            let (new_right, (min_key, min_value)) = delete_min_inner(T.right.take().unwrap());
+            T.right = new_right;
+            removed = (
+                mem::replace(&mut T.key, min_key),
+                mem::replace(&mut T.value, min_value),
+            );
+        } else {
+            (T.right, removed) = delete_inner(T.right.take().unwrap(), key);
+        }
+    }
+    (Some(LeftLeaning::balance(T)), removed)
+}
+
+impl Variant for LeftLeaning {
+    fn balance<K: Ord, V>(mut T: Node<K, V>) -> Node<K, V> {
+        if is_red(&T.right) && !is_red(&T.left) {
+            T = lean_left(T);
+        }
+        if is_red(&T.left) && is_red(&T.left.as_ref().unwrap().left) {
+            T = lean_right(T);
+        }
+        if is_red(&T.left) && is_red(&T.right) {
+            T.flip_colors();
+        }
+        T.update();
+        T
+    }
+
+    fn delete<K: Ord, V>(root: RbTreeNode<K, V>, key: &K) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+        if get(&root, key).is_none() {
+            return (root, None);
+        }
+        let mut T = root.unwrap();
+        prepare_root(&mut T);
+        let (new_root, removed) = delete_inner(T, key);
+        (new_root, Some(removed))
+    }
+
+    fn delete_min<K: Ord, V>(root: RbTreeNode<K, V>) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+        let Some(mut T) = root else {
+            return (None, None);
+        };
+        prepare_root(&mut T);
+        let (new_root, min) = delete_min_inner(T);
+        (new_root, Some(min))
+    }
+
+    fn delete_max<K: Ord, V>(root: RbTreeNode<K, V>) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+        let Some(mut T) = root else {
+            return (None, None);
+        };
+        prepare_root(&mut T);
+        let (new_root, max) = delete_max_inner(T);
+        (new_root, Some(max))
+    }
+
+    fn validate_node<K: Ord, V>(T: &RbTreeNodeInner<K, V>) -> Result<(), String> {
+        if is_red(&T.right) {
+            return Err("a red node leans right".to_string());
+        }
+        Ok(())
+    }
+}
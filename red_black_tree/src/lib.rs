@@ -0,0 +1,8 @@
+pub mod classic;
+pub mod iterator;
+pub mod left_leaning;
+pub mod map;
+pub mod node;
+pub mod print_util;
+pub mod set;
+pub mod variant;
@@ -0,0 +1,421 @@
+#![allow(non_snake_case)]
+
+use std::{cmp::Ordering, mem};
+
+use crate::variant::Variant;
+
+/// ノードの色
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Black,
+}
+
+impl Color {
+    /// 色を反転する
+    pub fn flip(self) -> Self {
+        match self {
+            Color::Red => Color::Black,
+            Color::Black => Color::Red,
+        }
+    }
+}
+
+/// 赤黒木のノード
+#[derive(Debug)]
+pub struct RbTreeNodeInner<K: Ord, V> {
+    pub key: K,
+    pub value: V,
+    pub color: Color,
+    /// 部分木のノード数
+    pub size: usize,
+    pub left: RbTreeNode<K, V>,
+    pub right: RbTreeNode<K, V>,
+}
+
+impl<K: Ord, V> RbTreeNodeInner<K, V> {
+    /// 赤いノードを作成する
+    pub fn new(key: K, value: V) -> RbTreeNode<K, V> {
+        Some(Box::new(RbTreeNodeInner {
+            key,
+            value,
+            color: Color::Red,
+            size: 1,
+            left: None,
+            right: None,
+        }))
+    }
+
+    /// 子の情報から大きさを再計算する
+    pub(crate) fn update(&mut self) {
+        self.size = len(&self.left) + len(&self.right) + 1;
+    }
+
+    /// 自身と子の色を反転する
+    pub(crate) fn flip_colors(&mut self) {
+        self.color = self.color.flip();
+        if let Some(left) = self.left.as_mut() {
+            left.color = left.color.flip();
+        }
+        if let Some(right) = self.right.as_mut() {
+            right.color = right.color.flip();
+        }
+    }
+}
+
+pub type RbTreeNode<K, V> = Option<Box<RbTreeNodeInner<K, V>>>;
+
+/// 分割した結果：(左の木, 分割位置の要素, 右の木)
+pub type Splitted<K, V> = (RbTreeNode<K, V>, Option<(K, V)>, RbTreeNode<K, V>);
+
+/// 左右の木の黒高さを伴う分割の結果
+type SplittedWithHeight<K, V> = (
+    (RbTreeNode<K, V>, usize),
+    Option<(K, V)>,
+    (RbTreeNode<K, V>, usize),
+);
+
+/// ノードが赤いか判定する（空のノードは黒）
+pub fn is_red<K: Ord, V>(node: &RbTreeNode<K, V>) -> bool {
+    node.as_ref().is_some_and(|node| node.color == Color::Red)
+}
+
+/// 部分木のノード数
+pub fn len<K: Ord, V>(node: &RbTreeNode<K, V>) -> usize {
+    node.as_ref().map_or(0, |node| node.size)
+}
+
+/// 黒高さ（根から葉までの経路上にある黒いノードの数）
+pub fn black_height<K: Ord, V>(root: &RbTreeNode<K, V>) -> usize {
+    let mut height = 0;
+    let mut node = root;
+    while let Some(T) = node {
+        if T.color == Color::Black {
+            height += 1;
+        }
+        node = &T.left;
+    }
+    height
+}
+
+/// 右回転（色は変更しない）
+/// ```text
+///      T          L
+///     ↙ ↘        ↙ ↘
+///    L   R  =>  A   T
+///   ↙ ↘            ↙ ↘
+///  A   B          B   R
+/// ```
+pub(crate) fn rotate_right<K: Ord, V>(
+    mut T: Box<RbTreeNodeInner<K, V>>,
+) -> Box<RbTreeNodeInner<K, V>> {
+    let Some(mut L) = T.left.take() else {
+        return T;
+    };
+    T.left = L.right.take();
+    T.update();
+    L.right = Some(T);
+    L.update();
+    L
+}
+
+/// 左回転（色は変更しない）
+/// ```text
+///    T              R
+///   ↙ ↘            ↙ ↘
+///  L   R    =>    T   B
+///     ↙ ↘        ↙ ↘
+///    A   B      L   A
+/// ```
+pub(crate) fn rotate_left<K: Ord, V>(
+    mut T: Box<RbTreeNodeInner<K, V>>,
+) -> Box<RbTreeNodeInner<K, V>> {
+    let Some(mut R) = T.right.take() else {
+        return T;
+    };
+    T.right = R.left.take();
+    T.update();
+    R.left = Some(T);
+    R.update();
+    R
+}
+
+/// 根を黒くする
+pub(crate) fn blacken<K: Ord, V>(mut root: RbTreeNode<K, V>) -> RbTreeNode<K, V> {
+    if let Some(T) = root.as_mut() {
+        T.color = Color::Black;
+    }
+    root
+}
+
+/// 値`key`の値の参照を取得する
+pub fn get<'a, K: Ord, V>(root: &'a RbTreeNode<K, V>, key: &K) -> Option<&'a V> {
+    let T = root.as_ref()?;
+    match key.cmp(&T.key) {
+        Ordering::Less => get(&T.left, key),
+        Ordering::Greater => get(&T.right, key),
+        Ordering::Equal => Some(&T.value),
+    }
+}
+
+/// 値`key`の値の可変参照を取得する
+pub fn get_mut<'a, K: Ord, V>(root: &'a mut RbTreeNode<K, V>, key: &K) -> Option<&'a mut V> {
+    let T = root.as_mut()?;
+    match key.cmp(&T.key) {
+        Ordering::Less => get_mut(&mut T.left, key),
+        Ordering::Greater => get_mut(&mut T.right, key),
+        Ordering::Equal => Some(&mut T.value),
+    }
+}
+
+/// 値`key`に`value`を挿入する
+/// - すでに値が存在する場合は更新し，もとの値を返す
+pub fn insert<P: Variant, K: Ord, V>(
+    root: RbTreeNode<K, V>,
+    key: K,
+    value: V,
+) -> (RbTreeNode<K, V>, Option<V>) {
+    let (new_root, old_value) = insert_inner::<P, K, V>(root, key, value);
+    (blacken(new_root), old_value)
+}
+
+fn insert_inner<P: Variant, K: Ord, V>(
+    root: RbTreeNode<K, V>,
+    key: K,
+    value: V,
+) -> (RbTreeNode<K, V>, Option<V>) {
+    let Some(mut T) = root else {
+        return (RbTreeNodeInner::new(key, value), None);
+    };
+    let old_value = match key.cmp(&T.key) {
+        Ordering::Less => {
+            let old_value;
+            (T.left, old_value) = insert_inner::<P, K, V>(T.left, key, value);
+            old_value
+        }
+        Ordering::Greater => {
+            let old_value;
+            (T.right, old_value) = insert_inner::<P, K, V>(T.right, key, value);
+            old_value
+        }
+        Ordering::Equal => {
+            let old_value = mem::replace(&mut T.value, value);
+            return (Some(T), Some(old_value));
+        }
+    };
+    (Some(P::balance(T)), old_value)
+}
+
+/// 値`key`を削除し，削除されたノードの`(key, value)`を返す
+pub fn delete<P: Variant, K: Ord, V>(
+    root: RbTreeNode<K, V>,
+    key: &K,
+) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+    let (new_root, old_key_value) = P::delete(root, key);
+    (blacken(new_root), old_key_value)
+}
+
+/// 最小の要素を削除する
+/// - 戻り値：(新しい根, 削除された`(key, value)`)
+pub fn delete_min<P: Variant, K: Ord, V>(
+    root: RbTreeNode<K, V>,
+) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+    let (new_root, min) = P::delete_min(root);
+    (blacken(new_root), min)
+}
+
+/// 最大の要素を削除する
+/// - 戻り値：(新しい根, 削除された`(key, value)`)
+pub fn delete_max<P: Variant, K: Ord, V>(
+    root: RbTreeNode<K, V>,
+) -> (RbTreeNode<K, V>, Option<(K, V)>) {
+    let (new_root, max) = P::delete_max(root);
+    (blacken(new_root), max)
+}
+
+/// 根を黒くし，黒高さを更新する
+fn blacken_with_height<K: Ord, V>(root: RbTreeNode<K, V>, h: usize) -> (RbTreeNode<K, V>, usize) {
+    let h = if is_red(&root) { h + 1 } else { h };
+    (blacken(root), h)
+}
+
+/// `left`のすべてのキー < `key` < `right`のすべてのキー であるとき，3つを連結する
+/// - 計算量：$O(\log n)$（黒高さの計算を含む）
+pub fn join<P: Variant, K: Ord, V>(
+    left: RbTreeNode<K, V>,
+    key: K,
+    value: V,
+    right: RbTreeNode<K, V>,
+) -> RbTreeNode<K, V> {
+    let (lh, rh) = (black_height(&left), black_height(&right));
+    join_with_height::<P, K, V>(left, lh, key, value, right, rh).0
+}
+
+/// 黒高さ`lh`の木`left`と黒高さ`rh`の木`right`を`key`で連結し，(連結した木, その黒高さ)を返す
+/// - 黒高さの高い方の木の辺を，もう一方の木と黒高さが等しい黒いノードまで下り，
+///   そこに`key`を持つ赤いノードをつなげてから挿入と同様に再平衡化する
+/// - 計算量：$O(|bh_{left} - bh_{right}| + 1)$
+fn join_with_height<P: Variant, K: Ord, V>(
+    left: RbTreeNode<K, V>,
+    lh: usize,
+    key: K,
+    value: V,
+    right: RbTreeNode<K, V>,
+    rh: usize,
+) -> (RbTreeNode<K, V>, usize) {
+    let (left, lh) = blacken_with_height(left, lh);
+    let (right, rh) = blacken_with_height(right, rh);
+    let root = match lh.cmp(&rh) {
+        Ordering::Greater => join_right::<P, K, V>(left, lh, key, value, right, rh),
+        Ordering::Less => join_left::<P, K, V>(left, lh, key, value, right, rh),
+        Ordering::Equal => {
+            let mut T = RbTreeNodeInner::new(key, value).unwrap();
+            T.left = left;
+            T.right = right;
+            T.update();
+            Some(T)
+        }
+    };
+    // 根の黒高さは高い方の木と等しく，根が赤ければ黒くした分だけ高くなる
+    blacken_with_height(root, lh.max(rh))
+}
+
+/// 左の木`node`（黒高さ`h`）の右の辺を下り，黒高さ`rh`の黒いノードの位置で`right`とつなげる
+fn join_right<P: Variant, K: Ord, V>(
+    node: RbTreeNode<K, V>,
+    h: usize,
+    key: K,
+    value: V,
+    right: RbTreeNode<K, V>,
+    rh: usize,
+) -> RbTreeNode<K, V> {
+    if h == rh && !is_red(&node) {
+        let mut T = RbTreeNodeInner::new(key, value).unwrap();
+        T.left = node;
+        T.right = right;
+        T.update();
+        return Some(T);
+    }
+    let mut T = node.unwrap();
+    let child_h = if T.color == Color::Black { h - 1 } else { h };
+    T.right = join_right::<P, K, V>(T.right, child_h, key, value, right, rh);
+    Some(P::balance(T))
+}
+
+/// 右の木`node`（黒高さ`h`）の左の辺を下り，黒高さ`lh`の黒いノードの位置で`left`とつなげる
+fn join_left<P: Variant, K: Ord, V>(
+    left: RbTreeNode<K, V>,
+    lh: usize,
+    key: K,
+    value: V,
+    node: RbTreeNode<K, V>,
+    h: usize,
+) -> RbTreeNode<K, V> {
+    if h == lh && !is_red(&node) {
+        let mut T = RbTreeNodeInner::new(key, value).unwrap();
+        T.left = left;
+        T.right = node;
+        T.update();
+        return Some(T);
+    }
+    let mut T = node.unwrap();
+    let child_h = if T.color == Color::Black { h - 1 } else { h };
+    T.left = join_left::<P, K, V>(left, lh, key, value, T.left, child_h);
+    Some(P::balance(T))
+}
+
+/// `left`のすべてのキー < `right`のすべてのキー であるとき，2つを連結する
+pub fn join2<P: Variant, K: Ord, V>(
+    left: RbTreeNode<K, V>,
+    right: RbTreeNode<K, V>,
+) -> RbTreeNode<K, V> {
+    let (right, min) = delete_min::<P, K, V>(right);
+    match min {
+        Some((key, value)) => join::<P, K, V>(left, key, value, right),
+        None => left,
+    }
+}
+
+/// `key`で木を分割する
+/// - 戻り値：(`key`未満の木, `key`に一致する要素, `key`より大きい木)
+/// - 計算量：$O(\log n)$
+pub fn split<P: Variant, K: Ord, V>(root: RbTreeNode<K, V>, key: &K) -> Splitted<K, V> {
+    let h = black_height(&root);
+    let ((l, lh), mid, (r, rh)) = split_inner::<P, K, V>(root, h, key);
+    debug_assert_eq!((black_height(&l), black_height(&r)), (lh, rh));
+    (l, mid, r)
+}
+
+/// 黒高さ`h`の木を`key`で分割し，左右の木をそれぞれの黒高さとともに返す
+/// - 経路上の各段で`join_with_height`を呼ぶ．連結する木の黒高さの差の和は$O(\log n)$に抑えられる
+fn split_inner<P: Variant, K: Ord, V>(
+    root: RbTreeNode<K, V>,
+    h: usize,
+    key: &K,
+) -> SplittedWithHeight<K, V> {
+    let Some(T) = root else {
+        return ((None, 0), None, (None, 0));
+    };
+    let T = *T;
+    let child_h = if T.color == Color::Black { h - 1 } else { h };
+    match key.cmp(&T.key) {
+        Ordering::Less => {
+            let (l, mid, (r, rh)) = split_inner::<P, K, V>(T.left, child_h, key);
+            let r = join_with_height::<P, K, V>(r, rh, T.key, T.value, T.right, child_h);
+            (blacken_with_height(l.0, l.1), mid, r)
+        }
+        Ordering::Greater => {
+            let ((l, lh), mid, r) = split_inner::<P, K, V>(T.right, child_h, key);
+            let l = join_with_height::<P, K, V>(T.left, child_h, T.key, T.value, l, lh);
+            (l, mid, blacken_with_height(r.0, r.1))
+        }
+        Ordering::Equal => (
+            blacken_with_height(T.left, child_h),
+            Some((T.key, T.value)),
+            blacken_with_height(T.right, child_h),
+        ),
+    }
+}
+
+/// 木が赤黒木の条件を満たしているか検証する
+/// - 二分探索木であること
+/// - 根が黒いこと
+/// - 赤いノードの子が黒いこと
+/// - 根から葉までのすべての経路で黒高さが等しいこと
+/// - 各ノードが保持する大きさが正しいこと
+/// - 各方式に固有の条件（左傾赤黒木では，赤いノードが右の子にならないこと）
+pub fn validate<P: Variant, K: Ord, V>(root: &RbTreeNode<K, V>) -> Result<(), String> {
+    if is_red(root) {
+        return Err("the root is red".to_string());
+    }
+    validate_inner::<P, K, V>(root, None, None).map(|_| ())
+}
+
+/// 戻り値：(黒高さ, 大きさ)
+fn validate_inner<P: Variant, K: Ord, V>(
+    root: &RbTreeNode<K, V>,
+    lower: Option<&K>,
+    upper: Option<&K>,
+) -> Result<(usize, usize), String> {
+    let Some(T) = root else {
+        return Ok((0, 0));
+    };
+    if lower.is_some_and(|lower| lower >= &T.key) || upper.is_some_and(|upper| &T.key >= upper) {
+        return Err("keys are not sorted".to_string());
+    }
+    if T.color == Color::Red && (is_red(&T.left) || is_red(&T.right)) {
+        return Err("a red node has a red child".to_string());
+    }
+    P::validate_node(T)?;
+    let (lh, ls) = validate_inner::<P, K, V>(&T.left, lower, Some(&T.key))?;
+    let (rh, rs) = validate_inner::<P, K, V>(&T.right, Some(&T.key), upper)?;
+    if lh != rh {
+        return Err(format!("black heights differ: left {lh}, right {rh}"));
+    }
+    let h = lh + usize::from(T.color == Color::Black);
+    let s = ls + rs + 1;
+    if T.size != s {
+        return Err(format!("wrong size: stored {}, actual {s}", T.size));
+    }
+    Ok((h, s))
+}
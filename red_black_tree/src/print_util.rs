@@ -0,0 +1,34 @@
+//! 木を整形して表示するための関数
+
+use crate::node::{Color, RbTreeNode};
+use std::fmt::Debug;
+
+pub fn pretty_print<K, V>(root: &RbTreeNode<K, V>)
+where
+    K: Ord + Debug,
+    V: Debug,
+{
+    println!("┌─ Tree ───────────────");
+    fmt_inner(root, 0);
+    println!("└──────────────────────");
+}
+
+/// print recursive
+/// - 赤いノードには`*`を付けて表示する
+fn fmt_inner<K, V>(node: &RbTreeNode<K, V>, depth: usize)
+where
+    K: Ord + Debug,
+    V: Debug,
+{
+    if let Some(node) = node.as_ref() {
+        fmt_inner(&node.left, depth + 1);
+        println!(
+            "│{}{}({:?}, {:?})",
+            "    ".repeat(depth),
+            if node.color == Color::Red { "*" } else { "" },
+            node.key,
+            node.value
+        );
+        fmt_inner(&node.right, depth + 1);
+    }
+}
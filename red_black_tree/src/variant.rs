@@ -0,0 +1,30 @@
+//! 赤黒木の平衡化の方式
+
+use crate::node::{RbTreeNode, RbTreeNodeInner};
+
+pub use crate::{classic::Classic, left_leaning::LeftLeaning};
+
+/// 赤黒木の平衡化の方式
+/// - 挿入・連結のあとの再平衡化と，削除の方法を定める
+pub trait Variant {
+    /// 子孫に赤いノードが連続する箇所があるとき，回転と色の変更によって解消する
+    /// - 挿入・連結で部分木に赤いノードが追加されたあと，経路上のノードに対して下から順に呼び出す
+    fn balance<K: Ord, V>(node: Box<RbTreeNodeInner<K, V>>) -> Box<RbTreeNodeInner<K, V>>;
+
+    /// 値`key`を削除し，削除されたノードの`(key, value)`を返す
+    /// - 戻り値の根の色は任意
+    fn delete<K: Ord, V>(root: RbTreeNode<K, V>, key: &K) -> (RbTreeNode<K, V>, Option<(K, V)>);
+
+    /// 最小の要素を削除する
+    /// - 戻り値の根の色は任意
+    fn delete_min<K: Ord, V>(root: RbTreeNode<K, V>) -> (RbTreeNode<K, V>, Option<(K, V)>);
+
+    /// 最大の要素を削除する
+    /// - 戻り値の根の色は任意
+    fn delete_max<K: Ord, V>(root: RbTreeNode<K, V>) -> (RbTreeNode<K, V>, Option<(K, V)>);
+
+    /// 方式に固有の条件をノード単位で検証する
+    fn validate_node<K: Ord, V>(_node: &RbTreeNodeInner<K, V>) -> Result<(), String> {
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+
+use rand::prelude::*;
+use red_black_tree::{
+    map::RbTreeMap,
+    variant::{Classic, LeftLeaning, Variant},
+};
+
+#[test]
+fn test_insert() {
+    let mut map = RbTreeMap::new();
+
+    let alphabet = [
+        "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa",
+        "lambda", "mu", "nu", "xi", "omicron", "pi", "rho", "sigma", "tau", "upsilon", "phi",
+        "chi", "psi", "omega",
+    ];
+
+    for (i, &a) in alphabet.iter().enumerate() {
+        assert_eq!(map.insert(a, i), None);
+        assert_eq!(map.validate(), Ok(()));
+    }
+
+    map.pretty_print();
+
+    assert_eq!(map.len(), 24);
+    assert_eq!(map.get(&"iota"), Some(&8));
+    assert_eq!(map.insert("iota", 100), Some(8));
+    assert_eq!(map.get(&"iota"), Some(&100));
+    assert_eq!(map.get(&"foo"), None);
+
+    let mut sorted = alphabet.to_vec();
+    sorted.sort();
+    assert!(map.iter().map(|(&k, _)| k).eq(sorted));
+}
+
+#[test]
+fn test_range() {
+    let map: RbTreeMap<i32, i32> = (0..100).map(|i| (i * 2, i)).collect();
+
+    assert!(map.range(10..20).map(|(&k, _)| k).eq([10, 12, 14, 16, 18]));
+    assert!(map.range(..5).map(|(&k, _)| k).eq([0, 2, 4]));
+    assert!(map.range(195..).map(|(&k, _)| k).eq([196, 198]));
+    assert_eq!(map.range(50..50).count(), 0);
+    assert_eq!(map.range(..).count(), 100);
+}
+
+fn check_sorted_black_height<P: Variant>() {
+    let mut map = RbTreeMap::<u32, (), P>::default();
+
+    for i in 0..1 << 12 {
+        map.insert(i, ());
+        assert_eq!(map.validate(), Ok(()));
+    }
+
+    // 黒高さは log2(n+1) 以下
+    assert!(map.black_height() <= 12);
+}
+
+#[test]
+fn test_sorted_black_height() {
+    check_sorted_black_height::<LeftLeaning>();
+    check_sorted_black_height::<Classic>();
+}
+
+fn check_random<P: Variant>() {
+    const ITER: usize = 5000;
+    const SIZE: u32 = 500;
+
+    let mut rng = thread_rng();
+    let mut map = RbTreeMap::<u32, usize, P>::default();
+    let mut expected = BTreeMap::new();
+
+    for i in 0..ITER {
+        let key = rng.gen_range(0..SIZE);
+        match rng.gen_range(0..10) {
+            0..=5 => assert_eq!(map.insert(key, i), expected.insert(key, i)),
+            6..=7 => assert_eq!(map.remove(&key), expected.remove(&key)),
+            8 => assert_eq!(map.pop_first(), expected.pop_first()),
+            _ => assert_eq!(map.pop_last(), expected.pop_last()),
+        }
+        assert_eq!(map.validate(), Ok(()));
+        assert_eq!(map.len(), expected.len());
+        assert_eq!(map.get(&key), expected.get(&key));
+    }
+
+    assert!(map.iter().eq(expected.iter()));
+}
+
+#[test]
+fn test_random() {
+    check_random::<LeftLeaning>();
+    check_random::<Classic>();
+}
+
+fn check_split_join<P: Variant>() {
+    let mut rng = thread_rng();
+
+    for _ in 0..100 {
+        let n = rng.gen_range(0..300);
+        let mut map: RbTreeMap<u32, u32, P> = (0..n).map(|i| (i * 3, i)).collect();
+
+        let key = rng.gen_range(0..n * 3 + 3);
+        let right = map.split_off(&key);
+
+        assert_eq!(map.validate(), Ok(()));
+        assert_eq!(right.validate(), Ok(()));
+        assert!(map.iter().all(|(&k, _)| k < key));
+        assert!(right.iter().all(|(&k, _)| k >= key));
+        assert_eq!(map.len() + right.len(), n as usize);
+
+        let joined = RbTreeMap::join(map, right);
+        assert_eq!(joined.validate(), Ok(()));
+        assert!(joined
+            .iter()
+            .map(|(&k, &v)| (k, v))
+            .eq((0..n).map(|i| (i * 3, i))));
+    }
+}
+
+#[test]
+fn test_split_join() {
+    check_split_join::<LeftLeaning>();
+    check_split_join::<Classic>();
+}
+
+fn check_append<P: Variant>() {
+    // 黒高さの異なる木の連結
+    let mut left: RbTreeMap<_, _, P> = (0..10).map(|i| (i, i)).collect();
+    let mut right: RbTreeMap<_, _, P> = (10..1000).map(|i| (i, i)).collect();
+    left.append(&mut right);
+    assert_eq!(left.validate(), Ok(()));
+    assert!(right.is_empty());
+    assert!(left.iter().map(|(&k, _)| k).eq(0..1000));
+
+    let mut left: RbTreeMap<_, _, P> = (0..1000).map(|i| (i, i)).collect();
+    let mut right: RbTreeMap<_, _, P> = (1000..1010).map(|i| (i, i)).collect();
+    left.append(&mut right);
+    assert_eq!(left.validate(), Ok(()));
+    assert!(left.iter().map(|(&k, _)| k).eq(0..1010));
+
+    // 重複のある連結
+    let mut a: RbTreeMap<_, _, P> = (0..100).step_by(2).map(|i| (i, 'a')).collect();
+    let mut b: RbTreeMap<_, _, P> = (0..100).step_by(3).map(|i| (i, 'b')).collect();
+    a.append(&mut b);
+    assert_eq!(a.validate(), Ok(()));
+    assert_eq!(a.get(&6), Some(&'b'));
+    assert_eq!(a.get(&4), Some(&'a'));
+}
+
+#[test]
+fn test_append() {
+    check_append::<LeftLeaning>();
+    check_append::<Classic>();
+}
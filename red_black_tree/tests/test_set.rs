@@ -0,0 +1,65 @@
+use std::collections::BTreeSet;
+
+use rand::prelude::*;
+use red_black_tree::{set::RbTreeSet, variant::Classic};
+
+#[test]
+fn test_insert_remove() {
+    let mut set = RbTreeSet::new();
+
+    assert!(set.insert(3));
+    assert!(set.insert(1));
+    assert!(set.insert(4));
+    assert!(!set.insert(1));
+    assert!(set.insert(5));
+
+    assert_eq!(format!("{set:?}"), "{1, 3, 4, 5}");
+    assert!(set.contains(&4));
+    assert!(!set.contains(&2));
+
+    assert!(set.remove(&4));
+    assert!(!set.remove(&4));
+    assert_eq!(format!("{set:?}"), "{1, 3, 5}");
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn test_random() {
+    let mut rng = thread_rng();
+    let mut set = RbTreeSet::new();
+    let mut expected = BTreeSet::new();
+
+    for _ in 0..5000 {
+        let x = rng.gen_range(0..300);
+        if rng.gen_bool(0.5) {
+            assert_eq!(set.insert(x), expected.insert(x));
+        } else {
+            assert_eq!(set.remove(&x), expected.remove(&x));
+        }
+    }
+
+    assert_eq!(set.validate(), Ok(()));
+    assert!(set.iter().eq(expected.iter()));
+    assert!(set.range(100..200).eq(expected.range(100..200)));
+
+    let right = set.split_off(&150);
+    let expected_right = expected.split_off(&150);
+    assert!(set.iter().eq(expected.iter()));
+    assert!(right.iter().eq(expected_right.iter()));
+
+    let joined = RbTreeSet::join(set, right);
+    assert_eq!(joined.validate(), Ok(()));
+    assert_eq!(joined.len(), expected.len() + expected_right.len());
+}
+
+#[test]
+fn test_classic() {
+    let mut set: RbTreeSet<u32, Classic> = (0..100).collect();
+
+    for x in (0..100).step_by(3) {
+        assert!(set.remove(&x));
+    }
+
+    assert_eq!(set.validate(), Ok(()));
+    assert!(set.iter().copied().eq((0..100).filter(|x| x % 3 != 0)));
+}
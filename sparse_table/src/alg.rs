@@ -0,0 +1,36 @@
+//! 代数的構造
+//! - モノイドは`dynamic_segment_tree::alg`のものを用いる
+
+use std::ops::{Bound, RangeBounds};
+
+pub use dynamic_segment_tree::alg::{Commutative, Monoid};
+
+/// 冪等なモノイド
+/// - 任意の`x`について`op(x, x) = x`が成り立つ
+pub trait Idempotent: Monoid {}
+
+pub mod monoids {
+    pub use dynamic_segment_tree::alg::monoids::*;
+
+    use super::Idempotent;
+
+    impl Idempotent for Min {}
+    impl Idempotent for Max {}
+    impl Idempotent for Gcd {}
+}
+
+/// 区間`range`を長さ`n`の配列上の半開区間`[l, r)`に変換する
+pub(crate) fn parse_range<R: RangeBounds<usize>>(range: R, n: usize) -> (usize, usize) {
+    let l = match range.start_bound() {
+        Bound::Included(&l) => l,
+        Bound::Excluded(&l) => l + 1,
+        Bound::Unbounded => 0,
+    };
+    let r = match range.end_bound() {
+        Bound::Included(&r) => r + 1,
+        Bound::Excluded(&r) => r,
+        Bound::Unbounded => n,
+    };
+    assert!(l <= r && r <= n, "range [{l}, {r}) is out of [0, {n})");
+    (l, r)
+}
@@ -0,0 +1,85 @@
+//! # Disjoint Sparse Table
+//! - 静的な配列に対する区間クエリを $O(1)$ で処理する
+//! - 段 `k` では配列を長さ `2^k` のブロックに分け，各ブロックの中央から
+//!   左側へは累積（接尾辞），右側へは累積（接頭辞）を持つ
+//! - 区間の集約値は互いに素な2区間の集約値から求めるため，任意のモノイドに対応する
+
+use std::{fmt::Debug, ops::RangeBounds};
+
+use crate::alg::{parse_range, Monoid};
+
+pub struct DisjointSparseTable<M: Monoid> {
+    size: usize,
+    /// `table[0]` は元の配列
+    table: Vec<Vec<M::Val>>,
+}
+
+impl<M: Monoid> DisjointSparseTable<M> {
+    /// 配列から作成
+    /// - 計算量：$O(n \log n)$
+    pub fn from_vec(src: Vec<M::Val>) -> Self {
+        let size = src.len();
+        let log = size.next_power_of_two().trailing_zeros() as usize;
+        let mut table = vec![src];
+        for k in 1..=log {
+            let half = 1 << (k - 1);
+            let mut row = table[0].clone();
+            for mid in (half..size).step_by(half * 2) {
+                // 中央から左へ累積
+                for i in (mid - half..mid - 1).rev() {
+                    row[i] = M::op(&table[0][i], &row[i + 1]);
+                }
+                // 中央から右へ累積
+                for i in mid + 1..(mid + half).min(size) {
+                    row[i] = M::op(&row[i - 1], &table[0][i]);
+                }
+            }
+            table.push(row);
+        }
+        Self { size, table }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// 1点取得
+    pub fn get(&self, i: usize) -> M::Val {
+        assert!(i < self.size, "index {i} is out of [0, {})", self.size);
+        self.table[0][i].clone()
+    }
+
+    /// 区間取得
+    /// - 計算量：$O(1)$
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> M::Val {
+        let (l, r) = parse_range(range, self.size);
+        if l == r {
+            return M::E;
+        }
+        let r = r - 1;
+        if l == r {
+            return self.table[0][l].clone();
+        }
+        // l と r が同じブロックの異なる側にある段
+        let k = (l ^ r).ilog2() as usize + 1;
+        M::op(&self.table[k][l], &self.table[k][r])
+    }
+}
+
+impl<M: Monoid> FromIterator<M::Val> for DisjointSparseTable<M> {
+    fn from_iter<I: IntoIterator<Item = M::Val>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<M: Monoid> Debug for DisjointSparseTable<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.table[0]).finish()
+    }
+}
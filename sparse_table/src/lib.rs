@@ -0,0 +1,4 @@
+// スパーステーブル
+pub mod alg;
+pub mod disjoint_sparse_table;
+pub mod sparse_table;
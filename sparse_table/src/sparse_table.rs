@@ -0,0 +1,72 @@
+//! # スパーステーブル
+//! - 静的な配列に対する区間クエリを $O(1)$ で処理する
+//! - `table[k][i]` は区間 `[i, i + 2^k)` の集約値を持つ
+//! - 重なりのある2区間の集約値を合わせるため，演算は冪等である必要がある
+
+use std::{fmt::Debug, ops::RangeBounds};
+
+use crate::alg::{parse_range, Idempotent};
+
+pub struct SparseTable<M: Idempotent> {
+    size: usize,
+    table: Vec<Vec<M::Val>>,
+}
+
+impl<M: Idempotent> SparseTable<M> {
+    /// 配列から作成
+    /// - 計算量：$O(n \log n)$
+    pub fn from_vec(src: Vec<M::Val>) -> Self {
+        let size = src.len();
+        let mut table = vec![src];
+        let mut width = 1;
+        while width * 2 <= size {
+            let prev = table.last().unwrap();
+            let next = (0..=size - width * 2)
+                .map(|i| M::op(&prev[i], &prev[i + width]))
+                .collect();
+            table.push(next);
+            width *= 2;
+        }
+        Self { size, table }
+    }
+
+    /// 要素数
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// 1点取得
+    pub fn get(&self, i: usize) -> M::Val {
+        assert!(i < self.size, "index {i} is out of [0, {})", self.size);
+        self.table[0][i].clone()
+    }
+
+    /// 区間取得
+    /// - 計算量：$O(1)$
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> M::Val {
+        let (l, r) = parse_range(range, self.size);
+        if l == r {
+            return M::E;
+        }
+        // 区間 [l, r) を長さ 2^k の2区間 [l, l + 2^k), [r - 2^k, r) で覆う
+        let k = (r - l).ilog2() as usize;
+        M::op(&self.table[k][l], &self.table[k][r - (1 << k)])
+    }
+}
+
+impl<M: Idempotent> FromIterator<M::Val> for SparseTable<M> {
+    fn from_iter<I: IntoIterator<Item = M::Val>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<M: Idempotent> Debug for SparseTable<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.table[0]).finish()
+    }
+}
@@ -0,0 +1,110 @@
+use lazy_segment_tree::{
+    alg::Monoid as LazyMonoid, lazy_segment_tree::LazySegmentTree, structures::RSQandRAQ,
+};
+use rand::Rng;
+use sparse_table::{
+    alg::monoids::{Add, Min, Str},
+    disjoint_sparse_table::DisjointSparseTable,
+};
+
+/// 文字列の連結（作用なし）
+struct Concat;
+impl LazyMonoid for Concat {
+    type X = String;
+    type M = ();
+    const IX: Self::X = String::new();
+    const IM: Self::M = ();
+    fn fx(x: &Self::X, y: &Self::X) -> Self::X {
+        x.to_string() + y
+    }
+    fn fa(x: &Self::X, _: &Self::M) -> Self::X {
+        x.clone()
+    }
+    fn fm(_: &Self::M, _: &Self::M) -> Self::M {}
+    fn fp(_: &Self::M, _: usize) -> Self::M {}
+}
+
+#[test]
+fn test_add() {
+    let dst = DisjointSparseTable::<Add>::from_vec(vec![3, -1, 4, 1, -5, 9, 2]);
+
+    assert_eq!(dst.len(), 7);
+    assert_eq!(dst.get(4), -5);
+    assert_eq!(dst.get_range(..), 13);
+    assert_eq!(dst.get_range(1..4), 4);
+    assert_eq!(dst.get_range(3..=5), 5);
+    assert_eq!(dst.get_range(6..), 2);
+    assert_eq!(dst.get_range(2..2), 0);
+    assert_eq!(format!("{dst:?}"), "[3, -1, 4, 1, -5, 9, 2]");
+}
+
+#[test]
+fn test_str() {
+    let dst: DisjointSparseTable<Str> = "abcdefghij".chars().map(|c| c.to_string()).collect();
+
+    assert_eq!(dst.get_range(..), "abcdefghij");
+    assert_eq!(dst.get_range(2..7), "cdefg");
+    assert_eq!(dst.get_range(3..4), "d");
+    assert_eq!(dst.get_range(9..9), "");
+}
+
+#[test]
+fn test_idempotent() {
+    let dst = DisjointSparseTable::<Min>::from_vec(vec![5, 3, 8, 1, 9, 2]);
+
+    assert_eq!(dst.get_range(0..3), 3);
+    assert_eq!(dst.get_range(4..), 2);
+}
+
+#[test]
+fn test_empty() {
+    let dst = DisjointSparseTable::<Add>::from_vec(vec![]);
+
+    assert!(dst.is_empty());
+    assert_eq!(dst.get_range(..), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_range() {
+    let dst = DisjointSparseTable::<Add>::from_vec(vec![1, 2, 3]);
+    dst.get_range(2..=3);
+}
+
+/// 遅延セグ木との比較
+#[test]
+fn test_random_add() {
+    let mut rng = rand::thread_rng();
+
+    for n in [1, 2, 7, 16, 33, 100] {
+        let src: Vec<isize> = (0..n).map(|_| rng.gen_range(-1000..1000)).collect();
+        let dst = DisjointSparseTable::<Add>::from_vec(src.clone());
+        let mut segtree = LazySegmentTree::<RSQandRAQ>::from_vec(src);
+
+        for _ in 0..1000 {
+            let l = rng.gen_range(0..=n);
+            let r = rng.gen_range(l..=n);
+            assert_eq!(dst.get_range(l..r), segtree.get_range(l, r));
+        }
+    }
+}
+
+/// 遅延セグ木との比較（非可換な演算）
+#[test]
+fn test_random_str() {
+    let mut rng = rand::thread_rng();
+
+    for n in [1, 3, 8, 20, 45] {
+        let src: Vec<String> = (0..n)
+            .map(|_| ((b'a' + rng.gen_range(0..26)) as char).to_string())
+            .collect();
+        let dst = DisjointSparseTable::<Str>::from_vec(src.clone());
+        let mut segtree = LazySegmentTree::<Concat>::from_vec(src);
+
+        for _ in 0..1000 {
+            let l = rng.gen_range(0..=n);
+            let r = rng.gen_range(l..=n);
+            assert_eq!(dst.get_range(l..r), segtree.get_range(l, r));
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use lazy_segment_tree::{lazy_segment_tree::LazySegmentTree, structures::RMQandRUQ};
+use rand::Rng;
+use sparse_table::{
+    alg::{
+        monoids::{Gcd, Max, Min},
+        Monoid,
+    },
+    sparse_table::SparseTable,
+};
+
+#[test]
+fn test_min() {
+    let st = SparseTable::<Min>::from_vec(vec![5, 3, 8, 1, 9, 2]);
+
+    assert_eq!(st.len(), 6);
+    assert_eq!(st.get(2), 8);
+    assert_eq!(st.get_range(..), 1);
+    assert_eq!(st.get_range(0..3), 3);
+    assert_eq!(st.get_range(4..=5), 2);
+    assert_eq!(st.get_range(2..2), isize::MAX);
+    assert_eq!(format!("{st:?}"), "[5, 3, 8, 1, 9, 2]");
+}
+
+#[test]
+fn test_gcd() {
+    let st: SparseTable<Gcd> = [12, 18, 30, 7, 14].into_iter().collect();
+
+    assert_eq!(st.get_range(0..3), 6);
+    assert_eq!(st.get_range(1..2), 18);
+    assert_eq!(st.get_range(3..), 7);
+    assert_eq!(st.get_range(..), 1);
+}
+
+#[test]
+fn test_empty() {
+    let st = SparseTable::<Max>::from_vec(vec![]);
+
+    assert!(st.is_empty());
+    assert_eq!(st.get_range(..), isize::MIN);
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_range() {
+    let st = SparseTable::<Min>::from_vec(vec![1, 2, 3]);
+    st.get_range(1..4);
+}
+
+/// 遅延セグ木との比較
+#[test]
+fn test_random_min() {
+    let mut rng = rand::thread_rng();
+
+    for n in [1, 2, 7, 16, 33, 100] {
+        let src: Vec<isize> = (0..n).map(|_| rng.gen_range(-1000..1000)).collect();
+        let st = SparseTable::<Min>::from_vec(src.clone());
+        let mut segtree = LazySegmentTree::<RMQandRUQ>::from_vec(src);
+
+        for _ in 0..1000 {
+            let l = rng.gen_range(0..n);
+            let r = rng.gen_range(l + 1..=n);
+            assert_eq!(st.get_range(l..r), segtree.get_range(l, r));
+        }
+    }
+}
+
+/// 愚直な実装との比較
+#[test]
+fn test_random_max_gcd() {
+    let mut rng = rand::thread_rng();
+
+    for n in [1, 5, 32, 77] {
+        let src: Vec<isize> = (0..n).map(|_| rng.gen_range(-1000..1000)).collect();
+        let max = SparseTable::<Max>::from_vec(src.clone());
+        let gcd: SparseTable<Gcd> = src.iter().map(|x| x.unsigned_abs()).collect();
+
+        for _ in 0..1000 {
+            let l = rng.gen_range(0..=n);
+            let r = rng.gen_range(l..=n);
+            let expected_max = src[l..r].iter().fold(Max::E, |acc, x| Max::op(&acc, x));
+            let expected_gcd = src[l..r]
+                .iter()
+                .fold(Gcd::E, |acc, x| Gcd::op(&acc, &x.unsigned_abs()));
+            assert_eq!(max.get_range(l..r), expected_max);
+            assert_eq!(gcd.get_range(l..r), expected_gcd);
+        }
+    }
+}
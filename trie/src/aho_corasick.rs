@@ -0,0 +1,447 @@
+//! # Aho-Corasick
+//! - トライ木に失敗リンクと出力リンクを張った，複数パターンの同時検索
+//! - 位置は文字（`A::Symbol`）単位で数える（`Chars`なら`char`単位，`Bytes`ならバイト単位）
+
+use std::collections::VecDeque;
+
+use crate::{
+    alphabet::{Alphabet, Chars, Children},
+    trie::{Trie, TrieNode},
+};
+
+type Symbol<A> = <A as Alphabet>::Symbol;
+
+/// 見つかったパターン：(パターンの番号, 開始位置, 終了位置)
+/// - 半開区間 `[start, end)`
+pub type Match = (usize, usize, usize);
+
+/// マッチの方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchKind {
+    /// 重なりを許してすべての出現を報告する（終了位置の昇順，同じ終了位置では長い順）
+    Overlapping,
+    /// 重なりのない出現を左から報告する
+    /// - 同じ位置から始まる出現が複数あるときは，最も長いものを選ぶ
+    /// - 出現を確定するたびにその終了位置から走査し直すため，
+    ///   パターンの最大の長さを$L$として，最悪計算量は$O(nL)$となる
+    LeftmostLongest,
+}
+
+struct State<A: Alphabet> {
+    goto: A::Children<usize>,
+    /// 失敗リンク：この状態の真の接尾辞のうち，最長のものに対応する状態
+    fail: usize,
+    /// 出力リンク：失敗リンクをたどって最初に到達する，パターンの終端である状態
+    output: Option<usize>,
+    /// この状態で終わるパターンの番号
+    pattern: Option<usize>,
+    /// 根からの深さ
+    depth: usize,
+}
+
+/// Aho-Corasick法のオートマトン
+pub struct AhoCorasick<A: Alphabet = Chars> {
+    states: Vec<State<A>>,
+    kind: MatchKind,
+    /// パターンの最大の長さ
+    max_len: usize,
+}
+
+impl<A: Alphabet> AhoCorasick<A> {
+    /// パターンの列からオートマトンを構築する
+    /// - パターンの番号は`patterns`における添字
+    /// - 同じパターンが複数あるときは，最初のものの番号を用いる
+    /// - 空のパターンを含むときはパニックする
+    pub fn new<'p>(patterns: impl IntoIterator<Item = &'p A::Key>, kind: MatchKind) -> Self
+    where
+        A::Key: 'p,
+    {
+        let mut trie: Trie<usize, A> = Trie::new();
+        for (i, pattern) in patterns.into_iter().enumerate() {
+            if trie.get(pattern).is_none() {
+                trie.insert(pattern, i);
+            }
+        }
+        Self::from_trie(&trie, kind)
+    }
+
+    /// キーをパターン，値をパターンの番号とするトライ木からオートマトンを構築する
+    /// - 空のパターンを含むときはパニックする
+    pub fn from_trie(trie: &Trie<usize, A>, kind: MatchKind) -> Self {
+        assert!(trie.root.data.is_none(), "patterns must not be empty");
+
+        // 幅優先探索でトライ木のノードに番号を付ける
+        let mut states = vec![State::<A> {
+            goto: Default::default(),
+            fail: 0,
+            output: None,
+            pattern: None,
+            depth: 0,
+        }];
+        let mut queue: VecDeque<(usize, &TrieNode<usize, A>)> = VecDeque::from([(0, &trie.root)]);
+        while let Some((u, node)) = queue.pop_front() {
+            for (c, child) in node.children.iter() {
+                let v = states.len();
+                // 親の失敗リンクをたどり，`c`で遷移できる状態を探す
+                let fail = if u == 0 {
+                    0
+                } else {
+                    let mut f = states[u].fail;
+                    loop {
+                        if let Some(&next) = states[f].goto.get(c) {
+                            break next;
+                        }
+                        if f == 0 {
+                            break 0;
+                        }
+                        f = states[f].fail;
+                    }
+                };
+                let output = if states[fail].pattern.is_some() {
+                    Some(fail)
+                } else {
+                    states[fail].output
+                };
+                states.push(State {
+                    goto: Default::default(),
+                    fail,
+                    output,
+                    pattern: child.data,
+                    depth: states[u].depth + 1,
+                });
+                states[u].goto.get_or_insert_with(c, || v);
+                queue.push_back((v, child));
+            }
+        }
+
+        let max_len = states.iter().map(|s| s.depth).max().unwrap_or(0);
+        Self {
+            states,
+            kind,
+            max_len,
+        }
+    }
+
+    /// マッチの方式
+    pub fn match_kind(&self) -> MatchKind {
+        self.kind
+    }
+
+    /// 状態`state`から文字`c`で遷移する
+    fn next_state(&self, mut state: usize, c: Symbol<A>) -> usize {
+        loop {
+            if let Some(&next) = self.states[state].goto.get(c) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.states[state].fail;
+        }
+    }
+
+    /// 状態`state`で終わるパターンの状態のうち，最も長いもの
+    fn longest_output(&self, state: usize) -> Option<usize> {
+        if self.states[state].pattern.is_some() {
+            Some(state)
+        } else {
+            self.states[state].output
+        }
+    }
+
+    /// `text`に含まれるパターンを列挙する
+    pub fn find_iter<'a>(
+        &'a self,
+        text: &'a A::Key,
+    ) -> FindIter<'a, A, impl Iterator<Item = Symbol<A>> + 'a> {
+        FindIter {
+            symbols: A::symbols(text),
+            searcher: Searcher::new(self),
+            pending: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// 複数の断片に分けて与えられるテキストを検索する
+    pub fn stream(&self) -> Stream<'_, A> {
+        Stream {
+            searcher: Searcher::new(self),
+        }
+    }
+}
+
+// ----- searcher -----
+/// 文字を1つずつ受け取り，見つかったパターンを報告する
+struct Searcher<'a, A: Alphabet> {
+    ac: &'a AhoCorasick<A>,
+    state: usize,
+    /// これまでに受け取った文字数
+    pos: usize,
+    /// 最左最長：まだ確定していない候補
+    best: Option<Match>,
+    /// 最左最長：再走査のために保持する直近の文字（位置`pos - buf.len()`から`pos`まで）
+    buf: VecDeque<Symbol<A>>,
+}
+
+impl<'a, A: Alphabet> Searcher<'a, A> {
+    fn new(ac: &'a AhoCorasick<A>) -> Self {
+        Self {
+            ac,
+            state: 0,
+            pos: 0,
+            best: None,
+            buf: VecDeque::new(),
+        }
+    }
+
+    /// 文字`c`を受け取る
+    fn push(&mut self, c: Symbol<A>, out: &mut VecDeque<Match>) {
+        match self.ac.kind {
+            MatchKind::Overlapping => self.push_overlapping(c, out),
+            MatchKind::LeftmostLongest => self.push_leftmost_longest(c, out),
+        }
+    }
+
+    fn push_overlapping(&mut self, c: Symbol<A>, out: &mut VecDeque<Match>) {
+        self.state = self.ac.next_state(self.state, c);
+        self.pos += 1;
+        // 出力リンクをたどって，ここで終わるパターンをすべて報告する
+        let mut s = self.ac.longest_output(self.state);
+        while let Some(t) = s {
+            let state = &self.ac.states[t];
+            out.push_back((state.pattern.unwrap(), self.pos - state.depth, self.pos));
+            s = state.output;
+        }
+    }
+
+    fn push_leftmost_longest(&mut self, c: Symbol<A>, out: &mut VecDeque<Match>) {
+        self.buf.push_back(c);
+        if self.buf.len() > self.ac.max_len {
+            self.buf.pop_front();
+        }
+        self.state = self.ac.next_state(self.state, c);
+        self.pos += 1;
+
+        // ここで終わるパターンのうち，最も長いもの（最も左から始まるもの）を候補とする
+        if let Some(t) = self.ac.longest_output(self.state) {
+            let state = &self.ac.states[t];
+            let candidate = (state.pattern.unwrap(), self.pos - state.depth, self.pos);
+            let is_better = self.best.is_none_or(|(_, start, end)| {
+                candidate.1 < start || (candidate.1 == start && candidate.2 > end)
+            });
+            if is_better {
+                self.best = Some(candidate);
+            }
+        }
+
+        // 今後のパターンはすべて候補より右から始まるとき，候補を確定する
+        let earliest_start = self.pos - self.ac.states[self.state].depth;
+        if self
+            .best
+            .is_some_and(|(_, start, _)| earliest_start > start)
+        {
+            self.emit_best(out);
+        }
+    }
+
+    /// 候補を確定し，その終了位置から走査し直す
+    fn emit_best(&mut self, out: &mut VecDeque<Match>) {
+        let Some(best) = self.best.take() else {
+            return;
+        };
+        out.push_back(best);
+        let mut rest = vec![];
+        while self.pos > best.2 {
+            rest.push(self.buf.pop_back().unwrap());
+            self.pos -= 1;
+        }
+        self.state = 0;
+        for c in rest.into_iter().rev() {
+            self.push_leftmost_longest(c, out);
+        }
+    }
+
+    /// テキストの終端で，確定していない候補を報告する
+    fn finish(&mut self, out: &mut VecDeque<Match>) {
+        while self.best.is_some() {
+            self.emit_best(out);
+        }
+    }
+}
+
+// ----- iterator -----
+/// テキストに含まれるパターンを返すイテレータ
+pub struct FindIter<'a, A: Alphabet, I: Iterator<Item = Symbol<A>>> {
+    symbols: I,
+    searcher: Searcher<'a, A>,
+    pending: VecDeque<Match>,
+    finished: bool,
+}
+
+impl<A: Alphabet, I: Iterator<Item = Symbol<A>>> Iterator for FindIter<'_, A, I> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(m) = self.pending.pop_front() {
+                return Some(m);
+            }
+            if self.finished {
+                return None;
+            }
+            match self.symbols.next() {
+                Some(c) => self.searcher.push(c, &mut self.pending),
+                None => {
+                    self.searcher.finish(&mut self.pending);
+                    self.finished = true;
+                }
+            }
+        }
+    }
+}
+
+// ----- stream -----
+/// 断片に分けて与えられるテキストの検索
+/// - 断片の境界をまたぐパターンも検出する
+/// - 位置はテキスト全体の先頭から数える
+pub struct Stream<'a, A: Alphabet> {
+    searcher: Searcher<'a, A>,
+}
+
+impl<A: Alphabet> Stream<'_, A> {
+    /// 断片`chunk`を与え，新たに確定したパターンを返す
+    /// - 最左最長の場合，より長いパターンの可能性が残る候補は次以降の断片で報告する
+    pub fn feed(&mut self, chunk: &A::Key) -> Vec<Match> {
+        let mut out = VecDeque::new();
+        for c in A::symbols(chunk) {
+            self.searcher.push(c, &mut out);
+        }
+        out.into()
+    }
+
+    /// テキストの終端を通知し，残りのパターンを返す
+    pub fn finish(mut self) -> Vec<Match> {
+        let mut out = VecDeque::new();
+        self.searcher.finish(&mut out);
+        out.into()
+    }
+
+    /// これまでに与えられた文字数
+    pub fn position(&self) -> usize {
+        self.searcher.pos
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AhoCorasick, Match, MatchKind};
+    use crate::alphabet::{Bytes, Chars};
+    use rand::Rng;
+
+    /// 愚直な解法
+    fn naive(patterns: &[&str], text: &str, kind: MatchKind) -> Vec<Match> {
+        let text: Vec<char> = text.chars().collect();
+        let patterns: Vec<Vec<char>> = patterns.iter().map(|p| p.chars().collect()).collect();
+        let id = |p: &Vec<char>| patterns.iter().position(|q| q == p).unwrap();
+        let mut res = vec![];
+        match kind {
+            MatchKind::Overlapping => {
+                for end in 1..=text.len() {
+                    let mut found: Vec<_> = patterns
+                        .iter()
+                        .filter(|p| text[..end].ends_with(p))
+                        .map(|p| (id(p), end - p.len(), end))
+                        .collect();
+                    found.sort_by_key(|&(_, start, _)| start);
+                    found.dedup();
+                    res.extend(found);
+                }
+            }
+            MatchKind::LeftmostLongest => {
+                let mut start = 0;
+                while start < text.len() {
+                    let longest = patterns
+                        .iter()
+                        .filter(|p| text[start..].starts_with(p))
+                        .max_by_key(|p| p.len());
+                    match longest {
+                        Some(p) => {
+                            res.push((id(p), start, start + p.len()));
+                            start += p.len();
+                        }
+                        None => start += 1,
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let patterns = ["he", "she", "his", "hers"];
+        let ac = AhoCorasick::<Chars>::new(patterns, MatchKind::Overlapping);
+
+        let found: Vec<_> = ac.find_iter("ushers").collect();
+        assert_eq!(found, [(1, 1, 4), (0, 2, 4), (3, 2, 6)]);
+        assert_eq!(found, naive(&patterns, "ushers", MatchKind::Overlapping));
+    }
+
+    #[test]
+    fn test_leftmost_longest() {
+        let patterns = ["abcd", "bcdef", "b", "abcdefg"];
+        let ac = AhoCorasick::<Chars>::new(patterns, MatchKind::LeftmostLongest);
+
+        assert_eq!(ac.find_iter("xabcdefgh").collect::<Vec<_>>(), [(3, 1, 8)]);
+        assert_eq!(
+            ac.find_iter("abcdefbcdefb").collect::<Vec<_>>(),
+            [(0, 0, 4), (1, 6, 11), (2, 11, 12)]
+        );
+        assert_eq!(
+            ac.find_iter("abcdefbcdefb").collect::<Vec<_>>(),
+            naive(&patterns, "abcdefbcdefb", MatchKind::LeftmostLongest)
+        );
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+        let mut random_string =
+            |len: usize| -> String { (0..len).map(|_| rng.gen_range('a'..='c')).collect() };
+
+        for _ in 0..200 {
+            let patterns: Vec<String> = (0..5).map(|i| random_string(1 + i % 4)).collect();
+            let patterns: Vec<&str> = patterns.iter().map(|p| p.as_str()).collect();
+            let text = random_string(30);
+
+            for kind in [MatchKind::Overlapping, MatchKind::LeftmostLongest] {
+                let ac = AhoCorasick::<Chars>::new(patterns.iter().copied(), kind);
+                let found: Vec<_> = ac.find_iter(text.as_str()).collect();
+                let expected = naive(&patterns, &text, kind);
+                assert_eq!(found, expected, "{patterns:?} {text}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream() {
+        let patterns: [&[u8]; 3] = [b"error", b"err", b"warning"];
+        let text = b"ok error warn warning errors";
+
+        for kind in [MatchKind::Overlapping, MatchKind::LeftmostLongest] {
+            let ac = AhoCorasick::<Bytes>::new(patterns, kind);
+            let expected: Vec<_> = ac.find_iter(text).collect();
+
+            // 断片の境界がパターンをまたぐように分割する
+            for chunk_size in 1..=text.len() {
+                let mut stream = ac.stream();
+                let mut found = vec![];
+                for chunk in text.chunks(chunk_size) {
+                    found.extend(stream.feed(chunk));
+                }
+                found.extend(stream.finish());
+                assert_eq!(found, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! トライ木のキーを構成する文字の種類と，子ノードの持ち方
+
+use std::{fmt::Debug, marker::PhantomData};
+
+/// キーを構成する文字の種類
+pub trait Alphabet {
+    /// 文字の型
+    type Symbol: Copy + Ord + Debug;
+    /// キーの型
+    type Key: ?Sized;
+    /// 所有権を持つキーの型
+    type Owned;
+    /// 子ノードの持ち方
+    type Children<N>: Children<Self::Symbol, N>;
+
+    /// キーを文字の列に分解する
+    fn symbols(key: &Self::Key) -> impl Iterator<Item = Self::Symbol> + '_;
+
+    /// 文字の列からキーを復元する
+    fn collect(symbols: &[Self::Symbol]) -> Self::Owned;
+}
+
+/// 文字から子ノードへの対応
+/// - `iter`は文字の昇順に子ノードを返す
+pub trait Children<S, N>: Default {
+    /// 文字`symbol`に対応する子ノード
+    fn get(&self, symbol: S) -> Option<&N>;
+
+    /// 文字`symbol`に対応する子ノードの可変参照
+    fn get_mut(&mut self, symbol: S) -> Option<&mut N>;
+
+    /// 文字`symbol`に対応する子ノードの可変参照（存在しない場合は`f`で作成する）
+    fn get_or_insert_with(&mut self, symbol: S, f: impl FnOnce() -> N) -> &mut N;
+
+    /// 文字`symbol`に対応する子ノードを削除する
+    fn remove(&mut self, symbol: S) -> Option<N>;
+
+    /// 子ノードを文字の昇順に列挙する
+    fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = (S, &'a N)>
+    where
+        N: 'a;
+
+    /// 子ノードが存在しないか判定する
+    fn is_empty(&self) -> bool;
+}
+
+// ----- 疎な子ノード -----
+/// 文字でソートされた配列で子ノードを持つ
+/// - 文字の種類が多い場合（`char`，バイト列など）に用いる
+pub struct Sparse<S, N> {
+    children: Vec<(S, N)>,
+}
+
+impl<S, N> Default for Sparse<S, N> {
+    fn default() -> Self {
+        Self { children: vec![] }
+    }
+}
+
+impl<S: Ord + Copy, N> Children<S, N> for Sparse<S, N> {
+    fn get(&self, symbol: S) -> Option<&N> {
+        let i = self.children.binary_search_by_key(&symbol, |c| c.0).ok()?;
+        Some(&self.children[i].1)
+    }
+
+    fn get_mut(&mut self, symbol: S) -> Option<&mut N> {
+        let i = self.children.binary_search_by_key(&symbol, |c| c.0).ok()?;
+        Some(&mut self.children[i].1)
+    }
+
+    fn get_or_insert_with(&mut self, symbol: S, f: impl FnOnce() -> N) -> &mut N {
+        let i = match self.children.binary_search_by_key(&symbol, |c| c.0) {
+            Ok(i) => i,
+            Err(i) => {
+                self.children.insert(i, (symbol, f()));
+                i
+            }
+        };
+        &mut self.children[i].1
+    }
+
+    fn remove(&mut self, symbol: S) -> Option<N> {
+        let i = self.children.binary_search_by_key(&symbol, |c| c.0).ok()?;
+        Some(self.children.remove(i).1)
+    }
+
+    fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = (S, &'a N)>
+    where
+        N: 'a,
+    {
+        self.children.iter().map(|(s, n)| (*s, n))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+// ----- 密な子ノード -----
+/// 文字と`0..SIZE`の整数の1対1対応
+pub trait DenseAlphabet {
+    /// 文字の型
+    type Symbol: Copy + Ord + Debug;
+    /// 文字の種類数
+    const SIZE: usize;
+
+    /// 何番目の文字かを返す（アルファベットに含まれない場合は`None`）
+    fn index(symbol: Self::Symbol) -> Option<usize>;
+
+    /// `i`番目の文字を返す
+    fn symbol(i: usize) -> Self::Symbol;
+}
+
+/// 長さ`A::SIZE`の配列で子ノードを持つ
+/// - 文字の種類が少ない場合に用いる
+/// - 配列は最初の子ノードを追加したときに確保する
+pub struct Dense<A: DenseAlphabet, N> {
+    children: Vec<Option<N>>,
+    size: usize,
+    alphabet: PhantomData<A>,
+}
+
+impl<A: DenseAlphabet, N> Default for Dense<A, N> {
+    fn default() -> Self {
+        Self {
+            children: vec![],
+            size: 0,
+            alphabet: PhantomData,
+        }
+    }
+}
+
+impl<A: DenseAlphabet, N> Children<A::Symbol, N> for Dense<A, N> {
+    fn get(&self, symbol: A::Symbol) -> Option<&N> {
+        self.children.get(A::index(symbol)?)?.as_ref()
+    }
+
+    fn get_mut(&mut self, symbol: A::Symbol) -> Option<&mut N> {
+        self.children.get_mut(A::index(symbol)?)?.as_mut()
+    }
+
+    /// - 文字がアルファベットに含まれない場合はパニックする
+    fn get_or_insert_with(&mut self, symbol: A::Symbol, f: impl FnOnce() -> N) -> &mut N {
+        let Some(i) = A::index(symbol) else {
+            panic!("{symbol:?} is not in the alphabet");
+        };
+        if self.children.is_empty() {
+            self.children.resize_with(A::SIZE, || None);
+        }
+        if self.children[i].is_none() {
+            self.size += 1;
+        }
+        self.children[i].get_or_insert_with(f)
+    }
+
+    fn remove(&mut self, symbol: A::Symbol) -> Option<N> {
+        let removed = self.children.get_mut(A::index(symbol)?)?.take()?;
+        self.size -= 1;
+        if self.size == 0 {
+            self.children = vec![];
+        }
+        Some(removed)
+    }
+
+    fn iter<'a>(&'a self) -> impl DoubleEndedIterator<Item = (A::Symbol, &'a N)>
+    where
+        N: 'a,
+    {
+        self.children
+            .iter()
+            .enumerate()
+            .filter_map(|(i, child)| Some((A::symbol(i), child.as_ref()?)))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+}
+
+// ----- 定義済みのアルファベット -----
+/// Unicodeの文字（`char`）を文字とする
+/// - キーは`str`
+pub struct Chars;
+
+impl Alphabet for Chars {
+    type Symbol = char;
+    type Key = str;
+    type Owned = String;
+    type Children<N> = Sparse<char, N>;
+
+    fn symbols(key: &str) -> impl Iterator<Item = char> + '_ {
+        key.chars()
+    }
+
+    fn collect(symbols: &[char]) -> String {
+        symbols.iter().collect()
+    }
+}
+
+/// バイトを文字とする
+/// - キーは`[u8]`
+pub struct Bytes;
+
+impl Alphabet for Bytes {
+    type Symbol = u8;
+    type Key = [u8];
+    type Owned = Vec<u8>;
+    type Children<N> = Sparse<u8, N>;
+
+    fn symbols(key: &[u8]) -> impl Iterator<Item = u8> + '_ {
+        key.iter().copied()
+    }
+
+    fn collect(symbols: &[u8]) -> Vec<u8> {
+        symbols.to_vec()
+    }
+}
+
+/// 英小文字（`'a'..='z'`）を文字とする
+/// - キーは`str`
+/// - 子ノードを長さ26の配列で持つ
+pub struct LowerCase;
+
+impl DenseAlphabet for LowerCase {
+    type Symbol = char;
+    const SIZE: usize = 26;
+
+    fn index(symbol: char) -> Option<usize> {
+        symbol
+            .is_ascii_lowercase()
+            .then(|| (symbol as u8 - b'a') as usize)
+    }
+
+    fn symbol(i: usize) -> char {
+        (b'a' + i as u8) as char
+    }
+}
+
+impl Alphabet for LowerCase {
+    type Symbol = char;
+    type Key = str;
+    type Owned = String;
+    type Children<N> = Dense<LowerCase, N>;
+
+    fn symbols(key: &str) -> impl Iterator<Item = char> + '_ {
+        key.chars()
+    }
+
+    fn collect(symbols: &[char]) -> String {
+        symbols.iter().collect()
+    }
+}
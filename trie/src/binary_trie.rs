@@ -0,0 +1,415 @@
+//! # binary trie
+//! - 整数を上位ビットから順に2分木へ格納するトライ木
+//! - 同じ値を複数個格納できる（多重集合）
+//! - すべての値に一括でxorをとる操作を遅延して行う
+//! - 値を持たなくなったノードは切り離し，その番号を以降の挿入で再利用する
+
+/// ノードの番号（0は根）
+type NodeId = usize;
+
+#[derive(Debug, Clone)]
+struct BinaryTrieNode {
+    children: [Option<NodeId>; 2],
+    /// 部分木に含まれる値の個数
+    count: usize,
+}
+
+impl BinaryTrieNode {
+    fn new() -> Self {
+        Self {
+            children: [None, None],
+            count: 0,
+        }
+    }
+}
+
+/// `BITS`ビットの非負整数を格納するトライ木
+/// - 値は`u64`で受け渡す（`BITS <= 64`）
+/// - 格納している値はすべて`mask`とのxorをとった状態で扱う
+/// - 根以外のノードは値を1つ以上含む
+#[derive(Debug, Clone)]
+pub struct BinaryTrie<const BITS: usize> {
+    nodes: Vec<BinaryTrieNode>,
+    /// 一括でxorをとる値
+    mask: u64,
+    /// 再利用できるノードの番号
+    free: Vec<NodeId>,
+}
+
+impl<const BITS: usize> BinaryTrie<BITS> {
+    pub fn new() -> Self {
+        const { assert!(0 < BITS && BITS <= 64) };
+        Self {
+            nodes: vec![BinaryTrieNode::new()],
+            mask: 0,
+            free: vec![],
+        }
+    }
+
+    /// `x`が`BITS`ビットに収まっているか確認する
+    fn check(x: u64) {
+        assert!(
+            BITS == 64 || x >> BITS == 0,
+            "{x} does not fit in {BITS} bits"
+        );
+    }
+
+    /// `x`の上から`i`番目のビット
+    fn bit(x: u64, i: usize) -> usize {
+        ((x >> (BITS - 1 - i)) & 1) as usize
+    }
+
+    /// 値の個数
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 値`x`を1つ追加する
+    pub fn insert(&mut self, x: u64) {
+        Self::check(x);
+        let key = x ^ self.mask;
+        let mut node = 0;
+        self.nodes[node].count += 1;
+        for i in 0..BITS {
+            let b = Self::bit(key, i);
+            node = match self.nodes[node].children[b] {
+                Some(child) => child,
+                None => {
+                    let child = self.alloc();
+                    self.nodes[node].children[b] = Some(child);
+                    child
+                }
+            };
+            self.nodes[node].count += 1;
+        }
+    }
+
+    /// 空のノードを作成する（切り離したノードがあれば再利用する）
+    fn alloc(&mut self) -> NodeId {
+        match self.free.pop() {
+            Some(node) => {
+                self.nodes[node] = BinaryTrieNode::new();
+                node
+            }
+            None => {
+                self.nodes.push(BinaryTrieNode::new());
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// 値`x`を1つ削除する
+    /// - `x`が存在した場合は`true`を返す
+    /// - 値を持たなくなったノードは切り離し，再利用できるようにする
+    pub fn remove(&mut self, x: u64) -> bool {
+        if self.leaf(x).is_none() {
+            return false;
+        }
+        let key = x ^ self.mask;
+        let mut node = 0;
+        self.nodes[node].count -= 1;
+        for i in 0..BITS {
+            let b = Self::bit(key, i);
+            let child = self.nodes[node].children[b].unwrap();
+            self.nodes[child].count -= 1;
+            if self.nodes[child].count == 0 {
+                // 子孫は経路上のノードのみであり，いずれも値を持たなくなる
+                self.nodes[node].children[b] = None;
+                self.release(child);
+                break;
+            }
+            node = child;
+        }
+        true
+    }
+
+    /// 値を持たなくなったノード`node`と，その子孫を再利用できるようにする
+    fn release(&mut self, mut node: NodeId) {
+        loop {
+            self.free.push(node);
+            let [left, right] = std::mem::take(&mut self.nodes[node].children);
+            match left.or(right) {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+    }
+
+    /// 値`x`の個数
+    pub fn count(&self, x: u64) -> usize {
+        self.leaf(x).map_or(0, |node| self.nodes[node].count)
+    }
+
+    /// 値`x`に対応する葉（`x`が存在しない場合は`None`）
+    fn leaf(&self, x: u64) -> Option<NodeId> {
+        if BITS < 64 && x >> BITS != 0 {
+            return None;
+        }
+        let key = x ^ self.mask;
+        let mut node = 0;
+        for i in 0..BITS {
+            node = self.child(node, Self::bit(key, i))?;
+        }
+        Some(node)
+    }
+
+    /// 値を1つ以上含む子
+    fn child(&self, node: NodeId, b: usize) -> Option<NodeId> {
+        self.nodes[node].children[b]
+    }
+
+    /// すべての値に`x`のxorをとる
+    /// - 計算量：$O(1)$
+    pub fn xor_all(&mut self, x: u64) {
+        Self::check(x);
+        self.mask ^= x;
+    }
+
+    /// 格納している値`v`のうち，`v xor x`の最小値
+    pub fn min_xor(&self, x: u64) -> Option<u64> {
+        self.xor_extreme(x, false)
+    }
+
+    /// 格納している値`v`のうち，`v xor x`の最大値
+    pub fn max_xor(&self, x: u64) -> Option<u64> {
+        self.xor_extreme(x, true)
+    }
+
+    /// `maximize`が`false`なら`v xor x`の最小値，`true`なら最大値
+    fn xor_extreme(&self, x: u64, maximize: bool) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+        Self::check(x);
+        // 格納している値とのxorが x xor mask となる
+        let target = x ^ self.mask;
+        let mut node = 0;
+        let mut res = 0;
+        for i in 0..BITS {
+            // xorの結果のビットが`maximize`となる子を優先する
+            let want = Self::bit(target, i) ^ usize::from(maximize);
+            res <<= 1;
+            node = match self.child(node, want) {
+                Some(child) => {
+                    res |= u64::from(maximize);
+                    child
+                }
+                None => {
+                    res |= u64::from(!maximize);
+                    self.child(node, want ^ 1).unwrap()
+                }
+            };
+        }
+        Some(res)
+    }
+
+    /// 最小値
+    pub fn min(&self) -> Option<u64> {
+        self.min_xor(0)
+    }
+
+    /// 最大値
+    pub fn max(&self) -> Option<u64> {
+        self.max_xor(0)
+    }
+
+    /// 小さい方から`k`番目（0-indexed）の値
+    pub fn nth(&self, mut k: usize) -> Option<u64> {
+        if k >= self.len() {
+            return None;
+        }
+        let mut node = 0;
+        let mut res = 0;
+        for i in 0..BITS {
+            // xorをとったあとのビットが0となる子
+            let zero = Self::bit(self.mask, i);
+            let left = self.child(node, zero).map_or(0, |c| self.nodes[c].count);
+            res <<= 1;
+            if k < left {
+                node = self.nodes[node].children[zero].unwrap();
+            } else {
+                k -= left;
+                res |= 1;
+                node = self.nodes[node].children[zero ^ 1].unwrap();
+            }
+        }
+        Some(res)
+    }
+
+    /// `x`未満の値の個数
+    pub fn count_less(&self, x: u64) -> usize {
+        if BITS < 64 && x >> BITS != 0 {
+            return self.len();
+        }
+        let mut node = 0;
+        let mut res = 0;
+        for i in 0..BITS {
+            let zero = Self::bit(self.mask, i);
+            let b = Self::bit(x, i);
+            if b == 1 {
+                // ビットが0である値はすべて`x`未満
+                res += self.child(node, zero).map_or(0, |c| self.nodes[c].count);
+            }
+            let Some(next) = self.child(node, zero ^ b) else {
+                return res;
+            };
+            node = next;
+        }
+        res
+    }
+}
+
+impl<const BITS: usize> Default for BinaryTrie<BITS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: usize> FromIterator<u64> for BinaryTrie<BITS> {
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        for x in iter {
+            trie.insert(x);
+        }
+        trie
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BinaryTrie;
+    use rand::Rng;
+
+    #[test]
+    fn test_multiset() {
+        let mut trie = BinaryTrie::<4>::new();
+
+        trie.insert(3);
+        trie.insert(3);
+        trie.insert(5);
+        assert_eq!(trie.len(), 3);
+        assert_eq!(trie.count(3), 2);
+        assert_eq!(trie.count(4), 0);
+        assert_eq!(trie.count(100), 0);
+
+        assert!(trie.remove(3));
+        assert_eq!(trie.count(3), 1);
+        assert!(trie.remove(3));
+        assert!(!trie.remove(3));
+        assert_eq!(trie.len(), 1);
+        assert_eq!(trie.min(), Some(5));
+    }
+
+    #[test]
+    fn test_xor() {
+        let mut trie: BinaryTrie<3> = [1, 2, 6].into_iter().collect();
+
+        assert_eq!(trie.min_xor(7), Some(1)); // 6 ^ 7
+        assert_eq!(trie.max_xor(7), Some(6)); // 1 ^ 7
+        assert_eq!(trie.min_xor(2), Some(0));
+
+        // {1, 2, 6} ^ 3 = {2, 1, 5}
+        trie.xor_all(3);
+        assert_eq!(trie.nth(0), Some(1));
+        assert_eq!(trie.nth(1), Some(2));
+        assert_eq!(trie.nth(2), Some(5));
+        assert_eq!(trie.nth(3), None);
+        assert_eq!(trie.count_less(2), 1);
+        assert_eq!(trie.count(5), 1);
+        assert_eq!(trie.count(6), 0);
+
+        trie.insert(7);
+        assert!(trie.remove(1));
+        assert_eq!(trie.max(), Some(7));
+        assert_eq!(trie.min(), Some(2));
+    }
+
+    #[test]
+    fn test_u64() {
+        let mut trie = BinaryTrie::<64>::new();
+
+        trie.insert(u64::MAX);
+        trie.insert(0);
+        assert_eq!(trie.max_xor(0), Some(u64::MAX));
+        assert_eq!(trie.min_xor(u64::MAX), Some(0));
+        assert_eq!(trie.count_less(u64::MAX), 1);
+
+        trie.xor_all(1 << 63);
+        assert_eq!(trie.nth(0), Some(u64::MAX >> 1));
+        assert_eq!(trie.nth(1), Some(1 << 63));
+    }
+
+    #[test]
+    fn test_reuse_nodes() {
+        let mut trie = BinaryTrie::<16>::new();
+
+        for round in 0..100 {
+            for x in 0..50 {
+                trie.insert(x * 1000 + round);
+            }
+            for x in 0..50 {
+                assert!(trie.remove(x * 1000 + round));
+            }
+            assert!(trie.is_empty());
+        }
+        // 削除したノードの番号は再利用される
+        assert!(trie.nodes.len() <= 50 * 16 + 1);
+        assert_eq!(trie.free.len(), trie.nodes.len() - 1);
+        assert_eq!(trie.min(), None);
+        assert_eq!(trie.count(0), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_range() {
+        let mut trie = BinaryTrie::<8>::new();
+        trie.insert(256);
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = rand::thread_rng();
+
+        let mut trie = BinaryTrie::<10>::new();
+        let mut values: Vec<u64> = vec![];
+
+        for _ in 0..5000 {
+            let x = rng.gen_range(0..1 << 10);
+            match rng.gen_range(0..6) {
+                0 | 1 => {
+                    trie.insert(x);
+                    values.push(x);
+                }
+                2 => {
+                    let expected = values.iter().position(|&v| v == x);
+                    assert_eq!(trie.remove(x), expected.is_some());
+                    if let Some(i) = expected {
+                        values.swap_remove(i);
+                    }
+                }
+                3 => {
+                    trie.xor_all(x);
+                    values.iter_mut().for_each(|v| *v ^= x);
+                }
+                4 => {
+                    assert_eq!(trie.min_xor(x), values.iter().map(|v| v ^ x).min());
+                    assert_eq!(trie.max_xor(x), values.iter().map(|v| v ^ x).max());
+                    assert_eq!(trie.count(x), values.iter().filter(|&&v| v == x).count());
+                }
+                _ => {
+                    let mut sorted = values.clone();
+                    sorted.sort();
+                    let k = rng.gen_range(0..=values.len());
+                    assert_eq!(trie.nth(k), sorted.get(k).copied());
+                    assert_eq!(trie.count_less(x), sorted.partition_point(|&v| v < x));
+                }
+            }
+            assert_eq!(trie.len(), values.len());
+        }
+    }
+}
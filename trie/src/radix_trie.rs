@@ -0,0 +1,407 @@
+//! # radix trie
+//! - 基数木（Patricia木）の実装
+//! - 子を1つしか持たず値も持たないノードをまとめ，辺にバイト列のラベルを持たせる
+//! - キーはバイト列として扱う（`&str`も`&[u8]`もキーにできる）
+
+use std::fmt::{self, Debug};
+
+struct RadixNode<V> {
+    data: Option<V>,
+    /// 部分木に含まれるキーの数
+    count: usize,
+    /// 子への辺（ラベルの先頭のバイトでソートされている）
+    children: Vec<(Box<[u8]>, RadixNode<V>)>,
+}
+
+impl<V> RadixNode<V> {
+    fn new(data: Option<V>) -> Self {
+        Self {
+            count: usize::from(data.is_some()),
+            data,
+            children: vec![],
+        }
+    }
+
+    /// 先頭のバイトが`head`である辺の位置
+    fn search(&self, head: u8) -> Result<usize, usize> {
+        self.children
+            .binary_search_by_key(&head, |(label, _)| label[0])
+    }
+
+    /// 先頭のバイトが`head`である辺
+    fn edge(&self, head: u8) -> Option<&(Box<[u8]>, RadixNode<V>)> {
+        let i = self.search(head).ok()?;
+        Some(&self.children[i])
+    }
+}
+
+/// 2つのバイト列の共通接頭辞の長さ
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+pub struct RadixTrie<V> {
+    root: RadixNode<V>,
+}
+
+impl<V> RadixTrie<V> {
+    pub fn new() -> Self {
+        Self {
+            root: RadixNode::new(None),
+        }
+    }
+
+    /// キーの数
+    pub fn len(&self) -> usize {
+        self.root.count
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.root.count == 0
+    }
+
+    /// キー`key`に値`data`を挿入する
+    /// - すでに値が存在する場合は更新し，もとの値を返す
+    pub fn insert(&mut self, key: &(impl AsRef<[u8]> + ?Sized), data: V) -> Option<V> {
+        insert_inner(&mut self.root, key.as_ref(), data)
+    }
+
+    pub fn get(&self, key: &(impl AsRef<[u8]> + ?Sized)) -> Option<&V> {
+        let mut node = &self.root;
+        let mut key = key.as_ref();
+        while let Some(&head) = key.first() {
+            let (label, child) = node.edge(head)?;
+            key = key.strip_prefix(&label[..])?;
+            node = child;
+        }
+        node.data.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: &(impl AsRef<[u8]> + ?Sized)) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        let mut key = key.as_ref();
+        while let Some(&head) = key.first() {
+            let i = node.search(head).ok()?;
+            let (label, child) = &mut node.children[i];
+            key = key.strip_prefix(&label[..])?;
+            node = child;
+        }
+        node.data.as_mut()
+    }
+
+    /// キー`key`を削除し，対応する値を返す
+    /// - 値を持たなくなった辺は削除し，1本道になった辺は連結する
+    pub fn remove(&mut self, key: &(impl AsRef<[u8]> + ?Sized)) -> Option<V> {
+        remove_inner(&mut self.root, key.as_ref())
+    }
+
+    /// キーを昇順に列挙する
+    pub fn iter(&self) -> RadixPrefixIter<'_, V> {
+        RadixPrefixIter::new(Some(&self.root), vec![])
+    }
+
+    /// `prefix`で始まるキーを昇順に列挙する
+    pub fn iter_prefix(&self, prefix: &(impl AsRef<[u8]> + ?Sized)) -> RadixPrefixIter<'_, V> {
+        match self.find_prefix(prefix.as_ref()) {
+            Some((node, key)) => RadixPrefixIter::new(Some(node), key),
+            None => RadixPrefixIter::new(None, vec![]),
+        }
+    }
+
+    /// `prefix`で始まるキーの数
+    /// - 計算量：$O(|prefix|)$
+    pub fn count_prefix(&self, prefix: &(impl AsRef<[u8]> + ?Sized)) -> usize {
+        self.find_prefix(prefix.as_ref())
+            .map_or(0, |(node, _)| node.count)
+    }
+
+    /// `text`の接頭辞であるキーのうち，最長のものと対応する値を返す
+    pub fn longest_prefix_of(&self, text: &(impl AsRef<[u8]> + ?Sized)) -> Option<(Vec<u8>, &V)> {
+        let text = text.as_ref();
+        let mut node = &self.root;
+        let mut len = 0;
+        let mut longest = node.data.as_ref().map(|data| (0, data));
+        while let Some(&head) = text.get(len) {
+            let Some((label, child)) = node.edge(head) else {
+                break;
+            };
+            if !text[len..].starts_with(label) {
+                break;
+            }
+            len += label.len();
+            node = child;
+            if let Some(data) = node.data.as_ref() {
+                longest = Some((len, data));
+            }
+        }
+        longest.map(|(len, data)| (text[..len].to_vec(), data))
+    }
+
+    pub fn traverse(&self) -> Vec<(Vec<u8>, &V)> {
+        self.iter().collect()
+    }
+
+    /// `prefix`で始まるキーをすべて含む最小の部分木と，その根に対応するキー
+    fn find_prefix(&self, prefix: &[u8]) -> Option<(&RadixNode<V>, Vec<u8>)> {
+        let mut node = &self.root;
+        let mut key = vec![];
+        let mut rest = prefix;
+        while let Some(&head) = rest.first() {
+            let (label, child) = node.edge(head)?;
+            key.extend_from_slice(label);
+            if let Some(r) = rest.strip_prefix(&label[..]) {
+                rest = r;
+            } else if label.starts_with(rest) {
+                // 辺の途中で接頭辞が終わる
+                rest = &[];
+            } else {
+                return None;
+            }
+            node = child;
+        }
+        Some((node, key))
+    }
+}
+
+fn insert_inner<V>(node: &mut RadixNode<V>, key: &[u8], data: V) -> Option<V> {
+    let Some(&head) = key.first() else {
+        let old = node.data.replace(data);
+        if old.is_none() {
+            node.count += 1;
+        }
+        return old;
+    };
+    let i = match node.search(head) {
+        Ok(i) => i,
+        Err(i) => {
+            // 新たな辺を追加
+            node.children
+                .insert(i, (key.into(), RadixNode::new(Some(data))));
+            node.count += 1;
+            return None;
+        }
+    };
+    let (label, child) = &mut node.children[i];
+    let l = common_prefix_len(label, key);
+    if l < label.len() {
+        // 辺を共通接頭辞の位置で分割
+        let mut mid = RadixNode::new(None);
+        mid.count = child.count;
+        let old_child = std::mem::replace(child, mid);
+        child.children.push((label[l..].into(), old_child));
+        *label = label[..l].into();
+    }
+    let old = insert_inner(child, &key[l..], data);
+    if old.is_none() {
+        node.count += 1;
+    }
+    old
+}
+
+fn remove_inner<V>(node: &mut RadixNode<V>, key: &[u8]) -> Option<V> {
+    let Some(&head) = key.first() else {
+        let data = node.data.take()?;
+        node.count -= 1;
+        return Some(data);
+    };
+    let i = node.search(head).ok()?;
+    let (label, child) = &mut node.children[i];
+    let rest = key.strip_prefix(&label[..])?;
+    let data = remove_inner(child, rest)?;
+    node.count -= 1;
+    if child.count == 0 {
+        // 値を持たなくなった辺を削除
+        node.children.remove(i);
+    } else if child.data.is_none() && child.children.len() == 1 {
+        // 1本道になった辺を連結
+        let (grand_label, grand_child) = child.children.pop().unwrap();
+        *label = [&label[..], &grand_label[..]].concat().into();
+        *child = grand_child;
+    }
+    Some(data)
+}
+
+impl<V> Default for RadixTrie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Debug> Debug for RadixTrie<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.iter()
+                    .map(|(key, data)| (String::from_utf8_lossy(&key).into_owned(), data)),
+            )
+            .finish()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a RadixTrie<V> {
+    type IntoIter = RadixPrefixIter<'a, V>;
+    type Item = (Vec<u8>, &'a V);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// ----- iterator -----
+/// (親までのキーの長さ, 親からの辺のラベル, ノード)
+type Unvisited<'a, V> = (usize, &'a [u8], &'a RadixNode<V>);
+
+/// キーを昇順に返すイテレータ
+pub struct RadixPrefixIter<'a, V> {
+    unvisited: Vec<Unvisited<'a, V>>,
+    key: Vec<u8>,
+}
+
+impl<'a, V> RadixPrefixIter<'a, V> {
+    /// キー`key`に対応するノード`node`以下を探索するイテレータ
+    fn new(node: Option<&'a RadixNode<V>>, key: Vec<u8>) -> Self {
+        Self {
+            unvisited: node
+                .map(|node| (key.len(), &[][..], node))
+                .into_iter()
+                .collect(),
+            key,
+        }
+    }
+}
+
+impl<'a, V> Iterator for RadixPrefixIter<'a, V> {
+    type Item = (Vec<u8>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((len, label, node)) = self.unvisited.pop() {
+            self.key.truncate(len);
+            self.key.extend_from_slice(label);
+            // ラベルの小さい子から取り出すため，逆順に積む
+            let len = self.key.len();
+            self.unvisited.extend(
+                node.children
+                    .iter()
+                    .rev()
+                    .map(|(label, child)| (len, &label[..], child)),
+            );
+            if let Some(data) = node.data.as_ref() {
+                return Some((self.key.clone(), data));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RadixTrie;
+
+    /// 値を持たないノードは2つ以上の子を持つ
+    fn check_compressed<V>(trie: &RadixTrie<V>) {
+        let mut stack: Vec<_> = trie.root.children.iter().map(|(_, c)| c).collect();
+        while let Some(node) = stack.pop() {
+            assert!(node.data.is_some() || node.children.len() >= 2);
+            let count: usize = node.children.iter().map(|(_, c)| c.count).sum();
+            assert_eq!(node.count, count + usize::from(node.data.is_some()));
+            stack.extend(node.children.iter().map(|(_, c)| c));
+        }
+    }
+
+    #[test]
+    fn test_insert_get() {
+        let mut trie = RadixTrie::new();
+
+        assert_eq!(trie.insert("romane", 0), None);
+        assert_eq!(trie.insert("romanus", 1), None);
+        assert_eq!(trie.insert("romulus", 2), None);
+        assert_eq!(trie.insert("rubens", 3), None);
+        assert_eq!(trie.insert("ruber", 4), None);
+        assert_eq!(trie.insert("rubicon", 5), None);
+        assert_eq!(trie.insert("rubicundus", 6), None);
+        assert_eq!(trie.insert("rom", 7), None);
+        assert_eq!(trie.insert("ruber", 40), Some(4));
+        check_compressed(&trie);
+
+        assert_eq!(trie.len(), 8);
+        assert_eq!(trie.get("romanus"), Some(&1));
+        assert_eq!(trie.get("rom"), Some(&7));
+        assert_eq!(trie.get("ruber"), Some(&40));
+        assert_eq!(trie.get("roman"), None);
+        assert_eq!(trie.get("r"), None);
+        assert_eq!(trie.get("rubiconx"), None);
+        assert_eq!(trie.get(b"rubicon"), Some(&5));
+
+        *trie.get_mut("rom").unwrap() += 100;
+        assert_eq!(trie.get("rom"), Some(&107));
+
+        // ルートの直下は "r" の1本のみ
+        assert_eq!(trie.root.children.len(), 1);
+        assert_eq!(&trie.root.children[0].0[..], b"r");
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut trie = RadixTrie::new();
+        for (i, key) in ["test", "team", "toast", "te", ""].iter().enumerate() {
+            trie.insert(*key, i);
+        }
+
+        assert_eq!(trie.remove("tea"), None);
+        assert_eq!(trie.remove("te"), Some(3));
+        check_compressed(&trie);
+        assert_eq!(trie.remove("test"), Some(0));
+        check_compressed(&trie);
+        // "team" は1本の辺に連結される
+        assert_eq!(trie.get("team"), Some(&1));
+        assert_eq!(trie.root.children[0].1.children.len(), 2);
+
+        assert_eq!(trie.remove(""), Some(4));
+        assert_eq!(trie.remove("team"), Some(1));
+        check_compressed(&trie);
+        assert_eq!(&trie.root.children[0].0[..], b"toast");
+        assert_eq!(trie.remove("toast"), Some(2));
+        assert!(trie.is_empty());
+        assert!(trie.root.children.is_empty());
+    }
+
+    #[test]
+    fn test_prefix() {
+        let mut trie = RadixTrie::new();
+        for (i, key) in [
+            "/usr/bin",
+            "/usr/local/bin",
+            "/usr/local/lib",
+            "/usr",
+            "/var/log",
+            "/",
+        ]
+        .iter()
+        .enumerate()
+        {
+            trie.insert(*key, i);
+        }
+
+        assert_eq!(trie.count_prefix("/usr"), 4);
+        assert_eq!(trie.count_prefix("/usr/lo"), 2);
+        assert_eq!(trie.count_prefix("/usr/lx"), 0);
+        assert_eq!(trie.count_prefix(""), 6);
+
+        let keys: Vec<_> = trie.iter_prefix("/usr/l").map(|(k, _)| k).collect();
+        assert_eq!(keys, [b"/usr/local/bin", b"/usr/local/lib"]);
+        let keys: Vec<_> = trie.iter().map(|(k, &v)| (k, v)).collect();
+        assert_eq!(keys.len(), 6);
+        assert!(keys.windows(2).all(|w| w[0].0 < w[1].0));
+
+        assert_eq!(
+            trie.longest_prefix_of("/usr/local/bin/cargo"),
+            Some((b"/usr/local/bin".to_vec(), &1))
+        );
+        assert_eq!(
+            trie.longest_prefix_of("/usr/loc"),
+            Some((b"/usr".to_vec(), &3))
+        );
+        assert_eq!(trie.longest_prefix_of("usr"), None);
+    }
+}
@@ -0,0 +1,626 @@
+//! # trie
+//! - トライ木の実装
+//! - キーを構成する文字の種類は`A: Alphabet`で指定する（既定は`char`）
+
+use std::{
+    fmt::{self, Debug},
+    ops::{Deref, DerefMut},
+};
+
+use crate::alphabet::{Alphabet, Chars, Children};
+
+type Symbol<A> = <A as Alphabet>::Symbol;
+
+pub(crate) struct TrieNode<T, A: Alphabet> {
+    pub(crate) data: Option<T>,
+    /// 部分木に含まれるキーの数
+    pub(crate) count: usize,
+    pub(crate) children: A::Children<TrieNode<T, A>>,
+}
+
+impl<T, A: Alphabet> TrieNode<T, A> {
+    pub fn new(data: Option<T>) -> Self {
+        Self {
+            count: usize::from(data.is_some()),
+            data,
+            children: Default::default(),
+        }
+    }
+}
+
+pub struct Trie<T, A: Alphabet = Chars> {
+    pub(crate) root: TrieNode<T, A>,
+}
+
+impl<T, A: Alphabet> Trie<T, A> {
+    pub fn new() -> Self {
+        Trie {
+            root: TrieNode::new(None),
+        }
+    }
+
+    /// キーの数
+    pub fn len(&self) -> usize {
+        self.root.count
+    }
+
+    /// 空判定
+    pub fn is_empty(&self) -> bool {
+        self.root.count == 0
+    }
+
+    pub fn insert(&mut self, key: &A::Key, data: T) {
+        insert_inner(&mut self.root, A::symbols(key), data);
+    }
+
+    pub fn get(&self, key: &A::Key) -> Option<&T> {
+        self.find(key)?.data.as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: &A::Key) -> Option<&mut T> {
+        let mut node = &mut self.root;
+        for c in A::symbols(key) {
+            node = node.children.get_mut(c)?;
+        }
+        node.data.as_mut()
+    }
+
+    /// キー`key`に対応する値の入る場所を取得する
+    /// - 返り値を通して値の有無を変えると，破棄したときにキーの数（`len`・`count_prefix`）に反映する
+    /// - 値を持たないまま破棄した場合，トライ木は変化しない
+    pub fn get_or_insert_mut(&mut self, key: &A::Key) -> ValueMut<'_, T, A> {
+        let path: Vec<_> = A::symbols(key).collect();
+        // 値をいったん取り出し，破棄するときに入れ直す
+        let value = remove_inner(&mut self.root, &path);
+        ValueMut {
+            trie: self,
+            path,
+            value,
+        }
+    }
+
+    /// キー`key`を削除し，対応する値を返す
+    /// - 値を持たなくなった枝は削除する
+    pub fn remove(&mut self, key: &A::Key) -> Option<T> {
+        let path: Vec<_> = A::symbols(key).collect();
+        remove_inner(&mut self.root, &path)
+    }
+
+    /// キーを昇順に列挙する
+    pub fn iter(&self) -> PrefixIter<'_, T, A> {
+        PrefixIter::new(Some(&self.root), vec![])
+    }
+
+    /// `prefix`で始まるキーを昇順に列挙する
+    pub fn iter_prefix(&self, prefix: &A::Key) -> PrefixIter<'_, T, A> {
+        PrefixIter::new(self.find(prefix), A::symbols(prefix).collect())
+    }
+
+    /// `prefix`で始まるキーの数
+    /// - 計算量：$O(|prefix|)$
+    pub fn count_prefix(&self, prefix: &A::Key) -> usize {
+        self.find(prefix).map_or(0, |node| node.count)
+    }
+
+    /// `text`の接頭辞であるキーのうち，最長のものと対応する値を返す
+    pub fn longest_prefix_of(&self, text: &A::Key) -> Option<(A::Owned, &T)> {
+        let mut node = &self.root;
+        let mut path = vec![];
+        let mut longest = node.data.as_ref().map(|data| (0, data));
+        for c in A::symbols(text) {
+            let Some(child) = node.children.get(c) else {
+                break;
+            };
+            node = child;
+            path.push(c);
+            if let Some(data) = node.data.as_ref() {
+                longest = Some((path.len(), data));
+            }
+        }
+        longest.map(|(len, data)| (A::collect(&path[..len]), data))
+    }
+
+    pub fn traverse(&self) -> Vec<(A::Owned, &T)> {
+        self.iter().collect()
+    }
+
+    /// `query`との編集距離が`max_distance`以下のキーを，(キー, 編集距離, 値)の形で列挙する
+    /// - 編集距離の昇順，同じ距離ではキーの昇順に並べる
+    /// - 深さごとに編集距離のDPの1行を持ちながら探索し，行の最小値が`max_distance`を超えた部分木は探索しない
+    pub fn fuzzy(&self, query: &A::Key, max_distance: usize) -> Vec<(A::Owned, usize, &T)> {
+        let query: Vec<_> = A::symbols(query).collect();
+        let row: Vec<usize> = (0..=query.len()).collect();
+        let mut found = vec![];
+        fuzzy_inner(
+            &self.root,
+            &query,
+            &row,
+            max_distance,
+            &mut vec![],
+            &mut found,
+        );
+        // 探索はキーの昇順に行うため，安定ソートで距離の昇順にする
+        found.sort_by_key(|&(_, distance, _)| distance);
+        found
+    }
+
+    /// キー`key`に対応するノードを探索する
+    fn find(&self, key: &A::Key) -> Option<&TrieNode<T, A>> {
+        let mut node = &self.root;
+        for c in A::symbols(key) {
+            node = node.children.get(c)?;
+        }
+        Some(node)
+    }
+}
+
+impl<T, A: Alphabet> Default for Trie<T, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, A: Alphabet> Debug for Trie<T, A>
+where
+    A::Owned: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T, A: Alphabet> IntoIterator for &'a Trie<T, A> {
+    type IntoIter = PrefixIter<'a, T, A>;
+    type Item = (A::Owned, &'a T);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 編集距離のDPの行`row`（キー`cur`と`query`の各接頭辞との距離）を持って部分木を探索する
+fn fuzzy_inner<'a, T, A: Alphabet>(
+    node: &'a TrieNode<T, A>,
+    query: &[Symbol<A>],
+    row: &[usize],
+    max_distance: usize,
+    cur: &mut Vec<Symbol<A>>,
+    found: &mut Vec<(A::Owned, usize, &'a T)>,
+) {
+    if let Some(data) = node.data.as_ref() {
+        let distance = row[query.len()];
+        if distance <= max_distance {
+            found.push((A::collect(cur), distance, data));
+        }
+    }
+    for (c, child) in node.children.iter() {
+        let mut next = Vec::with_capacity(row.len());
+        next.push(row[0] + 1);
+        for j in 1..row.len() {
+            let replace = row[j - 1] + usize::from(query[j - 1] != c);
+            next.push(replace.min(row[j] + 1).min(next[j - 1] + 1));
+        }
+        // これ以上距離が小さくならないときは枝刈りする
+        if next.iter().min().is_some_and(|&min| min > max_distance) {
+            continue;
+        }
+        cur.push(c);
+        fuzzy_inner(child, query, &next, max_distance, cur, found);
+        cur.pop();
+    }
+}
+
+/// 残りのキー`symbols`の先に値`data`を入れる
+/// - 新しいキーであれば`true`を返し，経路上のノードのキーの数を増やす
+fn insert_inner<T, A: Alphabet>(
+    node: &mut TrieNode<T, A>,
+    mut symbols: impl Iterator<Item = Symbol<A>>,
+    data: T,
+) -> bool {
+    let added = match symbols.next() {
+        Some(c) => {
+            let child = node.children.get_or_insert_with(c, || TrieNode::new(None));
+            insert_inner(child, symbols, data)
+        }
+        None => node.data.replace(data).is_none(),
+    };
+    if added {
+        node.count += 1;
+    }
+    added
+}
+
+/// 経路`path`の先にあるキーを削除し，値を持たなくなった枝を削除する
+fn remove_inner<T, A: Alphabet>(node: &mut TrieNode<T, A>, path: &[Symbol<A>]) -> Option<T> {
+    let Some((&c, rest)) = path.split_first() else {
+        let data = node.data.take()?;
+        node.count -= 1;
+        return Some(data);
+    };
+    let child = node.children.get_mut(c)?;
+    let data = remove_inner(child, rest);
+    if child.data.is_none() && child.children.is_empty() {
+        node.children.remove(c);
+    }
+    if data.is_some() {
+        node.count -= 1;
+    }
+    data
+}
+
+// ----- value -----
+/// `get_or_insert_mut`が返す，キーに対応する値の入る場所
+/// - 破棄するときに値をトライ木へ戻し，経路上のノードのキーの数を更新する
+pub struct ValueMut<'a, T, A: Alphabet> {
+    trie: &'a mut Trie<T, A>,
+    path: Vec<Symbol<A>>,
+    value: Option<T>,
+}
+
+impl<T, A: Alphabet> Deref for ValueMut<'_, T, A> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, A: Alphabet> DerefMut for ValueMut<'_, T, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T, A: Alphabet> Drop for ValueMut<'_, T, A> {
+    fn drop(&mut self) {
+        if let Some(data) = self.value.take() {
+            insert_inner(&mut self.trie.root, self.path.iter().copied(), data);
+        }
+    }
+}
+
+// ----- iterator -----
+/// (親までのキーの長さ, 親からの文字, ノード)
+type Unvisited<'a, T, A> = (usize, Option<Symbol<A>>, &'a TrieNode<T, A>);
+
+/// キーを昇順に返すイテレータ
+pub struct PrefixIter<'a, T, A: Alphabet> {
+    unvisited: Vec<Unvisited<'a, T, A>>,
+    key: Vec<Symbol<A>>,
+}
+
+impl<'a, T, A: Alphabet> PrefixIter<'a, T, A> {
+    /// キー`key`に対応するノード`node`以下を探索するイテレータ
+    fn new(node: Option<&'a TrieNode<T, A>>, key: Vec<Symbol<A>>) -> Self {
+        Self {
+            unvisited: node
+                .map(|node| (key.len(), None, node))
+                .into_iter()
+                .collect(),
+            key,
+        }
+    }
+}
+
+impl<'a, T, A: Alphabet> Iterator for PrefixIter<'a, T, A> {
+    type Item = (A::Owned, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((len, c, node)) = self.unvisited.pop() {
+            self.key.truncate(len);
+            self.key.extend(c);
+            // 文字の小さい子から取り出すため，逆順に積む
+            let len = self.key.len();
+            self.unvisited.extend(
+                node.children
+                    .iter()
+                    .rev()
+                    .map(|(c, child)| (len, Some(c), child)),
+            );
+            if let Some(data) = node.data.as_ref() {
+                return Some((A::collect(&self.key), data));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Trie;
+    use crate::alphabet::{Bytes, Children, LowerCase};
+
+    #[test]
+    fn test_trie_node() {
+        let mut trie: Trie<usize> = Trie::new();
+
+        // 文字列の挿入
+        // trie.insert("powell", 5);
+        // trie.insert("kentakomoto", 11);
+        // trie.insert("kenta", 5);
+        // trie.insert("pow", 3);
+        // trie.insert("", 0);
+        *trie.get_or_insert_mut("powell") = Some(6);
+        *trie.get_or_insert_mut("kenta") = Some(8);
+
+        // デバッグ
+        println!("{:#?}", trie);
+
+        // 一覧表示
+        let dict = trie.traverse();
+        println!("{:?}", dict);
+
+        // 検索
+        println!("{:?}", trie.get_mut("pow"));
+
+        *trie.get_mut("powell").unwrap() += 1;
+
+        // 一覧表示
+        let dict = trie.traverse();
+        println!("{:?}", dict);
+    }
+
+    #[test]
+    fn test_unicode() {
+        let mut trie: Trie<usize> = Trie::new();
+
+        trie.insert("Alice", 0);
+        trie.insert("/usr/local/bin", 1);
+        trie.insert("ケンタ", 2);
+        trie.insert("user_42", 3);
+        trie.insert("", 4);
+
+        assert_eq!(trie.get("Alice"), Some(&0));
+        assert_eq!(trie.get("/usr/local/bin"), Some(&1));
+        assert_eq!(trie.get("ケンタ"), Some(&2));
+        assert_eq!(trie.get("user_42"), Some(&3));
+        assert_eq!(trie.get(""), Some(&4));
+        assert_eq!(trie.get("alice"), None);
+        assert_eq!(trie.get("/usr"), None);
+
+        // 文字の昇順に列挙される
+        let keys: Vec<_> = trie.traverse().into_iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, ["", "/usr/local/bin", "Alice", "user_42", "ケンタ"]);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let mut trie: Trie<&str, Bytes> = Trie::new();
+
+        trie.insert(b"\x00\xff", "binary");
+        trie.insert("café".as_bytes(), "utf-8");
+
+        assert_eq!(trie.get(b"\x00\xff"), Some(&"binary"));
+        assert_eq!(trie.get("café".as_bytes()), Some(&"utf-8"));
+        assert_eq!(trie.get(b"caf"), None);
+        assert_eq!(
+            trie.traverse(),
+            [
+                (vec![0x00, 0xff], &"binary"),
+                ("café".as_bytes().to_vec(), &"utf-8")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dense() {
+        let mut trie: Trie<usize, LowerCase> = Trie::new();
+
+        trie.insert("kenta", 5);
+        trie.insert("ken", 3);
+        trie.insert("powell", 6);
+
+        assert_eq!(trie.get("ken"), Some(&3));
+        // アルファベットに含まれない文字を含むキーは存在しない
+        assert_eq!(trie.get("Kenta"), None);
+        assert_eq!(trie.get("ken1"), None);
+        assert_eq!(
+            trie.traverse(),
+            [
+                ("ken".to_string(), &3),
+                ("kenta".to_string(), &5),
+                ("powell".to_string(), &6)
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dense_out_of_alphabet() {
+        let mut trie: Trie<usize, LowerCase> = Trie::new();
+        trie.insert("Kenta", 5);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut trie: Trie<usize> = Trie::new();
+
+        trie.insert("kenta", 5);
+        trie.insert("ken", 3);
+        trie.insert("powell", 6);
+        assert_eq!(trie.len(), 3);
+
+        assert_eq!(trie.remove("kent"), None);
+        assert_eq!(trie.remove("kenta"), Some(5));
+        assert_eq!(trie.remove("kenta"), None);
+        assert_eq!(trie.len(), 2);
+        assert_eq!(trie.get("ken"), Some(&3));
+        // 値を持たない枝は削除される
+        assert!(trie.find("ken").is_some());
+        assert!(trie.find("kent").is_none());
+
+        assert_eq!(trie.remove("ken"), Some(3));
+        assert!(trie.find("k").is_none());
+        assert_eq!(trie.remove("powell"), Some(6));
+        assert!(trie.is_empty());
+        assert!(trie.find("").unwrap().children.is_empty());
+    }
+
+    #[test]
+    fn test_insert_count() {
+        let mut trie: Trie<usize> = Trie::new();
+
+        trie.insert("abc", 1);
+        trie.insert("ab", 2);
+        assert_eq!(trie.len(), 2);
+        assert_eq!(trie.count_prefix("abc"), 1);
+
+        // 既存の値の更新ではキーの数は変わらない
+        trie.insert("abc", 3);
+        assert_eq!(trie.len(), 2);
+        assert_eq!(trie.get("abc"), Some(&3));
+
+        // 値の入る場所を取得しても，値を入れなければキーの数は変わらない
+        assert_eq!(*trie.get_or_insert_mut("abd"), None);
+        assert_eq!(trie.len(), 2);
+        assert!(trie.find("abd").is_none());
+        *trie.get_or_insert_mut("ab").as_mut().unwrap() += 10;
+        assert_eq!(trie.get("ab"), Some(&12));
+        assert_eq!(trie.len(), 2);
+
+        // 値を持つノードの祖先は削除されない
+        trie.insert("xyz", 4);
+        assert_eq!(trie.remove("xy"), None);
+        assert_eq!(trie.get("xyz"), Some(&4));
+
+        assert_eq!(trie.remove("abc"), Some(3));
+        assert_eq!(trie.remove("ab"), Some(12));
+        assert_eq!(trie.len(), 1);
+        assert_eq!(trie.count_prefix("a"), 0);
+    }
+
+    #[test]
+    fn test_get_or_insert_mut() {
+        let mut trie: Trie<usize> = Trie::new();
+
+        // 値を入れるとキーの数が増える
+        *trie.get_or_insert_mut("xyz") = Some(4);
+        *trie.get_or_insert_mut("xy") = Some(5);
+        assert_eq!(trie.len(), 2);
+        assert_eq!(trie.count_prefix("xyz"), 1);
+        assert_eq!(trie.remove("xyz"), Some(4));
+        assert_eq!(trie.len(), 1);
+
+        // 値を取り除くとキーの数が減り，値を持たなくなった枝は削除される
+        *trie.get_or_insert_mut("xyw") = Some(6);
+        assert_eq!(trie.get_or_insert_mut("xy").take(), Some(5));
+        assert_eq!(trie.len(), 1);
+        assert_eq!(trie.get("xy"), None);
+        assert_eq!(trie.get_or_insert_mut("xyw").take(), Some(6));
+        assert!(trie.is_empty());
+        assert!(trie.find("x").is_none());
+
+        // 値の書き換えではキーの数は変わらない
+        *trie.get_or_insert_mut("a") = Some(1);
+        *trie.get_or_insert_mut("a") = Some(2);
+        assert_eq!(trie.len(), 1);
+        assert_eq!(trie.remove("a"), Some(2));
+        assert_eq!(trie.remove("a"), None);
+        assert!(trie.is_empty());
+    }
+
+    #[test]
+    fn test_prefix() {
+        let trie: Trie<usize> = [
+            "/usr/bin",
+            "/usr/local/bin",
+            "/usr/local/lib",
+            "/usr",
+            "/var/log",
+            "/",
+        ]
+        .into_iter()
+        .enumerate()
+        .fold(Trie::new(), |mut trie, (i, key)| {
+            trie.insert(key, i);
+            trie
+        });
+
+        assert_eq!(trie.len(), 6);
+        assert_eq!(trie.count_prefix(""), 6);
+        assert_eq!(trie.count_prefix("/usr"), 4);
+        assert_eq!(trie.count_prefix("/usr/local/"), 2);
+        assert_eq!(trie.count_prefix("/opt"), 0);
+
+        let keys: Vec<_> = trie.iter_prefix("/usr/").map(|(k, _)| k).collect();
+        assert_eq!(keys, ["/usr/bin", "/usr/local/bin", "/usr/local/lib"]);
+        assert!(trie.iter_prefix("/home").next().is_none());
+        assert_eq!(
+            trie.iter_prefix("/var/log").next(),
+            Some(("/var/log".to_string(), &4))
+        );
+        assert_eq!(trie.iter().count(), 6);
+
+        assert_eq!(
+            trie.longest_prefix_of("/usr/local/bin/cargo"),
+            Some(("/usr/local/bin".to_string(), &1))
+        );
+        assert_eq!(
+            trie.longest_prefix_of("/usr/share"),
+            Some(("/usr".to_string(), &3))
+        );
+        assert_eq!(trie.longest_prefix_of("/opt"), Some(("/".to_string(), &5)));
+        assert_eq!(trie.longest_prefix_of("usr"), None);
+    }
+
+    /// 編集距離（愚直な解法）
+    fn levenshtein(a: &str, b: &str) -> usize {
+        let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+        let mut dp: Vec<usize> = (0..=b.len()).collect();
+        for i in 1..=a.len() {
+            let mut prev = dp[0];
+            dp[0] = i;
+            for j in 1..=b.len() {
+                let tmp = dp[j];
+                dp[j] = (prev + usize::from(a[i - 1] != b[j - 1]))
+                    .min(dp[j] + 1)
+                    .min(dp[j - 1] + 1);
+                prev = tmp;
+            }
+        }
+        dp[b.len()]
+    }
+
+    #[test]
+    fn test_fuzzy() {
+        let words = [
+            "kitten", "sitting", "mitten", "bitten", "kitchen", "knitting", "sit", "smitten",
+            "written", "kit",
+        ];
+        let mut trie: Trie<usize> = Trie::new();
+        for (i, word) in words.iter().enumerate() {
+            trie.insert(word, i);
+        }
+
+        let found: Vec<_> = trie
+            .fuzzy("kitten", 1)
+            .into_iter()
+            .map(|(key, d, &v)| (key, d, v))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("kitten".to_string(), 0, 0),
+                ("bitten".to_string(), 1, 3),
+                ("mitten".to_string(), 1, 2)
+            ]
+        );
+
+        for query in ["kitten", "sittin", "kit", "", "writing", "ケン"] {
+            for max_distance in 0..4 {
+                let mut expected: Vec<_> = words
+                    .iter()
+                    .map(|w| (w.to_string(), levenshtein(query, w)))
+                    .filter(|&(_, d)| d <= max_distance)
+                    .collect();
+                expected.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+                let found: Vec<_> = trie
+                    .fuzzy(query, max_distance)
+                    .into_iter()
+                    .map(|(key, d, _)| (key, d))
+                    .collect();
+                assert_eq!(found, expected, "{query} {max_distance}");
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! 辞書規模の入力に対する，トライ木と基数木のメモリ使用量の比較
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use trie::{alphabet::LowerCase, radix_trie::RadixTrie, trie::Trie};
+
+/// 確保中のメモリ量を数えるアロケータ
+struct CountingAlloc;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// `build`で構築したデータ構造が確保しているメモリ量
+fn measure<T>(build: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let value = build();
+    let after = ALLOCATED.load(Ordering::Relaxed);
+    (value, after - before)
+}
+
+/// 英小文字からなる単語を疑似乱数で生成する
+/// - 計測結果を比較できるよう，シードを固定する
+fn words(n: usize) -> Vec<String> {
+    let mut rng = StdRng::seed_from_u64(0x2545_f491_4f6c_dd1d);
+    (0..n)
+        .map(|_| {
+            let len = rng.gen_range(4..16);
+            (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_memory_usage() {
+    let words = words(20_000);
+
+    let (dense, dense_bytes) = measure(|| {
+        let mut trie: Trie<usize, LowerCase> = Trie::new();
+        for (i, word) in words.iter().enumerate() {
+            trie.insert(word, i);
+        }
+        trie
+    });
+    let (sparse, sparse_bytes) = measure(|| {
+        let mut trie: Trie<usize> = Trie::new();
+        for (i, word) in words.iter().enumerate() {
+            trie.insert(word, i);
+        }
+        trie
+    });
+    let (radix, radix_bytes) = measure(|| {
+        let mut trie = RadixTrie::new();
+        for (i, word) in words.iter().enumerate() {
+            trie.insert(word, i);
+        }
+        trie
+    });
+
+    println!("Trie<_, LowerCase>: {dense_bytes:>10} bytes");
+    println!("Trie<_, Chars>    : {sparse_bytes:>10} bytes");
+    println!("RadixTrie         : {radix_bytes:>10} bytes");
+
+    assert_eq!(dense.len(), sparse.len());
+    assert_eq!(sparse.len(), radix.len());
+    for word in words.iter().step_by(97) {
+        assert_eq!(dense.get(word), radix.get(word));
+        assert_eq!(sparse.get(word), radix.get(word));
+    }
+
+    assert!(radix_bytes < sparse_bytes);
+    assert!(radix_bytes < dense_bytes);
+}